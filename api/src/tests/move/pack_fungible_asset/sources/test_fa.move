@@ -0,0 +1,45 @@
+/// A minimal fungible asset, other than APT, that can be minted by the publisher.
+module addr::test_fa {
+    use aptos_framework::fungible_asset::{Self, Metadata, MintRef};
+    use aptos_framework::object::{Self, Object};
+    use aptos_framework::primary_fungible_store;
+    use std::option;
+    use std::signer;
+    use std::string::utf8;
+
+    /// Only the publisher can mint.
+    const ENOT_PUBLISHER: u64 = 1;
+
+    const ASSET_SYMBOL: vector<u8> = b"TFA";
+
+    #[resource_group_member(group = aptos_framework::object::ObjectGroup)]
+    struct Refs has key {
+        mint_ref: MintRef,
+    }
+
+    fun init_module(admin: &signer) {
+        let constructor_ref = &object::create_named_object(admin, ASSET_SYMBOL);
+        primary_fungible_store::create_primary_store_enabled_fungible_asset(
+            constructor_ref,
+            option::none(),
+            utf8(b"Test FA"),
+            utf8(ASSET_SYMBOL),
+            8,
+            utf8(b""),
+            utf8(b""),
+        );
+        let mint_ref = fungible_asset::generate_mint_ref(constructor_ref);
+        move_to(&object::generate_signer(constructor_ref), Refs { mint_ref });
+    }
+
+    #[view]
+    public fun metadata(): Object<Metadata> {
+        object::address_to_object<Metadata>(object::create_object_address(&@addr, ASSET_SYMBOL))
+    }
+
+    public entry fun mint(admin: &signer, to: address, amount: u64) acquires Refs {
+        assert!(signer::address_of(admin) == @addr, ENOT_PUBLISHER);
+        let refs = borrow_global<Refs>(object::object_address(&metadata()));
+        primary_fungible_store::mint(&refs.mint_ref, to, amount);
+    }
+}
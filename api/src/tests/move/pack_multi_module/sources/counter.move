@@ -0,0 +1,15 @@
+module addr::counter {
+    struct Counter has key {
+        value: u64,
+    }
+
+    public fun increment(account: &signer) acquires Counter {
+        let addr = std::signer::address_of(account);
+        if (exists<Counter>(addr)) {
+            let counter = borrow_global_mut<Counter>(addr);
+            counter.value = counter.value + 1;
+        } else {
+            move_to(account, Counter { value: 1 });
+        }
+    }
+}
@@ -0,0 +1,7 @@
+module addr::entry {
+    use addr::counter;
+
+    public entry fun increment(account: &signer) {
+        counter::increment(account);
+    }
+}
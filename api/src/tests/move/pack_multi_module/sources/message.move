@@ -0,0 +1,11 @@
+module addr::message {
+    use std::string::String;
+
+    struct Message has key {
+        text: String,
+    }
+
+    public entry fun set(account: &signer, text: String) {
+        move_to(account, Message { text });
+    }
+}
@@ -0,0 +1,9 @@
+module addr::upgrade {
+    struct Data has key {
+        value: u64,
+    }
+
+    public entry fun store(account: &signer, value: u64) {
+        move_to(account, Data { value });
+    }
+}
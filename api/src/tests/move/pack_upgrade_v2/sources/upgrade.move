@@ -0,0 +1,11 @@
+module addr::upgrade {
+    // Adding a field changes the struct layout, which is not a compatible upgrade.
+    struct Data has key {
+        value: u64,
+        extra: u64,
+    }
+
+    public entry fun store(account: &signer, value: u64) {
+        move_to(account, Data { value, extra: 0 });
+    }
+}
@@ -2,16 +2,25 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::new_test_context;
-use aptos_api_test_context::{current_function_name, TestContext};
+use aptos_api_test_context::{current_function_name, GovernanceStep, TestContext};
+use aptos_cached_packages::aptos_stdlib;
+use aptos_framework::natives::code::UpgradePolicy;
 use aptos_types::{
-    account_address::AccountAddress,
-    transaction::{EntryFunction, MultisigTransactionPayload},
+    account_address::{create_object_address, AccountAddress},
+    transaction::{
+        EntryFunction, ExecutionStatus, MultisigTransactionPayload, TransactionPayload,
+        TransactionStatus,
+    },
+    vm_status::StatusCode,
 };
 use move_core_types::{
     ident_str,
+    identifier::IdentStr,
     language_storage::{ModuleId, CORE_CODE_ADDRESS},
-    value::{serialize_values, MoveValue},
+    value::{serialize_values, MoveStruct, MoveValue},
 };
+use serde_json::{json, Value};
+use std::{path::PathBuf, time::Duration};
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_multisig_transaction_with_payload_succeeds() {
@@ -30,16 +39,16 @@ async fn test_multisig_transaction_with_payload_succeeds() {
     assert_eq!(1000, context.get_apt_balance(multisig_account).await);
 
     let multisig_payload = construct_multisig_txn_transfer_payload(owner_account_1.address(), 1000);
-    context
+    let transaction_id = context
         .create_multisig_transaction(owner_account_1, multisig_account, multisig_payload.clone())
         .await;
     // Owner 2 approves and owner 3 rejects. There are still 2 approvals total (owners 1 and 2) so
     // the transaction can still be executed.
     context
-        .approve_multisig_transaction(owner_account_2, multisig_account, 1)
+        .approve_multisig_transaction(owner_account_2, multisig_account, transaction_id)
         .await;
     context
-        .reject_multisig_transaction(owner_account_3, multisig_account, 1)
+        .reject_multisig_transaction(owner_account_3, multisig_account, transaction_id)
         .await;
     context
         .execute_multisig_transaction(owner_account_1, multisig_account, 202)
@@ -49,6 +58,33 @@ async fn test_multisig_transaction_with_payload_succeeds() {
     assert_eq!(0, context.get_apt_balance(multisig_account).await);
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_execution_reports_gas_used() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account = &mut context.create_account().await;
+    let recipient = context.gen_account();
+    let multisig_account = context
+        .create_multisig_account(owner_account, vec![], 1, 1000)
+        .await;
+    let multisig_payload = construct_multisig_txn_transfer_payload(recipient.address(), 1000);
+    context
+        .create_multisig_transaction(owner_account, multisig_account, multisig_payload)
+        .await;
+
+    let owner_balance = context.get_apt_balance(owner_account.address()).await;
+    let outcome = context
+        .execute_multisig_transaction_with_outcome(owner_account, multisig_account)
+        .await;
+    assert_eq!(outcome.vm_status, "Executed successfully");
+    assert!(outcome.gas_used > 0);
+    // The executing owner is charged for the gas used at the gas unit price of 100.
+    assert_eq!(
+        owner_balance - context.get_apt_balance(owner_account.address()).await,
+        outcome.gas_used * 100
+    );
+    assert_eq!(1000, context.get_apt_balance(recipient.address()).await);
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_multisig_transaction_with_existing_account() {
     let mut context = new_test_context(current_function_name!());
@@ -68,7 +104,7 @@ async fn test_multisig_transaction_with_existing_account() {
     assert_signature_threshold(&context, multisig_account.address(), 2).await;
 
     let multisig_payload = construct_multisig_txn_transfer_payload(owner_account_1.address(), 1000);
-    context
+    let transaction_id = context
         .create_multisig_transaction(
             owner_account_1,
             multisig_account.address(),
@@ -78,10 +114,10 @@ async fn test_multisig_transaction_with_existing_account() {
     // Owner 2 approves and owner 3 rejects. There are still 2 approvals total (owners 1 and 2) so
     // the transaction can still be executed.
     context
-        .approve_multisig_transaction(owner_account_2, multisig_account.address(), 1)
+        .approve_multisig_transaction(owner_account_2, multisig_account.address(), transaction_id)
         .await;
     context
-        .reject_multisig_transaction(owner_account_3, multisig_account.address(), 1)
+        .reject_multisig_transaction(owner_account_3, multisig_account.address(), transaction_id)
         .await;
 
     let org_multisig_balance = context.get_apt_balance(multisig_account.address()).await;
@@ -102,6 +138,73 @@ async fn test_multisig_transaction_with_existing_account() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_with_fungible_asset() {
+    let mut context = new_test_context(current_function_name!());
+    let publisher = &mut context.create_account().await;
+    let owner_account_1 = &mut context.create_account().await;
+    let owner_account_2 = &mut context.create_account().await;
+
+    // Publish a custom fungible asset and mint some to owner 1.
+    let path =
+        PathBuf::from(std::env!("CARGO_MANIFEST_DIR")).join("src/tests/move/pack_fungible_asset");
+    let payload = TestContext::build_package(path, vec![("addr".to_string(), publisher.address())]);
+    context.publish_package(publisher, payload).await;
+    let fa_metadata = create_object_address(publisher.address(), b"TFA");
+    context
+        .api_execute_entry_function(
+            publisher,
+            &format!("{}::test_fa::mint", publisher.address().to_hex_literal()),
+            json!([]),
+            json!([owner_account_1.address().to_hex_literal(), "1000"]),
+        )
+        .await;
+
+    let multisig_account = context
+        .create_multisig_account_with_fa(
+            owner_account_1,
+            vec![owner_account_2.address()],
+            2, /* 2-of-2 */
+            fa_metadata,
+            1000, /* FA amount */
+        )
+        .await;
+    assert_eq!(
+        1000,
+        context.get_fa_balance(multisig_account, fa_metadata).await
+    );
+    assert_eq!(
+        0,
+        context
+            .get_fa_balance(owner_account_1.address(), fa_metadata)
+            .await
+    );
+
+    let multisig_payload =
+        construct_multisig_txn_fa_transfer_payload(fa_metadata, owner_account_2.address(), 400);
+    let transaction_id = context
+        .create_multisig_transaction(owner_account_1, multisig_account, multisig_payload)
+        .await;
+    context
+        .approve_multisig_transaction(owner_account_2, multisig_account, transaction_id)
+        .await;
+    context
+        .execute_multisig_transaction(owner_account_1, multisig_account, 202)
+        .await;
+
+    // The multisig tx that transfers away 400 of the FA should have succeeded.
+    assert_eq!(
+        600,
+        context.get_fa_balance(multisig_account, fa_metadata).await
+    );
+    assert_eq!(
+        400,
+        context
+            .get_fa_balance(owner_account_2.address(), fa_metadata)
+            .await
+    );
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_multisig_transaction_to_update_owners() {
     let mut context = new_test_context(current_function_name!());
@@ -110,7 +213,7 @@ async fn test_multisig_transaction_to_update_owners() {
     let owner_account_3 = &mut context.create_account().await;
     let owner_account_4 = &mut context.create_account().await;
     let multisig_account = context
-        .create_multisig_account(
+        .create_multisig_account_checked(
             owner_account_1,
             vec![owner_account_2.address()],
             2,
@@ -132,7 +235,7 @@ async fn test_multisig_transaction_to_update_owners() {
         ),
     ))
     .unwrap();
-    context
+    let add_owners_id = context
         .create_multisig_transaction(
             owner_account_1,
             multisig_account,
@@ -140,7 +243,7 @@ async fn test_multisig_transaction_to_update_owners() {
         )
         .await;
     context
-        .approve_multisig_transaction(owner_account_2, multisig_account, 1)
+        .approve_multisig_transaction(owner_account_2, multisig_account, add_owners_id)
         .await;
     context
         .execute_multisig_transaction(owner_account_1, multisig_account, 202)
@@ -166,7 +269,7 @@ async fn test_multisig_transaction_to_update_owners() {
         ),
     ))
     .unwrap();
-    context
+    let remove_owners_id = context
         .create_multisig_transaction(
             owner_account_1,
             multisig_account,
@@ -174,7 +277,7 @@ async fn test_multisig_transaction_to_update_owners() {
         )
         .await;
     context
-        .approve_multisig_transaction(owner_account_3, multisig_account, 2)
+        .approve_multisig_transaction(owner_account_3, multisig_account, remove_owners_id)
         .await;
     context
         .execute_multisig_transaction(owner_account_1, multisig_account, 202)
@@ -189,12 +292,283 @@ async fn test_multisig_transaction_to_update_owners() {
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn test_multisig_transaction_update_signature_threshold() {
+async fn test_multisig_transaction_approval_from_removed_owner_does_not_count() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account_1 = &mut context.create_account().await;
+    let owner_account_2 = &mut context.create_account().await;
+    let owner_account_3 = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(
+            owner_account_1,
+            vec![owner_account_2.address(), owner_account_3.address()],
+            2,    /* 2-of-3 */
+            1000, /* initial balance */
+        )
+        .await;
+
+    // Transaction 1 removes owner 2.
+    let remove_owners_payload = bcs::to_bytes(&MultisigTransactionPayload::EntryFunction(
+        EntryFunction::new(
+            ModuleId::new(CORE_CODE_ADDRESS, ident_str!("multisig_account").to_owned()),
+            ident_str!("remove_owners").to_owned(),
+            vec![],
+            serialize_values(&vec![MoveValue::vector_address(vec![
+                owner_account_2.address()
+            ])]),
+        ),
+    ))
+    .unwrap();
+    let remove_owners_id = context
+        .create_multisig_transaction(owner_account_1, multisig_account, remove_owners_payload)
+        .await;
+    // Transaction 2 is created, and thus approved, by owner 2.
+    let transfer_payload = construct_multisig_txn_transfer_payload(owner_account_1.address(), 1000);
+    let transfer_id = context
+        .create_multisig_transaction(owner_account_2, multisig_account, transfer_payload)
+        .await;
+
+    // Owner 2 is removed while its approval of transaction 2 is pending.
+    context
+        .approve_multisig_transaction(owner_account_3, multisig_account, remove_owners_id)
+        .await;
+    context
+        .execute_multisig_transaction(owner_account_1, multisig_account, 202)
+        .await;
+    assert_owners(&context, multisig_account, vec![
+        owner_account_1.address(),
+        owner_account_3.address(),
+    ])
+    .await;
+
+    // Only votes of current owners are counted, so transaction 2 has just the implicit approval
+    // of the executing owner 1, which is not enough.
+    context
+        .execute_multisig_transaction(owner_account_1, multisig_account, 400)
+        .await;
+    // The rejected transaction did not use up a sequence number.
+    owner_account_1.decrement_sequence_number();
+    assert_eq!(1000, context.get_apt_balance(multisig_account).await);
+
+    // Another approval from a current owner is needed to execute transaction 2.
+    context
+        .approve_multisig_transaction(owner_account_3, multisig_account, transfer_id)
+        .await;
+    context
+        .execute_multisig_transaction(owner_account_1, multisig_account, 202)
+        .await;
+    assert_eq!(0, context.get_apt_balance(multisig_account).await);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_approvals_are_timed() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account_1 = &mut context.create_account().await;
+    let owner_account_2 = &mut context.create_account().await;
+    let owner_account_3 = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(
+            owner_account_1,
+            vec![owner_account_2.address(), owner_account_3.address()],
+            3,    /* 3-of-3 */
+            1000, /* initial balance */
+        )
+        .await;
+
+    let multisig_payload = construct_multisig_txn_transfer_payload(owner_account_1.address(), 1000);
+    context
+        .create_multisig_transaction(owner_account_1, multisig_account, multisig_payload)
+        .await;
+    let timing = context
+        .time_multisig_approvals(
+            &mut [&mut *owner_account_2, &mut *owner_account_3],
+            multisig_account,
+            1,
+        )
+        .await;
+    assert_eq!(2, timing.gas_used.len());
+    assert!(timing.gas_used.iter().all(|gas_used| *gas_used > 0));
+
+    // All approvals were recorded, so the transaction can be executed.
+    context
+        .execute_multisig_transaction(owner_account_1, multisig_account, 202)
+        .await;
+    assert_eq!(0, context.get_apt_balance(multisig_account).await);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_rejected_by_one_owner_can_be_revived() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account_1 = &mut context.create_account().await;
+    let owner_account_2 = &mut context.create_account().await;
+    let owner_account_3 = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(
+            owner_account_1,
+            vec![owner_account_2.address(), owner_account_3.address()],
+            3,    /* 3-of-3 */
+            1000, /* initial balance */
+        )
+        .await;
+
+    let multisig_payload = construct_multisig_txn_transfer_payload(owner_account_1.address(), 1000);
+    let transaction_id = context
+        .create_multisig_transaction(owner_account_1, multisig_account, multisig_payload)
+        .await;
+    context
+        .approve_multisig_transaction(owner_account_2, multisig_account, transaction_id)
+        .await;
+    context
+        .reject_multisig_transaction(owner_account_3, multisig_account, transaction_id)
+        .await;
+
+    // With one rejection a 3-of-3 can't reach 3 approvals, so execution fails and the transaction
+    // stays pending. A single rejection is not enough to remove it either.
+    context
+        .assert_multisig_transaction_not_executable(
+            owner_account_1,
+            multisig_account,
+            transaction_id,
+        )
+        .await;
+    assert_eq!(1000, context.get_apt_balance(multisig_account).await);
+    assert!(
+        !context
+            .call_view_bool("0x1::multisig_account::can_be_rejected", &[], vec![
+                json!(multisig_account.to_hex_literal()),
+                json!(transaction_id.to_string()),
+            ])
+            .await
+    );
+
+    // Votes can be changed while the transaction is pending, so the rejecting owner approving it
+    // later revives the transaction.
+    context
+        .approve_multisig_transaction(owner_account_3, multisig_account, transaction_id)
+        .await;
+    context
+        .execute_multisig_transaction(owner_account_1, multisig_account, 202)
+        .await;
+    assert_eq!(0, context.get_apt_balance(multisig_account).await);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_governance_sequence() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account_1 = &mut context.create_account().await;
+    let owner_account_2 = &mut context.create_account().await;
+    let owner_account_3 = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(owner_account_1, vec![], 1, 1000)
+        .await;
+
+    // Bring in two more owners, require 2 signatures, then remove one of the new owners and
+    // pay out from the treasury.
+    let transaction_ids = context
+        .queue_governance_sequence(owner_account_1, multisig_account, vec![
+            GovernanceStep::AddOwners(vec![owner_account_2.address(), owner_account_3.address()]),
+            GovernanceStep::ChangeThreshold(2),
+            GovernanceStep::RemoveOwners(vec![owner_account_3.address()]),
+            GovernanceStep::CustomPayload(EntryFunction::new(
+                ModuleId::new(CORE_CODE_ADDRESS, ident_str!("aptos_account").to_owned()),
+                ident_str!("transfer").to_owned(),
+                vec![],
+                serialize_values(&vec![
+                    MoveValue::Address(owner_account_2.address()),
+                    MoveValue::U64(1000),
+                ]),
+            )),
+        ])
+        .await;
+    assert_eq!(transaction_ids, vec![1, 2, 3, 4]);
+
+    // The first two steps only need the creator's implicit approval.
+    for _ in 0..2 {
+        context
+            .execute_multisig_transaction(owner_account_1, multisig_account, 202)
+            .await;
+    }
+    assert_signature_threshold(&context, multisig_account, 2).await;
+
+    // The remaining steps need a second approval under the new threshold.
+    for transaction_id in &transaction_ids[2..] {
+        context
+            .approve_multisig_transaction(owner_account_2, multisig_account, *transaction_id)
+            .await;
+        context
+            .execute_multisig_transaction(owner_account_1, multisig_account, 202)
+            .await;
+    }
+
+    assert_owners(&context, multisig_account, vec![
+        owner_account_1.address(),
+        owner_account_2.address(),
+    ])
+    .await;
+    assert_signature_threshold(&context, multisig_account, 2).await;
+    assert_eq!(0, context.get_apt_balance(multisig_account).await);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_with_vector_of_structs_argument() {
     let mut context = new_test_context(current_function_name!());
     let owner_account_1 = &mut context.create_account().await;
     let owner_account_2 = &mut context.create_account().await;
     let multisig_account = context
         .create_multisig_account(
+            owner_account_1,
+            vec![owner_account_2.address()],
+            2,
+            0, /* initial balance */
+        )
+        .await;
+
+    // update_metadata takes a vector<String>, where each String is a BCS-encoded struct.
+    let metadata = vec![("name", b"treasury".to_vec()), ("team", b"core".to_vec())];
+    let update_metadata_payload = construct_multisig_txn_update_metadata_payload(&metadata);
+    let transaction_id = context
+        .create_multisig_transaction(owner_account_1, multisig_account, update_metadata_payload)
+        .await;
+    context
+        .approve_multisig_transaction(owner_account_2, multisig_account, transaction_id)
+        .await;
+    context
+        .execute_multisig_transaction(owner_account_1, multisig_account, 202)
+        .await;
+
+    // The decoded keys and values should have landed in the multisig account's metadata.
+    let multisig_account_resource = context
+        .api_get_account_resource(
+            multisig_account,
+            "0x1",
+            "multisig_account",
+            "MultisigAccount",
+        )
+        .await;
+    let entries = multisig_account_resource["data"]["metadata"]["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| {
+            (
+                entry["key"].as_str().unwrap().to_string(),
+                entry["value"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let expected_entries = metadata
+        .iter()
+        .map(|(key, value)| (key.to_string(), format!("0x{}", hex::encode(value))))
+        .collect::<Vec<_>>();
+    assert_eq!(expected_entries, entries);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_update_signature_threshold() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account_1 = &mut context.create_account().await;
+    let owner_account_2 = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account_checked(
             owner_account_1,
             vec![owner_account_2.address()],
             2,    /* 2-of-2 */
@@ -212,7 +586,7 @@ async fn test_multisig_transaction_update_signature_threshold() {
         ),
     ))
     .unwrap();
-    context
+    let transaction_id = context
         .create_multisig_transaction(
             owner_account_1,
             multisig_account,
@@ -220,7 +594,7 @@ async fn test_multisig_transaction_update_signature_threshold() {
         )
         .await;
     context
-        .approve_multisig_transaction(owner_account_2, multisig_account, 1)
+        .approve_multisig_transaction(owner_account_2, multisig_account, transaction_id)
         .await;
     context
         .execute_multisig_transaction(owner_account_1, multisig_account, 202)
@@ -230,6 +604,45 @@ async fn test_multisig_transaction_update_signature_threshold() {
     assert_signature_threshold(&context, multisig_account, 1).await;
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_executed_by_non_owner_fails() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account_1 = &mut context.create_account().await;
+    let owner_account_2 = &mut context.create_account().await;
+    let non_owner_account = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(
+            owner_account_1,
+            vec![owner_account_2.address()],
+            1,    /* 1-of-2 */
+            1000, /* initial balance */
+        )
+        .await;
+
+    let multisig_payload = construct_multisig_txn_transfer_payload(owner_account_1.address(), 1000);
+    context
+        .create_multisig_transaction(owner_account_1, multisig_account, multisig_payload)
+        .await;
+
+    // Even though the transaction has enough approvals, only owners can execute it. The
+    // transaction is rejected during validation, so the non-owner is not charged any gas.
+    let non_owner_balance = context.get_apt_balance(non_owner_account.address()).await;
+    context
+        .execute_multisig_transaction(non_owner_account, multisig_account, 400)
+        .await;
+    assert_eq!(1000, context.get_apt_balance(multisig_account).await);
+    assert_eq!(
+        non_owner_balance,
+        context.get_apt_balance(non_owner_account.address()).await
+    );
+
+    // An owner can still execute the transaction.
+    context
+        .execute_multisig_transaction(owner_account_2, multisig_account, 202)
+        .await;
+    assert_eq!(0, context.get_apt_balance(multisig_account).await);
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_multisig_transaction_with_insufficient_balance_to_cover_gas() {
     let mut context = new_test_context(current_function_name!());
@@ -278,6 +691,41 @@ async fn test_multisig_transaction_with_payload_and_failing_execution() {
     assert_eq!(1000, context.get_apt_balance(multisig_account).await);
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_succeeds_after_funding() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(owner_account, vec![], 1, 1000)
+        .await;
+    let multisig_payload = construct_multisig_txn_transfer_payload(owner_account.address(), 2000);
+    context
+        .create_multisig_transaction(owner_account, multisig_account, multisig_payload)
+        .await;
+
+    // The first attempt fails because the multisig account can't cover the transfer.
+    context
+        .execute_multisig_transaction(owner_account, multisig_account, 202)
+        .await;
+    assert_eq!(1000, context.get_apt_balance(multisig_account).await);
+    assert!(context
+        .get_last_multisig_execution_error(owner_account)
+        .await
+        .is_some());
+
+    // Top up the multisig account and retry the same transaction.
+    context.fund_account(multisig_account, 1000).await;
+    assert_eq!(2000, context.get_apt_balance(multisig_account).await);
+    context
+        .execute_multisig_transaction(owner_account, multisig_account, 202)
+        .await;
+    assert!(context
+        .get_last_multisig_execution_error(owner_account)
+        .await
+        .is_none());
+    assert_eq!(0, context.get_apt_balance(multisig_account).await);
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_multisig_transaction_with_payload_hash() {
     let mut context = new_test_context(current_function_name!());
@@ -359,85 +807,355 @@ async fn test_multisig_transaction_with_payload_not_matching_hash() {
         )
         .await;
 
-    // The multisig transaction execution should fail due to the amount being different
-    // (1000 vs 500).
-    context
-        .execute_multisig_transaction_with_payload(
-            owner_account,
-            multisig_account,
-            "0x1::aptos_account::transfer",
-            &[],
-            &[&owner_account.address().to_hex_literal(), "1000"],
-            400,
-        )
+    // The multisig transaction execution should fail due to the amount being different
+    // (1000 vs 500).
+    context
+        .execute_multisig_transaction_with_payload(
+            owner_account,
+            multisig_account,
+            "0x1::aptos_account::transfer",
+            &[],
+            &[&owner_account.address().to_hex_literal(), "1000"],
+            400,
+        )
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_with_matching_payload() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(owner_account, vec![], 1, 1000)
+        .await;
+    assert_eq!(1000, context.get_apt_balance(multisig_account).await);
+    let multisig_payload = construct_multisig_txn_transfer_payload(owner_account.address(), 1000);
+    context
+        .create_multisig_transaction(owner_account, multisig_account, multisig_payload.clone())
+        .await;
+    context
+        .execute_multisig_transaction_with_payload(
+            owner_account,
+            multisig_account,
+            "0x1::aptos_account::transfer",
+            &[],
+            &[&owner_account.address().to_hex_literal(), "1000"],
+            202,
+        )
+        .await;
+
+    // The multisig tx that transfers away 1000 APT should have succeeded.
+    assert_eq!(0, context.get_apt_balance(multisig_account).await);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_with_mismatching_payload() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(owner_account, vec![], 1, 1000)
+        .await;
+    let multisig_payload = construct_multisig_txn_transfer_payload(owner_account.address(), 1000);
+    context
+        .create_multisig_transaction(owner_account, multisig_account, multisig_payload.clone())
+        .await;
+
+    // The multisig transaction execution should fail due to the payload mismatch
+    // amount being different (1000 vs 2000).
+    context
+        .execute_multisig_transaction_with_payload(
+            owner_account,
+            multisig_account,
+            "0x1::aptos_account::transfer",
+            &[],
+            &[&owner_account.address().to_hex_literal(), "2000"],
+            400,
+        )
+        .await;
+    // Balance didn't change since the target transaction failed.
+    assert_eq!(1000, context.get_apt_balance(multisig_account).await);
+
+    // Excuting the transaction with the correct payload should succeed.
+    context
+        .execute_multisig_transaction_with_payload(
+            owner_account,
+            multisig_account,
+            "0x1::aptos_account::transfer",
+            &[],
+            &[&owner_account.address().to_hex_literal(), "1000"],
+            202,
+        )
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_with_expiration() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(owner_account, vec![], 1, 1000)
+        .await;
+    let multisig_payload = construct_multisig_txn_transfer_payload(owner_account.address(), 1000);
+
+    // Advance the fake clock past the expiration so the creation is rejected.
+    let expiration_secs = 1000;
+    context.set_fake_time_usecs(Duration::from_secs(expiration_secs + 1).as_micros() as u64);
+    let status = context
+        .create_multisig_transaction_with_expiration(
+            owner_account,
+            multisig_account,
+            multisig_payload.clone(),
+            expiration_secs,
+        )
+        .await;
+    assert_eq!(
+        status,
+        TransactionStatus::Discard(StatusCode::TRANSACTION_EXPIRED)
+    );
+
+    // The same payload with an expiration in the future is accepted.
+    let status = context
+        .create_multisig_transaction_with_expiration(
+            owner_account,
+            multisig_account,
+            multisig_payload,
+            expiration_secs * 2,
+        )
+        .await;
+    assert!(matches!(status, TransactionStatus::Keep(_)));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_execution_after_expiration() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(owner_account, vec![], 1, 1000)
+        .await;
+    let multisig_payload = construct_multisig_txn_transfer_payload(owner_account.address(), 1000);
+
+    // Queue the transaction with a short expiration, while the clock is still before it.
+    let now_secs = context.get_latest_ledger_info().timestamp() / 1_000_000;
+    let expiration_secs = now_secs + 10;
+    let status = context
+        .create_multisig_transaction_with_expiration(
+            owner_account,
+            multisig_account,
+            multisig_payload,
+            expiration_secs,
+        )
+        .await;
+    assert!(matches!(status, TransactionStatus::Keep(_)));
+
+    // Let the expiration pass before executing the queued transaction.
+    context.set_fake_time_usecs(Duration::from_secs(expiration_secs + 1).as_micros() as u64);
+    let status = context
+        .execute_multisig_transaction_with_expiration(
+            owner_account,
+            multisig_account,
+            expiration_secs,
+        )
+        .await;
+    assert_eq!(
+        status,
+        TransactionStatus::Discard(StatusCode::TRANSACTION_EXPIRED)
+    );
+
+    // The transfer was not executed and the transaction is still pending.
+    assert_eq!(1000, context.get_apt_balance(multisig_account).await);
+    let last_resolved = context
+        .call_view_u64(
+            "0x1::multisig_account::last_resolved_sequence_number",
+            &[],
+            vec![json!(multisig_account.to_hex_literal())],
+        )
+        .await;
+    assert_eq!(last_resolved, 0);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_votes_with_timestamps() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account_1 = &mut context.create_account().await;
+    let owner_account_2 = &mut context.create_account().await;
+    let owner_account_3 = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(
+            owner_account_1,
+            vec![owner_account_2.address(), owner_account_3.address()],
+            3,    /* 3-of-3 */
+            1000, /* initial balance */
+        )
+        .await;
+
+    // The creator implicitly approves the transaction.
+    let multisig_payload = construct_multisig_txn_transfer_payload(owner_account_1.address(), 1000);
+    let transaction_id = context
+        .create_multisig_transaction(owner_account_1, multisig_account, multisig_payload)
+        .await;
+    let creation_hash = context.get_last_transaction_hash(owner_account_1).await;
+
+    // The other owners approve at clearly separated times.
+    let approval_time_2 =
+        context.get_latest_ledger_info().timestamp() + Duration::from_secs(60).as_micros() as u64;
+    context.set_fake_time_usecs(approval_time_2);
+    let approval_hash_2 = context
+        .approve_multisig_transaction(owner_account_2, multisig_account, transaction_id)
         .await;
+    let approval_time_3 = approval_time_2 + Duration::from_secs(60).as_micros() as u64;
+    context.set_fake_time_usecs(approval_time_3);
+    let approval_hash_3 = context
+        .approve_multisig_transaction(owner_account_3, multisig_account, transaction_id)
+        .await;
+
+    let votes = context
+        .get_multisig_votes(multisig_account, transaction_id, &[
+            creation_hash,
+            approval_hash_2,
+            approval_hash_3,
+        ])
+        .await;
+    let owners: Vec<_> = votes.iter().map(|(owner, _, _)| *owner).collect();
+    assert_eq!(owners, vec![
+        owner_account_1.address(),
+        owner_account_2.address(),
+        owner_account_3.address()
+    ]);
+    assert!(votes.iter().all(|(_, approved, _)| *approved));
+
+    let timestamps: Vec<_> = votes
+        .iter()
+        .map(|(_, _, timestamp)| timestamp.unwrap())
+        .collect();
+    assert!(timestamps[0] < approval_time_2);
+    assert!(approval_time_2 <= timestamps[1] && timestamps[1] < approval_time_3);
+    assert!(approval_time_3 <= timestamps[2]);
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn test_multisig_transaction_with_matching_payload() {
+async fn test_multisig_transaction_ids_are_never_reused() {
     let mut context = new_test_context(current_function_name!());
     let owner_account = &mut context.create_account().await;
     let multisig_account = context
-        .create_multisig_account(owner_account, vec![], 1, 1000)
+        .create_multisig_account(owner_account, vec![], 1, 3000)
         .await;
-    assert_eq!(1000, context.get_apt_balance(multisig_account).await);
+    // Each queued transaction gets a strictly greater id.
+    let mut ids = vec![];
+    for _ in 0..3 {
+        let expected_id = next_sequence_number(&context, multisig_account).await;
+        let multisig_payload =
+            construct_multisig_txn_transfer_payload(owner_account.address(), 1000);
+        let id = context
+            .create_multisig_transaction(owner_account, multisig_account, multisig_payload)
+            .await;
+        assert_eq!(id, expected_id);
+        assert!(ids.last().map_or(true, |last| id > *last));
+        ids.push(id);
+    }
+    assert_eq!(ids, vec![1, 2, 3]);
+
+    // Executing the transactions removes them from the queue, but their ids are not freed.
+    for _ in &ids {
+        context
+            .execute_multisig_transaction(owner_account, multisig_account, 202)
+            .await;
+    }
+    assert_eq!(0, context.get_apt_balance(multisig_account).await);
+    assert_eq!(
+        context
+            .call_view_u64(
+                "0x1::multisig_account::last_resolved_sequence_number",
+                &[],
+                vec![json!(multisig_account.to_hex_literal())],
+            )
+            .await,
+        3
+    );
+
     let multisig_payload = construct_multisig_txn_transfer_payload(owner_account.address(), 1000);
-    context
-        .create_multisig_transaction(owner_account, multisig_account, multisig_payload.clone())
+    let id = context
+        .create_multisig_transaction(owner_account, multisig_account, multisig_payload)
         .await;
-    context
-        .execute_multisig_transaction_with_payload(
-            owner_account,
-            multisig_account,
-            "0x1::aptos_account::transfer",
+    assert_eq!(id, 4);
+    let pending = context
+        .call_view(
+            "0x1::multisig_account::get_pending_transactions",
             &[],
-            &[&owner_account.address().to_hex_literal(), "1000"],
-            202,
+            vec![json!(multisig_account.to_hex_literal())],
         )
         .await;
+    let pending = pending[0].as_array().unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(next_sequence_number(&context, multisig_account).await, 5);
+    for id in &ids {
+        assert!(
+            !context
+                .call_view_bool("0x1::multisig_account::can_be_executed", &[], vec![
+                    json!(multisig_account.to_hex_literal()),
+                    json!(id.to_string()),
+                ])
+                .await,
+            "Executed transaction {} must not be executable again",
+            id
+        );
+    }
+}
 
-    // The multisig tx that transfers away 1000 APT should have succeeded.
-    assert_eq!(0, context.get_apt_balance(multisig_account).await);
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_threshold_boundary() {
+    assert_threshold_boundary(current_function_name!(), 5, 3).await;
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn test_multisig_transaction_with_mismatching_payload() {
+async fn test_multisig_transaction_approve_by_non_owner_fails() {
     let mut context = new_test_context(current_function_name!());
-    let owner_account = &mut context.create_account().await;
+    let owner_account_1 = &mut context.create_account().await;
+    let owner_account_2 = &mut context.create_account().await;
+    let non_owner_account = &mut context.create_account().await;
     let multisig_account = context
-        .create_multisig_account(owner_account, vec![], 1, 1000)
+        .create_multisig_account(
+            owner_account_1,
+            vec![owner_account_2.address()],
+            2,    /* 2-of-2 */
+            1000, /* initial balance */
+        )
         .await;
-    let multisig_payload = construct_multisig_txn_transfer_payload(owner_account.address(), 1000);
-    context
-        .create_multisig_transaction(owner_account, multisig_account, multisig_payload.clone())
+
+    let multisig_payload = construct_multisig_txn_transfer_payload(owner_account_1.address(), 1000);
+    let transaction_id = context
+        .create_multisig_transaction(owner_account_1, multisig_account, multisig_payload)
         .await;
 
-    // The multisig transaction execution should fail due to the payload mismatch
-    // amount being different (1000 vs 2000).
-    context
-        .execute_multisig_transaction_with_payload(
-            owner_account,
-            multisig_account,
-            "0x1::aptos_account::transfer",
-            &[],
-            &[&owner_account.address().to_hex_literal(), "2000"],
-            400,
-        )
+    // An account outside the owner set cannot vote on the transaction.
+    let status = context
+        .try_approve_multisig_transaction(non_owner_account, multisig_account, transaction_id)
         .await;
-    // Balance didn't change since the target transaction failed.
-    assert_eq!(1000, context.get_apt_balance(multisig_account).await);
+    match status {
+        TransactionStatus::Keep(ExecutionStatus::MoveAbort { code, .. }) => {
+            // error::permission_denied(ENOT_OWNER)
+            assert_eq!(code, (0x5 << 16) | 2003);
+        },
+        status => panic!("Expected the approval to abort, got {:?}", status),
+    }
 
-    // Excuting the transaction with the correct payload should succeed.
-    context
-        .execute_multisig_transaction_with_payload(
-            owner_account,
+    // The rejected approval must not count towards the threshold.
+    let simulation_resp = context
+        .simulate_multisig_transaction(
+            owner_account_1,
             multisig_account,
             "0x1::aptos_account::transfer",
             &[],
-            &[&owner_account.address().to_hex_literal(), "1000"],
-            202,
+            &[&owner_account_1.address().to_hex_literal(), "1000"],
+            200,
         )
         .await;
+    let simulation_resp = &simulation_resp.as_array().unwrap()[0];
+    assert!(!simulation_resp["success"].as_bool().unwrap());
+    assert!(simulation_resp["vm_status"]
+        .as_str()
+        .unwrap()
+        .contains("MULTISIG_TRANSACTION_INSUFFICIENT_APPROVALS"));
+    assert_eq!(1000, context.get_apt_balance(multisig_account).await);
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -473,15 +1191,69 @@ async fn test_multisig_transaction_simulation() {
         .await;
     // Validate that the simulation did successfully execute a transfer of 1000 coins from the
     // multisig account.
-    let simulation_resp = &simulation_resp.as_array().unwrap()[0];
-    assert!(simulation_resp["success"].as_bool().unwrap());
-    let withdraw_event = &simulation_resp["events"].as_array().unwrap()[0];
+    assert!(simulation_resp.as_array().unwrap()[0]["success"]
+        .as_bool()
+        .unwrap());
+    let events = TestContext::simulated_events(&simulation_resp);
+    let withdraw_event =
+        TestContext::find_event_by_type(events, "0x1::fungible_asset::Withdraw").unwrap();
+    assert_eq!(withdraw_event.amount(), 1000);
+
+    // Events are in emission order: the deposit follows the withdrawal, and the fee statement is
+    // emitted last.
+    assert_eq!(
+        events[1]["type"].as_str().unwrap(),
+        "0x1::fungible_asset::Deposit"
+    );
     assert_eq!(
-        withdraw_event["type"].as_str().unwrap(),
-        "0x1::fungible_asset::Withdraw"
+        events.last().unwrap()["type"].as_str().unwrap(),
+        "0x1::transaction_fee::FeeStatement"
     );
-    let withdrawn_amount = withdraw_event["data"]["amount"].as_str().unwrap();
-    assert_eq!(withdrawn_amount, "1000");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_queued_multisig_transaction_simulation_matches_inline_payload() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(owner_account, vec![], 1, 1000)
+        .await;
+
+    let multisig_payload = construct_multisig_txn_transfer_payload(owner_account.address(), 1000);
+    context
+        .create_multisig_transaction(owner_account, multisig_account, multisig_payload)
+        .await;
+
+    let inline_resp = context
+        .simulate_multisig_transaction(
+            owner_account,
+            multisig_account,
+            "0x1::aptos_account::transfer",
+            &[],
+            &[&owner_account.address().to_hex_literal(), "1000"],
+            200,
+        )
+        .await;
+    let queued_resp = context
+        .simulate_queued_multisig_transaction(owner_account, multisig_account, 1, 200)
+        .await;
+
+    let inline_resp = &inline_resp.as_array().unwrap()[0];
+    let queued_resp = &queued_resp.as_array().unwrap()[0];
+    assert!(queued_resp["success"].as_bool().unwrap());
+    assert_eq!(queued_resp["vm_status"], inline_resp["vm_status"]);
+    // Gas differs with the size of the transaction, so the fee statement is not compared.
+    let non_fee_events = |resp: &Value| {
+        resp["events"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|event| event["type"] != "0x1::transaction_fee::FeeStatement")
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(non_fee_events(queued_resp), non_fee_events(inline_resp));
+    assert_eq!(1000, context.get_apt_balance(multisig_account).await);
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -500,12 +1272,12 @@ async fn test_multisig_transaction_simulation_2_of_3() {
         .await;
 
     let multisig_payload = construct_multisig_txn_transfer_payload(owner_account_1.address(), 1000);
-    context
+    let transaction_id = context
         .create_multisig_transaction(owner_account_1, multisig_account, multisig_payload.clone())
         .await;
 
     context
-        .approve_multisig_transaction(owner_account_2, multisig_account, 1)
+        .approve_multisig_transaction(owner_account_2, multisig_account, transaction_id)
         .await;
 
     // Simulate the multisig transaction
@@ -521,15 +1293,181 @@ async fn test_multisig_transaction_simulation_2_of_3() {
         .await;
     // Validate that the simulation did successfully execute a transfer of 1000 coins from the
     // multisig account.
-    let simulation_resp = &simulation_resp.as_array().unwrap()[0];
-    assert!(simulation_resp["success"].as_bool().unwrap());
-    let withdraw_event = &simulation_resp["events"].as_array().unwrap()[0];
+    assert!(simulation_resp.as_array().unwrap()[0]["success"]
+        .as_bool()
+        .unwrap());
+    let events = TestContext::simulated_events(&simulation_resp);
+    let withdraw_event =
+        TestContext::find_event_by_type(events, "0x1::fungible_asset::Withdraw").unwrap();
+    assert_eq!(withdraw_event.amount(), 1000);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_incompatible_module_upgrade_fails() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(owner_account, vec![], 1, 0)
+        .await;
+    let module_path = format!(
+        "/accounts/{}/module/upgrade",
+        multisig_account.to_hex_literal()
+    );
+
+    // Publish the initial version of the package from the multisig account.
+    let multisig_payload =
+        construct_multisig_txn_publish_payload("pack_upgrade_v1", multisig_account);
+    context
+        .create_multisig_transaction(owner_account, multisig_account, multisig_payload)
+        .await;
+    context
+        .execute_multisig_transaction(owner_account, multisig_account, 202)
+        .await;
+    assert_eq!(
+        None,
+        context
+            .get_last_multisig_execution_error(owner_account)
+            .await
+    );
+    let module = context.get(&module_path).await;
+
+    // Packages are published as compatible by default, which is what allows the upgrade to be
+    // checked for compatibility at all.
+    assert_eq!(
+        context.get_module_upgrade_policy(multisig_account, "upgrade"),
+        UpgradePolicy::compat()
+    );
+
+    // Upgrading to a version that changes a struct layout should be rejected. The multisig
+    // transaction is still executed, with the failure tracked on chain.
+    let multisig_payload =
+        construct_multisig_txn_publish_payload("pack_upgrade_v2", multisig_account);
+    context
+        .create_multisig_transaction(owner_account, multisig_account, multisig_payload)
+        .await;
+    context
+        .execute_multisig_transaction(owner_account, multisig_account, 202)
+        .await;
+    let execution_error = context
+        .get_last_multisig_execution_error(owner_account)
+        .await
+        .unwrap();
+    assert_eq!(execution_error["error_type"], json!("VMError"));
+    assert_eq!(
+        execution_error["error_code"],
+        json!((StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE as u64).to_string())
+    );
+
+    // The module is unchanged.
+    assert_eq!(module, context.get(&module_path).await);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_multi_module_package_deployment() {
+    let mut context = new_test_context(current_function_name!());
+    let owner_account = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(owner_account, vec![], 1, 0)
+        .await;
+    assert!(context
+        .list_account_modules(multisig_account)
+        .await
+        .is_empty());
+
+    // Publish a package with several modules from the multisig account.
+    let multisig_payload =
+        construct_multisig_txn_publish_payload("pack_multi_module", multisig_account);
+    context
+        .create_multisig_transaction(owner_account, multisig_account, multisig_payload)
+        .await;
+    context
+        .execute_multisig_transaction(owner_account, multisig_account, 202)
+        .await;
+    assert_eq!(
+        None,
+        context
+            .get_last_multisig_execution_error(owner_account)
+            .await
+    );
+
+    // All modules of the package have been published.
+    assert_eq!(context.list_account_modules(multisig_account).await, vec![
+        "counter", "entry", "message"
+    ]);
+
+    // The package and its modules are recorded in the registry of the multisig account.
+    let registry = context.get_package_registry(multisig_account);
+    assert_eq!(registry.packages.len(), 1);
+    let package = &registry.packages[0];
+    assert_eq!(package.name, "pack_multi_module");
+    assert_eq!(package.upgrade_number, 0);
+    assert_eq!(package.upgrade_policy, UpgradePolicy::compat());
+    let mut module_names: Vec<_> = package.modules.iter().map(|m| m.name.as_str()).collect();
+    module_names.sort();
+    assert_eq!(module_names, vec!["counter", "entry", "message"]);
+    assert!(package
+        .deps
+        .iter()
+        .any(|dep| dep.package_name == "AptosFramework" && dep.account == AccountAddress::ONE));
+
+    // Values of the published types can be decoded with their field names.
+    let counter_type = format!("{}::counter::Counter", multisig_account.to_hex_literal())
+        .parse()
+        .unwrap();
+    assert_eq!(
+        context.decode_move_value_as_json(&bcs::to_bytes(&7u64).unwrap(), &counter_type),
+        json!({ "value": "7" })
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_multisig_transaction_calls_third_party_module() {
+    let mut context = new_test_context(current_function_name!());
+    let publisher = &mut context.create_account().await;
+    let owner_account = &mut context.create_account().await;
+    let multisig_account = context
+        .create_multisig_account(owner_account, vec![], 1, 0)
+        .await;
+
+    // A separate account publishes the package, so the modules are not owned by the multisig.
+    let path =
+        PathBuf::from(std::env!("CARGO_MANIFEST_DIR")).join("src/tests/move/pack_multi_module");
+    let payload = TestContext::build_package(path, vec![("addr".to_string(), publisher.address())]);
+    context.publish_package(publisher, payload).await;
+
+    let multisig_payload = construct_multisig_txn_entry_function_payload(
+        publisher.address(),
+        ident_str!("entry"),
+        ident_str!("increment"),
+        vec![],
+    );
+    context
+        .create_multisig_transaction(owner_account, multisig_account, multisig_payload)
+        .await;
+    context
+        .execute_multisig_transaction(owner_account, multisig_account, 202)
+        .await;
     assert_eq!(
-        withdraw_event["type"].as_str().unwrap(),
-        "0x1::fungible_asset::Withdraw"
+        None,
+        context
+            .get_last_multisig_execution_error(owner_account)
+            .await
     );
-    let withdrawn_amount = withdraw_event["data"]["amount"].as_str().unwrap();
-    assert_eq!(withdrawn_amount, "1000");
+
+    // The third-party module was called with the multisig account as signer.
+    let counter = context
+        .api_get_account_resource(
+            multisig_account,
+            &publisher.address().to_hex_literal(),
+            "counter",
+            "Counter",
+        )
+        .await;
+    assert_eq!(counter["data"]["value"], "1");
+    assert!(context
+        .list_account_modules(multisig_account)
+        .await
+        .is_empty());
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -651,6 +1589,78 @@ async fn test_simulate_multisig_transaction_should_charge_gas_against_sender() {
     assert!(simulation_resp["success"].as_bool().unwrap());
 }
 
+async fn next_sequence_number(context: &TestContext, multisig_account: AccountAddress) -> u64 {
+    context
+        .call_view_u64("0x1::multisig_account::next_sequence_number", &[], vec![
+            json!(multisig_account.to_hex_literal()),
+        ])
+        .await
+}
+
+/// Checks that a transaction of a `threshold`-of-`num_owners` multisig account can't be executed
+/// with one approval less than the threshold, but can be with exactly the threshold.
+async fn assert_threshold_boundary(test_name: String, num_owners: usize, threshold: u64) {
+    let mut context = new_test_context(test_name);
+    let mut owners = vec![];
+    for _ in 0..num_owners {
+        owners.push(context.create_account().await);
+    }
+    let (creator, others) = owners.split_first_mut().unwrap();
+    let multisig_account = context
+        .create_multisig_account(
+            creator,
+            others.iter().map(|owner| owner.address()).collect(),
+            threshold,
+            1000, /* initial balance */
+        )
+        .await;
+
+    let multisig_payload = construct_multisig_txn_transfer_payload(creator.address(), 1000);
+    let transaction_id = context
+        .create_multisig_transaction(creator, multisig_account, multisig_payload)
+        .await;
+
+    // The creator implicitly approves, so the other owners bring the count up to one less than
+    // the threshold.
+    for owner in others.iter_mut().take(threshold as usize - 2) {
+        context
+            .approve_multisig_transaction(owner, multisig_account, transaction_id)
+            .await;
+    }
+    let approvals = |votes: Vec<(AccountAddress, bool, Option<u64>)>| {
+        votes.iter().filter(|(_, approved, _)| *approved).count() as u64
+    };
+    assert_eq!(
+        approvals(
+            context
+                .get_multisig_votes(multisig_account, transaction_id, &[])
+                .await
+        ),
+        threshold - 1
+    );
+    context
+        .assert_multisig_transaction_not_executable(creator, multisig_account, transaction_id)
+        .await;
+    assert_eq!(1000, context.get_apt_balance(multisig_account).await);
+
+    let owner = &mut others[threshold as usize - 2];
+    context
+        .approve_multisig_transaction(owner, multisig_account, transaction_id)
+        .await;
+    assert_eq!(
+        approvals(
+            context
+                .get_multisig_votes(multisig_account, transaction_id, &[])
+                .await
+        ),
+        threshold
+    );
+    context
+        .execute_multisig_transaction(creator, multisig_account, 202)
+        .await;
+    assert_eq!(0, context.get_apt_balance(multisig_account).await);
+}
+
 async fn assert_owners(
     context: &TestContext,
     multisig_account: AccountAddress,
@@ -708,3 +1718,82 @@ fn construct_multisig_txn_transfer_payload(recipient: AccountAddress, amount: u6
     ))
     .unwrap()
 }
+
+fn construct_multisig_txn_entry_function_payload(
+    module_address: AccountAddress,
+    module_name: &IdentStr,
+    function: &IdentStr,
+    args: Vec<MoveValue>,
+) -> Vec<u8> {
+    bcs::to_bytes(&MultisigTransactionPayload::EntryFunction(
+        EntryFunction::new(
+            ModuleId::new(module_address, module_name.to_owned()),
+            function.to_owned(),
+            vec![],
+            serialize_values(&args),
+        ),
+    ))
+    .unwrap()
+}
+
+fn construct_multisig_txn_publish_payload(package: &str, publisher: AccountAddress) -> Vec<u8> {
+    let path = PathBuf::from(std::env!("CARGO_MANIFEST_DIR"))
+        .join("src/tests/move")
+        .join(package);
+    let (metadata, code) =
+        TestContext::build_package_from_dir(path, vec![("addr".to_string(), publisher)]);
+    match aptos_stdlib::code_publish_package_txn(metadata, code) {
+        TransactionPayload::EntryFunction(entry_function) => {
+            bcs::to_bytes(&MultisigTransactionPayload::EntryFunction(entry_function)).unwrap()
+        },
+        _ => unreachable!("Publishing a package is an entry function call"),
+    }
+}
+
+fn construct_multisig_txn_fa_transfer_payload(
+    fa_metadata: AccountAddress,
+    recipient: AccountAddress,
+    amount: u64,
+) -> Vec<u8> {
+    bcs::to_bytes(&MultisigTransactionPayload::EntryFunction(
+        EntryFunction::new(
+            ModuleId::new(
+                CORE_CODE_ADDRESS,
+                ident_str!("primary_fungible_store").to_owned(),
+            ),
+            ident_str!("transfer").to_owned(),
+            vec!["0x1::fungible_asset::Metadata".parse().unwrap()],
+            serialize_values(&vec![
+                MoveValue::Address(fa_metadata),
+                MoveValue::Address(recipient),
+                MoveValue::U64(amount),
+            ]),
+        ),
+    ))
+    .unwrap()
+}
+
+fn construct_multisig_txn_update_metadata_payload(metadata: &[(&str, Vec<u8>)]) -> Vec<u8> {
+    // A String is a struct with a single vector<u8> field.
+    let keys = metadata
+        .iter()
+        .map(|(key, _)| {
+            MoveValue::Struct(MoveStruct::Runtime(vec![MoveValue::vector_u8(
+                key.as_bytes().to_vec(),
+            )]))
+        })
+        .collect();
+    let values = metadata
+        .iter()
+        .map(|(_, value)| MoveValue::vector_u8(value.clone()))
+        .collect();
+    bcs::to_bytes(&MultisigTransactionPayload::EntryFunction(
+        EntryFunction::new(
+            ModuleId::new(CORE_CODE_ADDRESS, ident_str!("multisig_account").to_owned()),
+            ident_str!("update_metadata").to_owned(),
+            vec![],
+            serialize_values(&vec![MoveValue::Vector(keys), MoveValue::Vector(values)]),
+        ),
+    ))
+    .unwrap()
+}
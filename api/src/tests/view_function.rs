@@ -5,7 +5,7 @@ use super::{new_test_context, new_test_context_with_config};
 use aptos_api_test_context::{current_function_name, TestContext};
 use aptos_cached_packages::aptos_stdlib;
 use aptos_config::config::{NodeConfig, ViewFilter, ViewFunctionId};
-use aptos_types::account_address::AccountAddress;
+use aptos_types::account_address::{create_multisig_account_address, AccountAddress};
 use serde_json::{json, Value};
 use std::{path::PathBuf, str::FromStr};
 
@@ -43,6 +43,43 @@ async fn test_simple_view() {
     context.check_golden_output_no_prune(resp);
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_typed_view_helpers() {
+    let mut context = new_test_context(current_function_name!());
+    let creator = &mut context.gen_account();
+    let owner = &mut context.gen_account();
+    let txn1 = context.mint_user_account(creator).await;
+    let txn2 = context.account_transfer(creator, owner, 100_000);
+
+    context.commit_block(&vec![txn1, txn2]).await;
+
+    let balance = context
+        .call_view_u64("0x1::coin::balance", &["0x1::aptos_coin::AptosCoin"], vec![
+            json!(owner.address().to_hex_literal()),
+        ])
+        .await;
+    assert_eq!(balance, 100_000);
+
+    let exists = context
+        .call_view_bool("0x1::account::exists_at", &[], vec![json!(owner
+            .address()
+            .to_hex_literal())])
+        .await;
+    assert!(exists);
+
+    let multisig_address = context
+        .call_view_address(
+            "0x1::multisig_account::get_next_multisig_account_address",
+            &[],
+            vec![json!(creator.address().to_hex_literal())],
+        )
+        .await;
+    assert_eq!(
+        multisig_address,
+        create_multisig_account_address(creator.address(), creator.sequence_number())
+    );
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_view_gas_used_header() {
     let mut context = new_test_context(current_function_name!());
@@ -1521,6 +1521,10 @@ impl TransactionsApi {
     // from these types in render_transactions.
     /// Simulate a transaction in the VM
     ///
+    /// Simulates the transaction against the latest state. Events of the simulated transaction
+    /// are always returned in the order in which they were emitted during execution, so that
+    /// callers can rely on their positions.
+    ///
     /// Note: this returns a `Vec<UserTransaction>`, but for backwards compatibility, this can't
     /// be removed even though, there is only one possible transaction
     pub fn simulate(
@@ -1615,6 +1619,7 @@ impl TransactionsApi {
             output.gas_used(),
             exe_status,
         );
+        // Events are translated in place, which keeps them in emission order.
         let mut events = output.events().to_vec();
         let _ = self
             .context
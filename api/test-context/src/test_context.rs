@@ -4,7 +4,7 @@
 use super::{golden_output::GoldenOutputs, pretty};
 use aptos_api::{attach_poem_to_runtime, BasicError, Context};
 use aptos_api_types::{
-    mime_types, HexEncodedBytes, TransactionOnChainData, X_APTOS_CHAIN_ID,
+    mime_types, AsConverter, HexEncodedBytes, TransactionOnChainData, X_APTOS_CHAIN_ID,
     X_APTOS_LEDGER_TIMESTAMP, X_APTOS_LEDGER_VERSION,
 };
 use aptos_cached_packages::aptos_stdlib;
@@ -19,12 +19,19 @@ use aptos_crypto::{ed25519::Ed25519PrivateKey, hash::HashValue, SigningKey};
 use aptos_db::AptosDB;
 use aptos_executor::{block_executor::BlockExecutor, db_bootstrapper};
 use aptos_executor_types::BlockExecutorTrait;
-use aptos_framework::{BuildOptions, BuiltPackage};
+use aptos_framework::{
+    natives::code::{PackageRegistry, UpgradePolicy},
+    BuildOptions, BuiltPackage,
+};
 use aptos_indexer_grpc_table_info::internal_indexer_db_service::MockInternalIndexerDBService;
 use aptos_mempool::mocks::MockSharedMempool;
 use aptos_mempool_notifications::MempoolNotificationSender;
 use aptos_sdk::{
     bcs,
+    move_types::{
+        identifier::Identifier,
+        language_storage::{ModuleId, TypeTag},
+    },
     transaction_builder::TransactionFactory,
     types::{
         account_config::aptos_test_root_address, get_apt_primary_store_address,
@@ -45,9 +52,11 @@ use aptos_types::{
     function_info::FunctionInfo,
     indexer::indexer_db_reader::IndexerReader,
     ledger_info::{LedgerInfo, LedgerInfoWithSignatures},
+    on_chain_config::OnChainConfig,
+    state_store::{state_key::StateKey, TStateView},
     transaction::{
-        signature_verified_transaction::into_signature_verified_block, Transaction,
-        TransactionPayload, TransactionStatus, Version,
+        signature_verified_transaction::into_signature_verified_block, EntryFunction, Multisig,
+        MultisigTransactionPayload, Transaction, TransactionPayload, TransactionStatus, Version,
     },
 };
 use aptos_vm::aptos_vm::AptosVMBlockExecutor;
@@ -58,6 +67,7 @@ use rand::SeedableRng;
 use serde_json::{json, Value};
 use std::{
     boxed::Box,
+    collections::HashMap,
     net::SocketAddr,
     path::PathBuf,
     sync::Arc,
@@ -225,6 +235,70 @@ pub fn new_test_context_inner(
     )
 }
 
+/// A typed operation queued as a multisig transaction, to be executed by the multisig account.
+#[derive(Clone, Debug)]
+pub enum GovernanceStep {
+    AddOwners(Vec<AccountAddress>),
+    RemoveOwners(Vec<AccountAddress>),
+    ChangeThreshold(u64),
+    CustomPayload(EntryFunction),
+}
+
+impl GovernanceStep {
+    fn into_multisig_payload(self) -> Vec<u8> {
+        let payload = match self {
+            GovernanceStep::AddOwners(owners) => aptos_stdlib::multisig_account_add_owners(owners),
+            GovernanceStep::RemoveOwners(owners) => {
+                aptos_stdlib::multisig_account_remove_owners(owners)
+            },
+            GovernanceStep::ChangeThreshold(signatures_required) => {
+                aptos_stdlib::multisig_account_update_signatures_required(signatures_required)
+            },
+            GovernanceStep::CustomPayload(entry_function) => {
+                TransactionPayload::EntryFunction(entry_function)
+            },
+        };
+        match payload {
+            TransactionPayload::EntryFunction(entry_function) => {
+                bcs::to_bytes(&MultisigTransactionPayload::EntryFunction(entry_function)).unwrap()
+            },
+            _ => unreachable!("Governance steps are entry function calls"),
+        }
+    }
+}
+
+/// Event returned by the API, with typed accessors for common data fields.
+#[derive(Clone, Debug)]
+pub struct TypedEvent {
+    pub type_str: String,
+    pub data: Value,
+}
+
+impl TypedEvent {
+    /// Returns the `amount` data field, e.g., of withdraw and deposit events.
+    pub fn amount(&self) -> u64 {
+        self.data["amount"]
+            .as_str()
+            .and_then(|amount| amount.parse().ok())
+            .unwrap_or_else(|| panic!("Event {} has no u64 amount: {}", self.type_str, self.data))
+    }
+}
+
+/// Gas used and VM status of a committed transaction, as returned by the API.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommittedTransactionOutcome {
+    pub gas_used: u64,
+    pub vm_status: String,
+}
+
+/// Time taken to record the approvals of a multisig transaction, and the gas used by each
+/// approval in the order the owners approved.
+#[derive(Clone, Debug)]
+pub struct MultisigApprovalTiming {
+    pub elapsed: Duration,
+    pub gas_used: Vec<u64>,
+}
+
 #[derive(Clone)]
 pub struct TestContext {
     pub context: Context,
@@ -421,13 +495,81 @@ impl TestContext {
     }
 
     pub async fn is_feature_enabled(&self, feature: u64) -> bool {
+        self.call_view_bool("0x1::features::is_enabled", &[], vec![json!(
+            feature.to_string()
+        )])
+        .await
+    }
+
+    /// Calls a view function that returns a single value and returns that value as JSON.
+    pub async fn call_view(&self, function: &str, type_args: &[&str], args: Vec<Value>) -> Value {
         let request = json!({
-            "function":"0x1::features::is_enabled",
-            "arguments": vec![feature.to_string()],
-            "type_arguments": Vec::<String>::new(),
+            "function": function,
+            "type_arguments": type_args,
+            "arguments": args,
         });
         let resp = self.post("/view", request).await;
-        resp[0].as_bool().unwrap()
+        let values = resp
+            .as_array()
+            .unwrap_or_else(|| panic!("View function {} returned a non-array: {}", function, resp));
+        assert_eq!(
+            values.len(),
+            1,
+            "View function {} returned {} values, expected 1: {}",
+            function,
+            values.len(),
+            resp
+        );
+        values[0].clone()
+    }
+
+    /// Calls a view function that returns a single u64, which the API encodes as a string.
+    pub async fn call_view_u64(&self, function: &str, type_args: &[&str], args: Vec<Value>) -> u64 {
+        let value = self.call_view(function, type_args, args).await;
+        value
+            .as_str()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or_else(|| {
+                panic!(
+                    "View function {} returned {}, expected a u64",
+                    function, value
+                )
+            })
+    }
+
+    /// Calls a view function that returns a single bool.
+    pub async fn call_view_bool(
+        &self,
+        function: &str,
+        type_args: &[&str],
+        args: Vec<Value>,
+    ) -> bool {
+        let value = self.call_view(function, type_args, args).await;
+        value.as_bool().unwrap_or_else(|| {
+            panic!(
+                "View function {} returned {}, expected a bool",
+                function, value
+            )
+        })
+    }
+
+    /// Calls a view function that returns a single address.
+    pub async fn call_view_address(
+        &self,
+        function: &str,
+        type_args: &[&str],
+        args: Vec<Value>,
+    ) -> AccountAddress {
+        let value = self.call_view(function, type_args, args).await;
+        value
+            .as_str()
+            .and_then(|v| AccountAddress::from_hex_literal(v).ok())
+            .unwrap_or_else(|| {
+                panic!(
+                    "View function {} returned {}, expected an address",
+                    function, value
+                )
+            })
     }
 
     pub fn latest_state_view(&self) -> DbStateView {
@@ -513,6 +655,18 @@ impl TestContext {
         )
     }
 
+    /// Mints `amount` of APT to `address` from the root account and commits it in its own block.
+    pub async fn fund_account(&mut self, address: AccountAddress, amount: u64) {
+        let mut root = self.root_account().await;
+        let factory = self.transaction_factory();
+        let txn = root.sign_with_transaction_builder(
+            factory
+                .mint(address, amount)
+                .expiration_timestamp_secs(u64::MAX),
+        );
+        self.commit_block(&[txn]).await;
+    }
+
     pub async fn add_dispatchable_authentication_function(
         &self,
         account: &LocalAccount,
@@ -543,6 +697,36 @@ impl TestContext {
         .await;
     }
 
+    /// Same as [Self::execute_multisig_transaction], but expects the transaction to be accepted
+    /// and returns the gas used and VM status of the committed transaction.
+    pub async fn execute_multisig_transaction_with_outcome(
+        &mut self,
+        owner: &mut LocalAccount,
+        multisig_account: AccountAddress,
+    ) -> CommittedTransactionOutcome {
+        let resp = self
+            .api_execute_txn_expecting(
+                owner,
+                json!({
+                    "type": "multisig_payload",
+                    "multisig_address": multisig_account.to_hex_literal(),
+                }),
+                202,
+            )
+            .await;
+        self.get_committed_outcome(resp["hash"].as_str().unwrap())
+            .await
+    }
+
+    /// Returns the gas used and VM status of the committed transaction with the given hash.
+    pub async fn get_committed_outcome(&self, hash: &str) -> CommittedTransactionOutcome {
+        let txn = self.get(&format!("/transactions/by_hash/{}", hash)).await;
+        CommittedTransactionOutcome {
+            gas_used: txn["gas_used"].as_str().unwrap().parse().unwrap(),
+            vm_status: txn["vm_status"].as_str().unwrap().to_string(),
+        }
+    }
+
     pub async fn execute_multisig_transaction_with_payload(
         &mut self,
         owner: &mut LocalAccount,
@@ -569,6 +753,43 @@ impl TestContext {
         .await;
     }
 
+    /// Decodes the BCS-encoded Move value `bytes` of type `type_tag` into the JSON the API would
+    /// return for it, resolving struct layouts from the modules at the latest version.
+    pub fn decode_move_value_as_json(&self, bytes: &[u8], type_tag: &TypeTag) -> Value {
+        let state_view = self.context.latest_state_view().unwrap();
+        let converter =
+            state_view.as_converter(self.context.db.clone(), self.context.indexer_reader.clone());
+        let value = converter.try_into_move_value(type_tag, bytes).unwrap();
+        serde_json::to_value(value).unwrap()
+    }
+
+    /// Returns the `code::PackageRegistry` resource at `address` at the latest version, which
+    /// records the metadata of all packages published there. Panics if there is none.
+    pub fn get_package_registry(&self, address: AccountAddress) -> PackageRegistry {
+        let state_key = StateKey::resource(&address, &PackageRegistry::struct_tag()).unwrap();
+        let bytes = self
+            .latest_state_view()
+            .get_state_value_bytes(&state_key)
+            .unwrap()
+            .unwrap_or_else(|| panic!("No package registry at {}", address));
+        bcs::from_bytes(&bytes).unwrap()
+    }
+
+    /// Returns the upgrade policy of the package containing `module` published at `address`, as
+    /// recorded in the `code::PackageRegistry` resource at the latest version.
+    pub fn get_module_upgrade_policy(
+        &self,
+        address: AccountAddress,
+        module: &str,
+    ) -> UpgradePolicy {
+        self.get_package_registry(address)
+            .packages
+            .into_iter()
+            .find(|package| package.modules.iter().any(|m| m.name == module))
+            .unwrap_or_else(|| panic!("Module {} not found in packages at {}", module, address))
+            .upgrade_policy
+    }
+
     pub fn get_indexer_reader(&self) -> Option<&Arc<dyn IndexerReader>> {
         self.context.get_indexer_reader()
     }
@@ -596,6 +817,89 @@ impl TestContext {
         multisig_address
     }
 
+    /// Same as [Self::create_multisig_account], but also checks that the created account is owned
+    /// by `account` and `additional_owners` and requires `signatures_required` signatures, so that
+    /// problems with account creation are reported where the account is created.
+    pub async fn create_multisig_account_checked(
+        &mut self,
+        account: &mut LocalAccount,
+        additional_owners: Vec<AccountAddress>,
+        signatures_required: u64,
+        initial_balance: u64,
+    ) -> AccountAddress {
+        let mut expected_owners = additional_owners.clone();
+        expected_owners.push(account.address());
+        expected_owners.sort();
+
+        let multisig_address = self
+            .create_multisig_account(
+                account,
+                additional_owners,
+                signatures_required,
+                initial_balance,
+            )
+            .await;
+
+        let multisig_account_resource = self
+            .api_get_account_resource(
+                multisig_address,
+                "0x1",
+                "multisig_account",
+                "MultisigAccount",
+            )
+            .await;
+        let mut owners = multisig_account_resource["data"]["owners"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|address| AccountAddress::from_hex_literal(address.as_str().unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        owners.sort();
+        assert_eq!(
+            owners, expected_owners,
+            "Multisig account {} was created with unexpected owners",
+            multisig_address
+        );
+        let num_signatures_required = multisig_account_resource["data"]["num_signatures_required"]
+            .as_str()
+            .unwrap()
+            .parse::<u64>()
+            .unwrap();
+        assert_eq!(
+            num_signatures_required, signatures_required,
+            "Multisig account {} was created with an unexpected signature threshold",
+            multisig_address
+        );
+
+        multisig_address
+    }
+
+    /// Creates a multisig account and funds it with `amount` of the fungible asset identified by
+    /// `fa_metadata`, transferred from the primary store of `account`.
+    pub async fn create_multisig_account_with_fa(
+        &mut self,
+        account: &mut LocalAccount,
+        additional_owners: Vec<AccountAddress>,
+        signatures_required: u64,
+        fa_metadata: AccountAddress,
+        amount: u64,
+    ) -> AccountAddress {
+        let factory = self.transaction_factory();
+        let multisig_address =
+            create_multisig_account_address(account.address(), account.sequence_number());
+        let create_multisig_txn = account.sign_with_transaction_builder(
+            factory
+                .create_multisig_account(additional_owners, signatures_required)
+                .expiration_timestamp_secs(u64::MAX),
+        );
+        self.commit_block(&vec![
+            create_multisig_txn,
+            self.fa_transfer_to(account, fa_metadata, multisig_address, amount),
+        ])
+        .await;
+        multisig_address
+    }
+
     pub async fn create_multisig_account_with_existing_account(
         &mut self,
         account: &mut LocalAccount,
@@ -616,12 +920,14 @@ impl TestContext {
         .await;
     }
 
+    /// Creates a multisig transaction and returns the id assigned to it, as recorded by the
+    /// emitted creation event.
     pub async fn create_multisig_transaction(
         &mut self,
         owner: &mut LocalAccount,
         multisig_account: AccountAddress,
         payload: Vec<u8>,
-    ) {
+    ) -> u64 {
         let factory = self.transaction_factory();
         let txn = owner.sign_with_transaction_builder(
             factory
@@ -629,36 +935,220 @@ impl TestContext {
                 .expiration_timestamp_secs(u64::MAX),
         );
         self.commit_block(&vec![txn]).await;
+
+        self.get_last_transaction_events(owner)
+            .await
+            .iter()
+            .find(|event| {
+                event["type"]
+                    .as_str()
+                    .unwrap()
+                    .contains("multisig_account::CreateTransaction")
+            })
+            .and_then(|event| event["data"]["sequence_number"].as_str())
+            .expect("Creating a multisig transaction must emit a creation event")
+            .parse()
+            .unwrap()
+    }
+
+    /// Queues the steps as multisig transactions, in order, and returns their assigned ids. The
+    /// steps are executed in the same order as they are queued.
+    pub async fn queue_governance_sequence(
+        &mut self,
+        owner: &mut LocalAccount,
+        multisig_account: AccountAddress,
+        steps: Vec<GovernanceStep>,
+    ) -> Vec<u64> {
+        let mut transaction_ids = vec![];
+        for step in steps {
+            let transaction_id = self
+                .create_multisig_transaction(owner, multisig_account, step.into_multisig_payload())
+                .await;
+            transaction_ids.push(transaction_id);
+        }
+        transaction_ids
     }
 
+    /// Creates a multisig transaction whose creating transaction expires at `expiration_secs`.
+    /// Returns the status instead of asserting it's kept, so callers can exercise the expiration path.
+    pub async fn create_multisig_transaction_with_expiration(
+        &mut self,
+        owner: &mut LocalAccount,
+        multisig_account: AccountAddress,
+        payload: Vec<u8>,
+        expiration_secs: u64,
+    ) -> TransactionStatus {
+        let factory = self.transaction_factory();
+        let txn = owner.sign_with_transaction_builder(
+            factory
+                .create_multisig_transaction(multisig_account, payload)
+                .expiration_timestamp_secs(expiration_secs),
+        );
+        let status = self.try_commit_block(&vec![txn]).await.pop().unwrap();
+        if !matches!(status, TransactionStatus::Keep(_)) {
+            // The transaction was not committed, so the sequence number was not consumed.
+            owner.decrement_sequence_number();
+        }
+        status
+    }
+
+    /// Executes the next pending multisig transaction with an executing transaction that expires
+    /// at `expiration_secs`. Returns the status instead of asserting it's kept, so callers can
+    /// exercise the expiration path.
+    pub async fn execute_multisig_transaction_with_expiration(
+        &mut self,
+        owner: &mut LocalAccount,
+        multisig_account: AccountAddress,
+        expiration_secs: u64,
+    ) -> TransactionStatus {
+        let factory = self.transaction_factory();
+        let txn = owner.sign_with_transaction_builder(
+            factory
+                .payload(TransactionPayload::Multisig(Multisig {
+                    multisig_address: multisig_account,
+                    transaction_payload: None,
+                }))
+                .expiration_timestamp_secs(expiration_secs),
+        );
+        let status = self.try_commit_block(&vec![txn]).await.pop().unwrap();
+        if !matches!(status, TransactionStatus::Keep(_)) {
+            // The transaction was not committed, so the sequence number was not consumed.
+            owner.decrement_sequence_number();
+        }
+        status
+    }
+
+    /// Approves a multisig transaction, and returns the hash of the approving transaction.
     pub async fn approve_multisig_transaction(
         &mut self,
         owner: &mut LocalAccount,
         multisig_account: AccountAddress,
         transaction_id: u64,
-    ) {
+    ) -> HashValue {
         let factory = self.transaction_factory();
         let txn = owner.sign_with_transaction_builder(
             factory
                 .approve_multisig_transaction(multisig_account, transaction_id)
                 .expiration_timestamp_secs(u64::MAX),
         );
+        let hash = txn.committed_hash();
         self.commit_block(&vec![txn]).await;
+        hash
     }
 
+    /// Approves a multisig transaction from each of `owners`, one block per approval, and returns
+    /// the time taken to commit all approvals together with the gas used by each of them. Looking
+    /// up the gas used is not included in the elapsed time.
+    pub async fn time_multisig_approvals(
+        &mut self,
+        owners: &mut [&mut LocalAccount],
+        multisig_account: AccountAddress,
+        transaction_id: u64,
+    ) -> MultisigApprovalTiming {
+        let factory = self.transaction_factory();
+        let mut hashes = Vec::with_capacity(owners.len());
+        let start = Instant::now();
+        for owner in owners.iter_mut() {
+            let txn = owner.sign_with_transaction_builder(
+                factory
+                    .approve_multisig_transaction(multisig_account, transaction_id)
+                    .expiration_timestamp_secs(u64::MAX),
+            );
+            hashes.push(txn.committed_hash());
+            self.commit_block(&vec![txn]).await;
+        }
+        let elapsed = start.elapsed();
+
+        let mut gas_used = Vec::with_capacity(hashes.len());
+        for hash in hashes {
+            gas_used.push(
+                self.get_committed_outcome(&hash.to_hex_literal())
+                    .await
+                    .gas_used,
+            );
+        }
+        MultisigApprovalTiming { elapsed, gas_used }
+    }
+
+    /// Approves a multisig transaction and returns the status instead of asserting it's kept, so
+    /// callers can exercise rejected approvals (e.g. from accounts that are not owners).
+    pub async fn try_approve_multisig_transaction(
+        &mut self,
+        account: &mut LocalAccount,
+        multisig_account: AccountAddress,
+        transaction_id: u64,
+    ) -> TransactionStatus {
+        let factory = self.transaction_factory();
+        let txn = account.sign_with_transaction_builder(
+            factory
+                .approve_multisig_transaction(multisig_account, transaction_id)
+                .expiration_timestamp_secs(u64::MAX),
+        );
+        let status = self.try_commit_block(&vec![txn]).await.pop().unwrap();
+        if !matches!(status, TransactionStatus::Keep(_)) {
+            // The transaction was not committed, so the sequence number was not consumed.
+            account.decrement_sequence_number();
+        }
+        status
+    }
+
+    /// Rejects a multisig transaction, and returns the hash of the rejecting transaction.
     pub async fn reject_multisig_transaction(
         &mut self,
         owner: &mut LocalAccount,
         multisig_account: AccountAddress,
         transaction_id: u64,
-    ) {
+    ) -> HashValue {
         let factory = self.transaction_factory();
         let txn = owner.sign_with_transaction_builder(
             factory
                 .reject_multisig_transaction(multisig_account, transaction_id)
                 .expiration_timestamp_secs(u64::MAX),
         );
+        let hash = txn.committed_hash();
         self.commit_block(&vec![txn]).await;
+        hash
+    }
+
+    /// Asserts that executing the multisig transaction `sequence_number` as `owner` is rejected
+    /// during validation, and that the transaction is still pending afterwards.
+    pub async fn assert_multisig_transaction_not_executable(
+        &mut self,
+        owner: &mut LocalAccount,
+        multisig_account: AccountAddress,
+        sequence_number: u64,
+    ) {
+        self.execute_multisig_transaction(owner, multisig_account, 400)
+            .await;
+        // The rejected transaction did not use up a sequence number.
+        owner.decrement_sequence_number();
+
+        let args = || {
+            vec![
+                json!(multisig_account.to_hex_literal()),
+                json!(sequence_number.to_string()),
+            ]
+        };
+        assert!(
+            !self
+                .call_view_bool("0x1::multisig_account::can_be_executed", &[], args())
+                .await,
+            "Multisig transaction {} should not be executable",
+            sequence_number
+        );
+        let last_resolved = self
+            .call_view_u64(
+                "0x1::multisig_account::last_resolved_sequence_number",
+                &[],
+                vec![json!(multisig_account.to_hex_literal())],
+            )
+            .await;
+        assert!(
+            last_resolved < sequence_number,
+            "Multisig transaction {} should still be pending, last resolved is {}",
+            sequence_number,
+            last_resolved
+        );
     }
 
     pub async fn create_multisig_transaction_with_payload_hash(
@@ -699,6 +1189,33 @@ impl TestContext {
         )
     }
 
+    pub fn fa_transfer_to(
+        &self,
+        sender: &mut LocalAccount,
+        fa_metadata: AccountAddress,
+        receiver: AccountAddress,
+        amount: u64,
+    ) -> SignedTransaction {
+        let factory = self.transaction_factory();
+        sender.sign_with_transaction_builder(
+            factory
+                .payload(TransactionPayload::EntryFunction(EntryFunction::new(
+                    ModuleId::new(
+                        AccountAddress::ONE,
+                        Identifier::new("primary_fungible_store").unwrap(),
+                    ),
+                    Identifier::new("transfer").unwrap(),
+                    vec!["0x1::fungible_asset::Metadata".parse::<TypeTag>().unwrap()],
+                    vec![
+                        bcs::to_bytes(&fa_metadata).unwrap(),
+                        bcs::to_bytes(&receiver).unwrap(),
+                        bcs::to_bytes(&amount).unwrap(),
+                    ],
+                )))
+                .expiration_timestamp_secs(u64::MAX),
+        )
+    }
+
     pub fn create_user_account_by(
         &self,
         creator: &mut LocalAccount,
@@ -770,19 +1287,37 @@ impl TestContext {
         )
     }
 
+    /// Compiles the on-disk package at `path` and returns its serialized metadata and module
+    /// bytecode, i.e., the arguments of `0x1::code::publish_package_txn`. Useful to build publish
+    /// payloads which are not sent directly, e.g., multisig transaction payloads.
+    pub fn build_package_from_dir(
+        path: PathBuf,
+        named_addresses: Vec<(String, AccountAddress)>,
+    ) -> (Vec<u8>, Vec<Vec<u8>>) {
+        Self::build_package_components(path, named_addresses, BuildOptions::default())
+    }
+
     fn build_package_with_options(
         path: PathBuf,
         named_addresses: Vec<(String, AccountAddress)>,
-        mut build_options: BuildOptions,
+        build_options: BuildOptions,
     ) -> TransactionPayload {
+        let (metadata, code) = Self::build_package_components(path, named_addresses, build_options);
+        aptos_stdlib::code_publish_package_txn(metadata, code)
+    }
+
+    fn build_package_components(
+        path: PathBuf,
+        named_addresses: Vec<(String, AccountAddress)>,
+        mut build_options: BuildOptions,
+    ) -> (Vec<u8>, Vec<Vec<u8>>) {
         named_addresses.into_iter().for_each(|(name, address)| {
             build_options.named_addresses.insert(name, address);
         });
         let package = BuiltPackage::build(path, build_options).unwrap();
         let code = package.extract_code();
         let metadata = package.extract_metadata().unwrap();
-
-        aptos_stdlib::code_publish_package_txn(bcs::to_bytes(&metadata).unwrap(), code)
+        (bcs::to_bytes(&metadata).unwrap(), code)
     }
 
     pub async fn publish_package(
@@ -879,6 +1414,8 @@ impl TestContext {
             .unwrap()
     }
 
+    /// Returns the APT balance of the account from its coin store, or from its primary fungible
+    /// store if the coin store does not exist. Returns 0 if the account has neither.
     pub async fn get_apt_balance(&self, account: AccountAddress) -> u64 {
         let coin_balance_option = self
             .try_api_get_account_resource(
@@ -918,6 +1455,24 @@ impl TestContext {
         }
     }
 
+    /// Returns the balance of the fungible asset in the primary fungible store of the account, or
+    /// 0 if the account has no primary store for the asset.
+    pub async fn get_fa_balance(
+        &self,
+        account: AccountAddress,
+        fa_metadata: AccountAddress,
+    ) -> u64 {
+        self.call_view_u64(
+            "0x1::primary_fungible_store::balance",
+            &["0x1::fungible_asset::Metadata"],
+            vec![
+                json!(account.to_hex_literal()),
+                json!(fa_metadata.to_hex_literal()),
+            ],
+        )
+        .await
+    }
+
     pub async fn gen_events_by_handle(
         &self,
         account_address: &AccountAddress,
@@ -962,6 +1517,21 @@ impl TestContext {
         self.get(&request).await
     }
 
+    // return the names of all modules published under an account, sorted by name
+    pub async fn list_account_modules(&self, account_address: AccountAddress) -> Vec<String> {
+        let request = format!("/accounts/{}/modules", account_address);
+        let mut names: Vec<String> = self
+            .get(&request)
+            .await
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|module| module["abi"]["name"].as_str().unwrap().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
     // TODO: Add support for generic_type_params if necessary.
     pub async fn try_api_get_account_resource(
         &self,
@@ -1035,7 +1605,7 @@ impl TestContext {
         account: &mut LocalAccount,
         payload: Value,
         status_code: u16,
-    ) {
+    ) -> Value {
         let mut request = json!({
             "sender": account.address(),
             "sequence_number": account.sequence_number().to_string(),
@@ -1066,11 +1636,141 @@ impl TestContext {
             "signature": HexEncodedBytes::from(sig.to_bytes().to_vec()),
         });
 
-        self.expect_status_code(status_code)
+        let resp = self
+            .expect_status_code(status_code)
             .post("/transactions", request)
             .await;
         self.commit_mempool_txns(1).await;
         account.increment_sequence_number();
+        resp
+    }
+
+    /// Returns the execution error recorded by the `TransactionExecutionFailed` event of the last
+    /// transaction sent by `executor`, or [None] if the multisig payload executed successfully.
+    pub async fn get_last_multisig_execution_error(
+        &self,
+        executor: &LocalAccount,
+    ) -> Option<Value> {
+        self.get_last_transaction_events(executor)
+            .await
+            .iter()
+            .find(|event| {
+                event["type"]
+                    .as_str()
+                    .unwrap()
+                    .contains("TransactionExecutionFailed")
+            })
+            .map(|event| event["data"]["execution_error"].clone())
+    }
+
+    /// Returns the last committed transaction sent by `sender`.
+    async fn get_last_transaction(&self, sender: &LocalAccount) -> Value {
+        let txns = self
+            .get(&format!(
+                "/accounts/{}/transactions?start={}&limit=1",
+                sender.address().to_hex_literal(),
+                sender.sequence_number() - 1
+            ))
+            .await;
+        txns[0].clone()
+    }
+
+    /// Returns the events emitted by the last committed transaction sent by `sender`.
+    async fn get_last_transaction_events(&self, sender: &LocalAccount) -> Vec<Value> {
+        self.get_last_transaction(sender).await["events"]
+            .as_array()
+            .unwrap()
+            .clone()
+    }
+
+    /// Returns the hash of the last committed transaction sent by `sender`.
+    pub async fn get_last_transaction_hash(&self, sender: &LocalAccount) -> HashValue {
+        let hash = self.get_last_transaction(sender).await["hash"].clone();
+        HashValue::from_hex(hash.as_str().unwrap().trim_start_matches("0x")).unwrap()
+    }
+
+    /// Returns the current vote of each owner on a multisig transaction, in the order in which the
+    /// owners first voted, along with the timestamp (in microseconds) of the transaction which
+    /// recorded the vote. Multisig accounts do not store when votes were cast, so the timestamp is
+    /// read from the vote transactions with the given hashes (e.g., as returned by
+    /// [Self::approve_multisig_transaction]), and is [None] for owners whose vote is not recorded
+    /// by any of them.
+    pub async fn get_multisig_votes(
+        &self,
+        multisig_account: AccountAddress,
+        sequence_number: u64,
+        vote_txn_hashes: &[HashValue],
+    ) -> Vec<(AccountAddress, bool, Option<u64>)> {
+        let transaction = self
+            .call_view("0x1::multisig_account::get_transaction", &[], vec![
+                json!(multisig_account.to_hex_literal()),
+                json!(sequence_number.to_string()),
+            ])
+            .await;
+
+        let mut vote_timestamps = HashMap::new();
+        for hash in vote_txn_hashes {
+            if let Some((owner, timestamp)) = self
+                .get_multisig_vote_timestamp(*hash, multisig_account, sequence_number)
+                .await
+            {
+                vote_timestamps.insert(owner, timestamp);
+            }
+        }
+
+        transaction["votes"]["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|vote| {
+                let owner =
+                    AccountAddress::from_hex_literal(vote["key"].as_str().unwrap()).unwrap();
+                let approved = vote["value"].as_bool().unwrap();
+                (owner, approved, vote_timestamps.get(&owner).copied())
+            })
+            .collect()
+    }
+
+    /// Returns the owner and the timestamp (in microseconds) of the vote on the multisig
+    /// transaction recorded by the transaction with the given hash, taken from its `Vote` event
+    /// (or `CreateTransaction` event, for the implicit vote of the creator). Returns [None] if the
+    /// transaction did not vote on the multisig transaction.
+    async fn get_multisig_vote_timestamp(
+        &self,
+        vote_txn_hash: HashValue,
+        multisig_account: AccountAddress,
+        sequence_number: u64,
+    ) -> Option<(AccountAddress, u64)> {
+        let txn = self
+            .get(&format!(
+                "/transactions/by_hash/{}",
+                vote_txn_hash.to_hex_literal()
+            ))
+            .await;
+        let owner = txn["events"].as_array().unwrap().iter().find_map(|event| {
+            let event_type = event["type"].as_str().unwrap();
+            let owner_field = if event_type.contains("multisig_account::Vote") {
+                "owner"
+            } else if event_type.contains("multisig_account::CreateTransaction") {
+                "creator"
+            } else {
+                return None;
+            };
+            // Module events store the multisig account in the data, handle-based events in the
+            // handle.
+            let event_multisig_account = event["data"]["multisig_account"]
+                .as_str()
+                .or_else(|| event["guid"]["account_address"].as_str())
+                .and_then(|address| AccountAddress::from_hex_literal(address).ok());
+            if event_multisig_account != Some(multisig_account)
+                || event["data"]["sequence_number"] != json!(sequence_number.to_string())
+            {
+                return None;
+            }
+            AccountAddress::from_hex_literal(event["data"][owner_field].as_str().unwrap()).ok()
+        })?;
+        let timestamp = txn["timestamp"].as_str().unwrap().parse().unwrap();
+        Some((owner, timestamp))
     }
 
     pub async fn simulate_multisig_transaction(
@@ -1099,6 +1799,57 @@ impl TestContext {
         .await
     }
 
+    /// Simulates executing the multisig transaction `transaction_id`, which must be the next one to
+    /// be executed, using the payload stored on-chain instead of providing it inline.
+    pub async fn simulate_queued_multisig_transaction(
+        &mut self,
+        sender: &LocalAccount,
+        multisig_account: AccountAddress,
+        transaction_id: u64,
+        expected_status_code: u16,
+    ) -> Value {
+        let last_resolved = self
+            .call_view_u64(
+                "0x1::multisig_account::last_resolved_sequence_number",
+                &[],
+                vec![json!(multisig_account.to_hex_literal())],
+            )
+            .await;
+        assert_eq!(
+            last_resolved + 1,
+            transaction_id,
+            "Only the next multisig transaction can be simulated"
+        );
+        self.simulate_transaction(
+            sender,
+            json!({
+                "type": "multisig_payload",
+                "multisig_address": multisig_account.to_hex_literal(),
+            }),
+            expected_status_code,
+        )
+        .await
+    }
+
+    /// Returns the events of the first transaction in a simulation response. The simulation API
+    /// returns events in emission order, so they can be asserted on by index.
+    pub fn simulated_events(simulation_resp: &Value) -> &[Value] {
+        simulation_resp.as_array().unwrap()[0]["events"]
+            .as_array()
+            .unwrap()
+    }
+
+    /// Returns the first event of the given type, e.g., `0x1::fungible_asset::Withdraw`.
+    pub fn find_event_by_type(events: &[Value], type_str: &str) -> Option<TypedEvent> {
+        events
+            .iter()
+            .find(|event| event["type"].as_str() == Some(type_str))
+            .map(|event| TypedEvent {
+                type_str: type_str.to_string(),
+                data: event["data"].clone(),
+            })
+    }
+
     pub async fn simulate_transaction(
         &mut self,
         sender: &LocalAccount,
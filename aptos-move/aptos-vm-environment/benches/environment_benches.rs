@@ -0,0 +1,32 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_types::state_store::MockStateView;
+use aptos_vm_environment::environment::{AptosEnvironment, EnvironmentFingerprint};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Compares creating a new environment for every block, and reusing the cached environment if the
+/// fingerprint of configs is unchanged, for blocks with identical configs.
+fn environment_per_block(c: &mut Criterion) {
+    let state_view = MockStateView::empty();
+    let cached_environment = AptosEnvironment::new(&state_view);
+
+    let mut group = c.benchmark_group("environment_per_block");
+    group.bench_function("always_construct", |b| {
+        b.iter(|| AptosEnvironment::new(&state_view))
+    });
+    group.bench_function("reuse_on_same_fingerprint", |b| {
+        b.iter(|| {
+            if EnvironmentFingerprint::new(&state_view) == cached_environment.fingerprint() {
+                cached_environment.clone()
+            } else {
+                AptosEnvironment::new(&state_view)
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, environment_per_block);
+
+criterion_main!(benches);
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    gas::get_gas_parameters,
+    gas::{get_gas_parameters, update_hash_with_gas_schedule},
     natives::aptos_natives_with_builder,
     prod_configs::{
         aptos_default_ty_builder, aptos_prod_ty_builder, aptos_prod_vm_config,
@@ -10,20 +10,96 @@ use crate::{
     },
 };
 use aptos_gas_algebra::DynamicExpression;
-use aptos_gas_schedule::{AptosGasParameters, MiscGasParameters, NativeGasParameters};
+use aptos_gas_schedule::{
+    AptosGasParameters, MiscGasParameters, NativeGasParameters, ToOnChainGasSchedule,
+};
 use aptos_native_interface::SafeNativeBuilder;
 use aptos_types::{
     chain_id::ChainId,
     on_chain_config::{
-        ConfigurationResource, Features, OnChainConfig, TimedFeatures, TimedFeaturesBuilder,
+        ConfigurationResource, FeatureFlag, Features, OnChainConfig, TimedFeatures,
+        TimedFeaturesBuilder,
     },
     state_store::StateView,
+    transaction::{
+        Script, TransactionArgument, TransactionExecutable, TransactionPayload,
+        TransactionPayloadInner,
+    },
 };
 use aptos_vm_types::storage::StorageGasParameters;
 use move_vm_runtime::{config::VMConfig, RuntimeEnvironment, WithRuntimeEnvironment};
 use sha3::{Digest, Sha3_256};
 use std::sync::Arc;
 
+/// Version of the loader used by the Move VM to load modules and scripts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LoaderVersion {
+    /// Legacy loader, used before the rollout of
+    /// [aptos_types::on_chain_config::FeatureFlag::_ENABLE_LOADER_V2]. No longer supported.
+    V1,
+    /// Loader which uses global module caches and code storage.
+    V2,
+}
+
+/// Returns true if any of the script arguments is serialized, which is gated by
+/// [FeatureFlag::ALLOW_SERIALIZED_SCRIPT_ARGS].
+fn has_serialized_args(script: &Script) -> bool {
+    script
+        .args()
+        .iter()
+        .any(|arg| matches!(arg, TransactionArgument::Serialized(_)))
+}
+
+/// Part of the environment which can differ between two [AptosEnvironment]s.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EnvironmentComponent {
+    ChainId,
+    Features,
+    /// Configuration resource, i.e., the last reconfiguration time used for timed features.
+    Configuration,
+    GasSchedule,
+    VMConfig,
+}
+
+impl EnvironmentComponent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EnvironmentComponent::ChainId => "chain_id",
+            EnvironmentComponent::Features => "features",
+            EnvironmentComponent::Configuration => "configuration",
+            EnvironmentComponent::GasSchedule => "gas_schedule",
+            EnvironmentComponent::VMConfig => "vm_config",
+        }
+    }
+}
+
+/// Fingerprint of the on-chain configs an [AptosEnvironment] is derived from. Environments with the
+/// same fingerprint are equal, so it can be used to check if a cached environment is still valid
+/// without creating a new one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EnvironmentFingerprint([u8; 32]);
+
+impl EnvironmentFingerprint {
+    /// Returns the fingerprint of the environment that would be created from the current state.
+    /// Only fetches configs, which is much cheaper than creating the environment. Does not account
+    /// for explicitly provided VM configs (see [AptosEnvironment::new_with_vm_config]) or injected
+    /// natives (see [AptosEnvironment::new_with_injected_create_signer_for_gov_sim]).
+    pub fn new(state_view: &impl StateView) -> Self {
+        // Must hash the same configs in the same order as the environment does.
+        let mut sha3_256 = Sha3_256::new();
+        fetch_config_and_update_hash::<Features>(&mut sha3_256, state_view);
+        fetch_config_and_update_hash::<ChainId>(&mut sha3_256, state_view);
+        fetch_config_and_update_hash::<ConfigurationResource>(&mut sha3_256, state_view);
+        if let Some(profile) = get_timed_feature_override() {
+            let profile_bytes = bcs::to_bytes(&profile)
+                .expect("Timed features override should always be serializable");
+            sha3_256.update(&profile_bytes);
+        }
+        update_hash_with_gas_schedule(&mut sha3_256, state_view);
+        Self(sha3_256.finalize().into())
+    }
+}
+
 /// A runtime environment which can be used for VM initialization and more. Contains features
 /// used by execution, gas parameters, VM configs and global caches. Note that it is the user's
 /// responsibility to make sure the environment is consistent, for now it should only be used per
@@ -33,7 +109,7 @@ pub struct AptosEnvironment(Arc<Environment>);
 impl AptosEnvironment {
     /// Returns new execution environment based on the current state.
     pub fn new(state_view: &impl StateView) -> Self {
-        Self(Arc::new(Environment::new(state_view, false, None)))
+        Self(Arc::new(Environment::new(state_view, false, None, None)))
     }
 
     /// Returns new execution environment based on the current state, also using the provided gas
@@ -46,23 +122,42 @@ impl AptosEnvironment {
             state_view,
             false,
             Some(gas_hook),
+            None,
+        )))
+    }
+
+    /// Returns new execution environment based on the current state, but using the provided VM
+    /// config instead of deriving it from features and timed features. Should only be used for
+    /// tests which need to pin a specific config.
+    pub fn new_with_vm_config(state_view: &impl StateView, vm_config: VMConfig) -> Self {
+        Self(Arc::new(Environment::new(
+            state_view,
+            false,
+            None,
+            Some(vm_config),
         )))
     }
 
     /// Returns new execution environment based on the current state, also injecting create signer
     /// native for government proposal simulation. Should not be used for regular execution.
     pub fn new_with_injected_create_signer_for_gov_sim(state_view: &impl StateView) -> Self {
-        Self(Arc::new(Environment::new(state_view, true, None)))
+        Self(Arc::new(Environment::new(state_view, true, None, None)))
     }
 
     /// Returns new environment but with delayed field optimization enabled. Should only be used by
     /// block executor where this optimization is needed. Note: whether the optimization will be
     /// enabled or not depends on the feature flag.
     pub fn new_with_delayed_field_optimization_enabled(state_view: &impl StateView) -> Self {
-        let env = Environment::new(state_view, false, None).try_enable_delayed_field_optimization();
+        let env =
+            Environment::new(state_view, false, None, None).try_enable_delayed_field_optimization();
         Self(Arc::new(env))
     }
 
+    /// Returns the fingerprint of the configs this environment is derived from.
+    pub fn fingerprint(&self) -> EnvironmentFingerprint {
+        EnvironmentFingerprint(self.0.hash)
+    }
+
     /// Returns the [ChainId] used by this environment.
     #[inline]
     pub fn chain_id(&self) -> ChainId {
@@ -75,18 +170,50 @@ impl AptosEnvironment {
         &self.0.features
     }
 
+    /// Returns the enabled features as a bitset, where bit `i % 8` of byte `i / 8` is set if the
+    /// feature flag with value `i` is enabled. Trailing zero bytes are trimmed, so that the same
+    /// set of enabled features always has the same encoding. Decodes back via [Features].
+    pub fn features_bitset(&self) -> Vec<u8> {
+        let mut bitset = self.features().features.clone();
+        while bitset.last() == Some(&0) {
+            bitset.pop();
+        }
+        bitset
+    }
+
     /// Returns the [TimedFeatures] used by this environment.
     #[inline]
     pub fn timed_features(&self) -> &TimedFeatures {
         &self.0.timed_features
     }
 
+    /// Returns the last reconfiguration time (in microseconds) used by this environment for timed
+    /// features, or 0 if the [ConfigurationResource] was not found on-chain.
+    #[inline]
+    pub fn last_reconfiguration_time(&self) -> u64 {
+        self.0.last_reconfiguration_time_micros
+    }
+
+    /// Returns the version of the loader used by this environment. The loader V2 feature can no
+    /// longer be disabled, so [LoaderVersion::V2] is always used, regardless of the on-chain
+    /// state of [aptos_types::on_chain_config::FeatureFlag::_ENABLE_LOADER_V2].
+    #[inline]
+    pub fn loader_version(&self) -> LoaderVersion {
+        LoaderVersion::V2
+    }
+
     /// Returns the [VMConfig] used by this environment.
     #[inline]
     pub fn vm_config(&self) -> &VMConfig {
         self.0.runtime_environment.vm_config()
     }
 
+    /// Returns the [VMConfig] used by this environment as JSON, so that configs of different
+    /// nodes can be compared textually.
+    pub fn vm_config_json(&self) -> serde_json::Value {
+        serde_json::to_value(self.vm_config()).expect("VM config should always be serializable")
+    }
+
     /// Returns the gas feature used by this environment.
     #[inline]
     pub fn gas_feature_version(&self) -> u64 {
@@ -100,6 +227,25 @@ impl AptosEnvironment {
         &self.0.gas_params
     }
 
+    /// Returns the maximum number of modules a transaction may depend on (including transitive
+    /// dependencies), or [None] if gas parameters were not found on-chain. Packages exceeding it
+    /// can be rejected before being submitted.
+    pub fn max_dependencies(&self) -> Option<u64> {
+        self.gas_params()
+            .as_ref()
+            .ok()
+            .map(|gas_params| gas_params.vm.txn.max_num_dependencies.into())
+    }
+
+    /// Returns the maximum total size in bytes of all modules a transaction may depend on, or
+    /// [None] if gas parameters were not found on-chain.
+    pub fn max_total_dependency_size(&self) -> Option<u64> {
+        self.gas_params()
+            .as_ref()
+            .ok()
+            .map(|gas_params| gas_params.vm.txn.max_total_dependency_size.into())
+    }
+
     /// Returns the storage gas parameters used by this environment, and an error if they were not
     /// found on-chain.
     #[inline]
@@ -107,6 +253,82 @@ impl AptosEnvironment {
         &self.0.storage_gas_params
     }
 
+    /// Returns the components which differ between this and the other environment, e.g., to
+    /// attribute why an environment has been reset. Comparing components is more expensive than
+    /// comparing environments, so it should only be used when environments are not equal.
+    pub fn diff(&self, other: &Self) -> Vec<EnvironmentComponent> {
+        let mut components = vec![];
+        if self.chain_id() != other.chain_id() {
+            components.push(EnvironmentComponent::ChainId);
+        }
+        if self.features() != other.features() {
+            components.push(EnvironmentComponent::Features);
+        }
+        if self.last_reconfiguration_time() != other.last_reconfiguration_time() {
+            components.push(EnvironmentComponent::Configuration);
+        }
+        if self.gas_feature_version() != other.gas_feature_version()
+            || self.gas_schedule_entries() != other.gas_schedule_entries()
+        {
+            components.push(EnvironmentComponent::GasSchedule);
+        }
+        if self.vm_config_json() != other.vm_config_json() {
+            components.push(EnvironmentComponent::VMConfig);
+        }
+        components
+    }
+
+    /// Checks if all features required by the transaction payload are enabled in this
+    /// environment. Returns the flags which need to be enabled otherwise, so that callers can
+    /// report them all at once instead of failing on the first one.
+    pub fn check_transaction_features(
+        &self,
+        payload: &TransactionPayload,
+    ) -> Result<(), Vec<FeatureFlag>> {
+        let mut required = vec![];
+        match payload {
+            TransactionPayload::Script(script) => {
+                if has_serialized_args(script) {
+                    required.push(FeatureFlag::ALLOW_SERIALIZED_SCRIPT_ARGS);
+                }
+            },
+            TransactionPayload::Payload(TransactionPayloadInner::V1 { executable, .. }) => {
+                required.push(FeatureFlag::TRANSACTION_PAYLOAD_V2);
+                if payload.replay_protection_nonce().is_some() {
+                    required.push(FeatureFlag::ORDERLESS_TRANSACTIONS);
+                }
+                if let TransactionExecutable::Script(script) = executable {
+                    if has_serialized_args(script) {
+                        required.push(FeatureFlag::ALLOW_SERIALIZED_SCRIPT_ARGS);
+                    }
+                }
+            },
+            TransactionPayload::EntryFunction(_)
+            | TransactionPayload::Multisig(_)
+            | TransactionPayload::ModuleBundle(_) => (),
+        }
+
+        let features = self.features();
+        let missing = required
+            .into_iter()
+            .filter(|flag| !features.is_enabled(*flag))
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
+    /// Returns gas parameters as on-chain gas schedule entries, or [None] if they were not found
+    /// on-chain.
+    fn gas_schedule_entries(&self) -> Option<Vec<(String, u64)>> {
+        self.gas_params()
+            .as_ref()
+            .ok()
+            .map(|gas_params| gas_params.to_on_chain_gas_schedule(self.gas_feature_version()))
+    }
+
     /// Returns true if create_signer native was injected for the government proposal simulation.
     /// Deprecated, and should not be used.
     #[inline]
@@ -145,6 +367,8 @@ struct Environment {
     features: Features,
     /// Set of timed features enabled in this environment.
     timed_features: TimedFeatures,
+    /// Last reconfiguration time (in microseconds) used to determine timed features.
+    last_reconfiguration_time_micros: u64,
 
     /// Gas feature version used in this environment.
     gas_feature_version: u64,
@@ -172,6 +396,7 @@ impl Environment {
         state_view: &impl StateView,
         inject_create_signer_for_gov_sim: bool,
         gas_hook: Option<Arc<dyn Fn(DynamicExpression) + Send + Sync>>,
+        vm_config: Option<VMConfig>,
     ) -> Self {
         // We compute and store a hash of configs in order to distinguish different environments.
         let mut sha3_256 = Sha3_256::new();
@@ -181,12 +406,13 @@ impl Environment {
         // If no chain ID is in storage, we assume we are in a testing environment.
         let chain_id = fetch_config_and_update_hash::<ChainId>(&mut sha3_256, state_view)
             .unwrap_or_else(ChainId::test);
-        let timestamp_micros =
+        let last_reconfiguration_time_micros =
             fetch_config_and_update_hash::<ConfigurationResource>(&mut sha3_256, state_view)
                 .map(|config| config.last_reconfiguration_time_micros())
                 .unwrap_or(0);
 
-        let mut timed_features_builder = TimedFeaturesBuilder::new(chain_id, timestamp_micros);
+        let mut timed_features_builder =
+            TimedFeaturesBuilder::new(chain_id, last_reconfiguration_time_micros);
         if let Some(profile) = get_timed_feature_override() {
             // We need to ensure the override is taken into account for the hash.
             let profile_bytes = bcs::to_bytes(&profile)
@@ -232,10 +458,27 @@ impl Environment {
             gas_hook,
         );
         let natives = aptos_natives_with_builder(&mut builder, inject_create_signer_for_gov_sim);
-        let vm_config =
-            aptos_prod_vm_config(gas_feature_version, &features, &timed_features, ty_builder);
+        let vm_config = match vm_config {
+            Some(vm_config) => {
+                // The provided config is not derived from the state, so it needs to be hashed
+                // explicitly to distinguish environments with different configs.
+                let vm_config_bytes =
+                    bcs::to_bytes(&vm_config).expect("VM config should always be serializable");
+                sha3_256.update(&vm_config_bytes);
+                vm_config
+            },
+            None => {
+                aptos_prod_vm_config(gas_feature_version, &features, &timed_features, ty_builder)
+            },
+        };
         let runtime_environment = RuntimeEnvironment::new_with_config(natives, vm_config);
 
+        // Injected natives are not derived from the state, so environments with and without the
+        // injection must not be equal.
+        if inject_create_signer_for_gov_sim {
+            sha3_256.update([1u8]);
+        }
+
         let hash = sha3_256.finalize().into();
 
         #[allow(deprecated)]
@@ -243,6 +486,7 @@ impl Environment {
             chain_id,
             features,
             timed_features,
+            last_reconfiguration_time_micros,
             gas_feature_version,
             gas_params,
             storage_gas_params,
@@ -273,9 +517,14 @@ fn fetch_config_and_update_hash<T: OnChainConfig>(
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use aptos_gas_schedule::{InitialGasSchedule, LATEST_GAS_FEATURE_VERSION};
     use aptos_types::{
-        on_chain_config::{FeatureFlag, GasScheduleV2},
+        on_chain_config::GasScheduleV2,
         state_store::{state_key::StateKey, state_value::StateValue, MockStateView},
+        transaction::{EntryFunction, TransactionExtraConfig},
+    };
+    use move_core_types::{
+        account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
     };
     use serde::Serialize;
     use std::collections::HashMap;
@@ -284,7 +533,7 @@ pub mod tests {
     fn test_new_environment() {
         // This creates an empty state.
         let state_view = MockStateView::empty();
-        let env = Environment::new(&state_view, false, None);
+        let env = Environment::new(&state_view, false, None, None);
 
         // Check default values.
         assert_eq!(&env.features, &Features::default());
@@ -312,6 +561,70 @@ pub mod tests {
         )]))
     }
 
+    fn entry_function() -> EntryFunction {
+        EntryFunction::new(
+            ModuleId::new(
+                AccountAddress::ONE,
+                Identifier::new("aptos_account").unwrap(),
+            ),
+            Identifier::new("transfer").unwrap(),
+            vec![],
+            vec![],
+        )
+    }
+
+    fn orderless_payload() -> TransactionPayload {
+        TransactionPayload::Payload(TransactionPayloadInner::V1 {
+            executable: TransactionExecutable::EntryFunction(entry_function()),
+            extra_config: TransactionExtraConfig::V1 {
+                multisig_address: None,
+                replay_protection_nonce: Some(7),
+            },
+        })
+    }
+
+    #[test]
+    fn test_check_transaction_features_with_enabled_features() {
+        let mut features = Features::default();
+        features.enable(FeatureFlag::TRANSACTION_PAYLOAD_V2);
+        features.enable(FeatureFlag::ORDERLESS_TRANSACTIONS);
+        let environment = AptosEnvironment::new(&state_view_with_non_default_config(features));
+
+        let payload = TransactionPayload::EntryFunction(entry_function());
+        assert_eq!(environment.check_transaction_features(&payload), Ok(()));
+        assert_eq!(
+            environment.check_transaction_features(&orderless_payload()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_check_transaction_features_with_disabled_features() {
+        let mut features = Features::default();
+        features.disable(FeatureFlag::TRANSACTION_PAYLOAD_V2);
+        features.disable(FeatureFlag::ORDERLESS_TRANSACTIONS);
+        features.disable(FeatureFlag::ALLOW_SERIALIZED_SCRIPT_ARGS);
+        let environment = AptosEnvironment::new(&state_view_with_non_default_config(features));
+
+        let payload = TransactionPayload::EntryFunction(entry_function());
+        assert_eq!(environment.check_transaction_features(&payload), Ok(()));
+        assert_eq!(
+            environment.check_transaction_features(&orderless_payload()),
+            Err(vec![
+                FeatureFlag::TRANSACTION_PAYLOAD_V2,
+                FeatureFlag::ORDERLESS_TRANSACTIONS
+            ])
+        );
+
+        let script = Script::new(vec![], vec![], vec![TransactionArgument::Serialized(vec![
+            0,
+        ])]);
+        assert_eq!(
+            environment.check_transaction_features(&TransactionPayload::Script(script)),
+            Err(vec![FeatureFlag::ALLOW_SERIALIZED_SCRIPT_ARGS])
+        );
+    }
+
     #[test]
     fn test_environment_eq() {
         let state_view = MockStateView::empty();
@@ -320,6 +633,70 @@ pub mod tests {
         assert!(environment_1 == environment_2);
     }
 
+    #[test]
+    fn test_environment_hash_is_deterministic() {
+        // Environments are compared by a hash over serialized configs, so configs constructed
+        // independently (e.g., by different processes) must serialize to identical bytes.
+        let flags = [
+            FeatureFlag::EMIT_FEE_STATEMENT,
+            FeatureFlag::STORAGE_SLOT_METADATA,
+            FeatureFlag::RESOURCE_GROUPS,
+        ];
+        let mut features_1 = Features::default();
+        let mut features_2 = Features::default();
+        for (flag_1, flag_2) in flags.iter().zip(flags.iter().rev()) {
+            features_1.disable(*flag_1);
+            features_2.disable(*flag_2);
+        }
+        assert_eq!(
+            bcs::to_bytes(&features_1).unwrap(),
+            bcs::to_bytes(&features_2).unwrap()
+        );
+
+        let environment_1 = AptosEnvironment::new(&state_view_with_non_default_config(features_1));
+        let environment_2 = AptosEnvironment::new(&state_view_with_non_default_config(features_2));
+        assert_eq!(environment_1.0.hash, environment_2.0.hash);
+
+        // Explicitly provided VM configs are hashed via BCS as well.
+        let vm_config_1 = environment_1.vm_config().clone();
+        let vm_config_2 = environment_2.vm_config().clone();
+        assert_eq!(
+            bcs::to_bytes(&vm_config_1).unwrap(),
+            bcs::to_bytes(&vm_config_2).unwrap()
+        );
+        let state_view = MockStateView::empty();
+        let environment_1 = AptosEnvironment::new_with_vm_config(&state_view, vm_config_1);
+        let environment_2 = AptosEnvironment::new_with_vm_config(&state_view, vm_config_2);
+        assert_eq!(environment_1.0.hash, environment_2.0.hash);
+    }
+
+    #[test]
+    fn test_environment_last_reconfiguration_time() {
+        let state_view = MockStateView::empty();
+        let environment = AptosEnvironment::new(&state_view);
+        assert_eq!(environment.last_reconfiguration_time(), 0);
+
+        let mut configuration = ConfigurationResource::default();
+        configuration.set_last_reconfiguration_time_for_test(12345);
+        let expected = configuration.last_reconfiguration_time_micros();
+        let state_view = state_view_with_non_default_config(configuration);
+        let environment = AptosEnvironment::new(&state_view);
+        assert_eq!(environment.last_reconfiguration_time(), expected);
+    }
+
+    #[test]
+    fn test_environment_vm_config_json() {
+        let state_view = MockStateView::empty();
+        let environment =
+            AptosEnvironment::new_with_delayed_field_optimization_enabled(&state_view);
+
+        let json = environment.vm_config_json();
+        assert_eq!(
+            json["delayed_field_optimization_enabled"],
+            serde_json::Value::Bool(environment.vm_config().delayed_field_optimization_enabled)
+        );
+    }
+
     #[test]
     fn test_environment_ne() {
         let mut non_default_configuration = ConfigurationResource::default();
@@ -373,6 +750,123 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_environment_diff() {
+        let environment = AptosEnvironment::new(&MockStateView::empty());
+        assert!(environment.diff(&environment.clone()).is_empty());
+
+        let mut features = Features::default();
+        features.disable(FeatureFlag::EMIT_FEE_STATEMENT);
+        let other = AptosEnvironment::new(&state_view_with_non_default_config(features));
+        assert_eq!(environment.diff(&other), vec![
+            EnvironmentComponent::Features
+        ]);
+
+        let other = AptosEnvironment::new(&state_view_with_non_default_config(ChainId::mainnet()));
+        assert!(environment
+            .diff(&other)
+            .contains(&EnvironmentComponent::ChainId));
+
+        let other = AptosEnvironment::new(&state_view_with_non_default_config(GasScheduleV2 {
+            feature_version: 12,
+            entries: vec![],
+        }));
+        assert!(environment
+            .diff(&other)
+            .contains(&EnvironmentComponent::GasSchedule));
+    }
+
+    #[test]
+    fn test_fingerprint_matches_environment() {
+        let mut features = Features::default();
+        features.disable(FeatureFlag::EMIT_FEE_STATEMENT);
+        let state_views = [
+            MockStateView::empty(),
+            state_view_with_non_default_config(features),
+            state_view_with_non_default_config(GasScheduleV2 {
+                feature_version: 12,
+                entries: vec![],
+            }),
+            state_view_with_non_default_config(ChainId::mainnet()),
+        ];
+        for (i, state_view_1) in state_views.iter().enumerate() {
+            let environment = AptosEnvironment::new(state_view_1);
+            assert_eq!(
+                EnvironmentFingerprint::new(state_view_1),
+                environment.fingerprint()
+            );
+            for state_view_2 in &state_views[i + 1..] {
+                assert_ne!(
+                    EnvironmentFingerprint::new(state_view_2),
+                    environment.fingerprint()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_features_bitset_round_trip() {
+        let mut features = Features::default();
+        features.disable(FeatureFlag::EMIT_FEE_STATEMENT);
+        features.enable(FeatureFlag::ORDERLESS_TRANSACTIONS);
+        // Trailing zero bytes do not change the enabled features, and are not encoded.
+        features.features.extend([0, 0]);
+        let environment =
+            AptosEnvironment::new(&state_view_with_non_default_config(features.clone()));
+
+        let bitset = environment.features_bitset();
+        assert_ne!(bitset.last(), Some(&0));
+        let decoded = Features { features: bitset };
+        assert_eq!(decoded.into_flag_vec(), features.into_flag_vec());
+    }
+
+    #[test]
+    fn test_dependency_limits() {
+        let environment = AptosEnvironment::new(&MockStateView::empty());
+        assert_eq!(environment.max_dependencies(), None);
+        assert_eq!(environment.max_total_dependency_size(), None);
+
+        let mut gas_params = AptosGasParameters::initial();
+        gas_params.vm.txn.max_num_dependencies = 42.into();
+        gas_params.vm.txn.max_total_dependency_size = 1024.into();
+        let gas_schedule = GasScheduleV2 {
+            feature_version: LATEST_GAS_FEATURE_VERSION,
+            entries: gas_params.to_on_chain_gas_schedule(LATEST_GAS_FEATURE_VERSION),
+        };
+        let environment = AptosEnvironment::new(&state_view_with_non_default_config(gas_schedule));
+        assert_eq!(environment.max_dependencies(), Some(42));
+        assert_eq!(environment.max_total_dependency_size(), Some(1024));
+    }
+
+    #[test]
+    fn test_environment_loader_version() {
+        let mut features = Features::default();
+        assert!(features.is_enabled(FeatureFlag::_ENABLE_LOADER_V2));
+        let env = AptosEnvironment::new(&state_view_with_non_default_config(features.clone()));
+        assert_eq!(env.loader_version(), LoaderVersion::V2);
+
+        // Disabling the feature on-chain does not bring back the legacy loader.
+        features.disable(FeatureFlag::_ENABLE_LOADER_V2);
+        let env = AptosEnvironment::new(&state_view_with_non_default_config(features));
+        assert_eq!(env.loader_version(), LoaderVersion::V2);
+    }
+
+    #[test]
+    fn test_environment_with_vm_config() {
+        let state_view = MockStateView::empty();
+        let prod_env = AptosEnvironment::new(&state_view);
+
+        let mut vm_config = prod_env.vm_config().clone();
+        vm_config.paranoid_type_checks = !vm_config.paranoid_type_checks;
+
+        let env = AptosEnvironment::new_with_vm_config(&state_view, vm_config.clone());
+        assert_eq!(env.vm_config(), &vm_config);
+        assert!(env != prod_env);
+
+        let other_env = AptosEnvironment::new_with_vm_config(&state_view, vm_config);
+        assert!(env == other_env);
+    }
+
     #[test]
     fn test_environment_with_injected_create_signer_for_gov_sim() {
         let state_view = MockStateView::empty();
@@ -394,4 +888,49 @@ pub mod tests {
         let enabled = env.inject_create_signer_for_gov_sim();
         assert!(enabled);
     }
+
+    #[test]
+    fn test_injected_create_signer_for_gov_sim_only_affects_injected_environment() {
+        let state_view = MockStateView::empty();
+        let injected_env =
+            AptosEnvironment::new_with_injected_create_signer_for_gov_sim(&state_view);
+
+        // Environments created after the injected one must not observe the injection.
+        let env = AptosEnvironment::new(&state_view);
+        #[allow(deprecated)]
+        let (injected, not_injected) = (
+            injected_env.inject_create_signer_for_gov_sim(),
+            !env.inject_create_signer_for_gov_sim(),
+        );
+        assert!(injected && not_injected);
+
+        // Block executor caches environments based on equality, so the injected environment must
+        // never be reused as a regular one.
+        assert!(injected_env != env);
+        assert_ne!(injected_env.fingerprint(), env.fingerprint());
+
+        // Only the injected runtime exposes create_signer under aptos_governance, while the
+        // regular create_signer native is always present.
+        let framework = AccountAddress::ONE;
+        assert!(injected_env.runtime_environment().has_native_for_test(
+            &framework,
+            "aptos_governance",
+            "create_signer"
+        ));
+        assert!(!env.runtime_environment().has_native_for_test(
+            &framework,
+            "aptos_governance",
+            "create_signer"
+        ));
+        assert!(injected_env.runtime_environment().has_native_for_test(
+            &framework,
+            "create_signer",
+            "create_signer"
+        ));
+        assert!(env.runtime_environment().has_native_for_test(
+            &framework,
+            "create_signer",
+            "create_signer"
+        ));
+    }
 }
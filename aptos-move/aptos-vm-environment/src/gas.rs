@@ -45,6 +45,16 @@ fn get_gas_config_from_storage(
     }
 }
 
+/// Updates the provided sha3 with the gas schedule config bytes in the same way as
+/// [get_gas_parameters] does, but without constructing gas parameters.
+pub(crate) fn update_hash_with_gas_schedule(sha3_256: &mut Sha3_256, state_view: &impl StateView) {
+    if let Some((_, bytes)) = GasScheduleV2::fetch_config_and_bytes(state_view) {
+        sha3_256.update(&bytes);
+    } else if let Some((_, bytes)) = GasSchedule::fetch_config_and_bytes(state_view) {
+        sha3_256.update(&bytes);
+    }
+}
+
 /// Returns gas and storage gas parameters, as well as the gas feature version, from the state. In
 /// case parameters are not found on-chain, errors are returned.
 pub(crate) fn get_gas_parameters(
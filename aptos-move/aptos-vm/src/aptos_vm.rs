@@ -149,6 +149,7 @@ static EXECUTION_CONCURRENCY_LEVEL: OnceCell<usize> = OnceCell::new();
 static NUM_EXECUTION_SHARD: OnceCell<usize> = OnceCell::new();
 static NUM_PROOF_READING_THREADS: OnceCell<usize> = OnceCell::new();
 static DISCARD_FAILED_BLOCKS: OnceCell<bool> = OnceCell::new();
+static DISABLE_CROSS_BLOCK_MODULE_CACHE: OnceCell<bool> = OnceCell::new();
 static PROCESSED_TRANSACTIONS_DETAILED_COUNTERS: OnceCell<bool> = OnceCell::new();
 
 macro_rules! deprecated_module_bundle {
@@ -385,6 +386,21 @@ impl AptosVM {
         }
     }
 
+    /// Sets runtime config when invoked the first time.
+    pub fn set_disable_cross_block_module_cache(disable: bool) {
+        // Only the first call succeeds, due to OnceCell semantics.
+        DISABLE_CROSS_BLOCK_MODULE_CACHE.set(disable).ok();
+    }
+
+    /// Get the flag disabling the cross-block module cache if already set, otherwise return
+    /// default (false)
+    pub fn get_disable_cross_block_module_cache() -> bool {
+        match DISABLE_CROSS_BLOCK_MODULE_CACHE.get() {
+            Some(disable) => *disable,
+            None => false,
+        }
+    }
+
     /// Sets the # of async proof reading threads.
     pub fn set_num_proof_reading_threads_once(mut num_threads: usize) {
         // TODO(grao): Do more analysis to tune this magic number.
@@ -2702,7 +2718,11 @@ impl VMBlockExecutor for AptosVMBlockExecutor {
                 concurrency_level: AptosVM::get_concurrency_level(),
                 allow_fallback: true,
                 discard_failed_blocks: AptosVM::get_discard_failed_blocks(),
-                module_cache_config: BlockExecutorModuleCacheLocalConfig::default(),
+                module_cache_config: BlockExecutorModuleCacheLocalConfig {
+                    disable_cross_block_module_cache: AptosVM::get_disable_cross_block_module_cache(
+                    ),
+                    ..BlockExecutorModuleCacheLocalConfig::default()
+                },
             },
             onchain: onchain_config,
         };
@@ -19,6 +19,9 @@ struct Entry<Deserialized, Verified, Extension> {
     /// republishing of this module so far). If true, executor needs to read the module from the
     /// per-block module caches.
     overridden: AtomicBool,
+    /// If true, the module is never evicted from the cache when it grows too large. It is still
+    /// removed when the whole cache is flushed.
+    pinned: bool,
     /// Cached verified module. Must always be verified.
     module: Arc<ModuleCode<Deserialized, Verified, Extension>>,
 }
@@ -38,6 +41,7 @@ where
 
         Ok(Self {
             overridden: AtomicBool::new(false),
+            pinned: false,
             module,
         })
     }
@@ -52,12 +56,28 @@ where
         !self.overridden.load(Ordering::Acquire)
     }
 
+    /// Returns true if the module is pinned and cannot be evicted.
+    fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
     /// Returns the module code stored is this [Entry].
     fn module_code(&self) -> &Arc<ModuleCode<Deserialized, Verified, Extension>> {
         &self.module
     }
 }
 
+/// Summary of a single module stored in [GlobalModuleCache], used for listing cache contents.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ModuleSummary {
+    /// Serialized size of the module in bytes.
+    pub size_in_bytes: usize,
+    /// True if the cached module code is verified.
+    pub is_verified: bool,
+    /// True if the module has been overridden by publishing in the current block.
+    pub is_overridden: bool,
+}
+
 /// A global module cache for verified code that is read-only and concurrently accessed during the
 /// block execution. Modified safely only at block boundaries.
 pub struct GlobalModuleCache<K, D, V, E> {
@@ -107,6 +127,30 @@ where
         })
     }
 
+    /// Returns at most `limit` cached modules with their summaries, skipping the first `offset`
+    /// ones, and a flag which is true if there are more modules after this page. The order is
+    /// stable as long as the cache is not modified, so callers can release any locks between
+    /// pages. Module code is not cloned.
+    pub fn iter_page(&self, offset: usize, limit: usize) -> (Vec<(K, ModuleSummary)>, bool) {
+        let page = self
+            .module_cache
+            .iter()
+            .skip(offset)
+            .take(limit)
+            .map(|(key, entry)| {
+                let module = entry.module_code();
+                let summary = ModuleSummary {
+                    size_in_bytes: module.extension().size_in_bytes(),
+                    is_verified: module.code().is_verified(),
+                    is_overridden: !entry.is_not_overridden(),
+                };
+                (key.clone(), summary)
+            })
+            .collect::<Vec<_>>();
+        let has_more = offset.saturating_add(page.len()) < self.module_cache.len();
+        (page, has_more)
+    }
+
     /// Returns the number of entries in the cache.
     pub fn num_modules(&self) -> usize {
         self.module_cache.len()
@@ -117,17 +161,44 @@ where
         self.size
     }
 
-    /// Flushes the module cache.
+    /// Flushes the module cache, including pinned modules.
     pub fn flush(&mut self) {
         self.module_cache.clear();
         self.size = 0;
     }
 
+    /// Pins the cached module so that it is not removed by [GlobalModuleCache::evict_unpinned].
+    /// Returns false if the module does not exist for the associated key.
+    pub fn pin(&mut self, key: &K) -> bool {
+        self.module_cache
+            .get_mut(key)
+            .map(|entry| entry.pinned = true)
+            .is_some()
+    }
+
+    /// Returns true if the key exists in cache and the corresponding module is pinned.
+    pub fn is_pinned(&self, key: &K) -> bool {
+        self.module_cache
+            .get(key)
+            .is_some_and(|entry| entry.is_pinned())
+    }
+
+    /// Removes all modules which are not pinned from the cache.
+    pub fn evict_unpinned(&mut self) {
+        self.module_cache.retain(|_, entry| entry.is_pinned());
+        self.size = self
+            .module_cache
+            .values()
+            .map(|entry| entry.module_code().extension().size_in_bytes())
+            .sum();
+    }
+
     /// Inserts modules into the cache.
     /// Notes:
     ///   1. Only verified modules are inserted.
     ///   2. Not overridden modules should not be removed, and new modules should have unique
     ///      ownership. If these constraints are violated, a panic error is returned.
+    ///   3. If an overridden module was pinned, its replacement stays pinned.
     pub fn insert_verified(
         &mut self,
         modules: impl Iterator<Item = (K, Arc<ModuleCode<D, V, E>>)>,
@@ -135,6 +206,7 @@ where
         use hashbrown::hash_map::Entry::*;
 
         for (key, module) in modules {
+            let mut pinned = false;
             if let Occupied(entry) = self.module_cache.entry(key.clone()) {
                 if entry.get().is_not_overridden() {
                     return Err(PanicError::CodeInvariantError(
@@ -142,14 +214,15 @@ where
                     ));
                 } else {
                     self.size -= entry.get().module_code().extension().size_in_bytes();
-                    entry.remove();
+                    pinned = entry.remove().is_pinned();
                 }
             }
 
             if module.code().is_verified() {
                 self.size += module.extension().size_in_bytes();
-                let entry =
+                let mut entry =
                     Entry::new(module).expect("Module has been checked and must be verified");
+                entry.pinned = pinned;
                 let prev = self.module_cache.insert(key.clone(), entry);
 
                 // At this point, we must have removed the entry, or returned a panic error.
@@ -244,6 +317,46 @@ mod test {
         assert_eq!(cache.size_in_bytes(), 0);
     }
 
+    #[test]
+    fn test_cache_iter_page() {
+        let mut cache = GlobalModuleCache::empty();
+        for i in 0..5 {
+            cache.insert(i, mock_verified_code(i, MockExtension::new(8 * (i + 1))));
+        }
+        cache.mark_overridden(&3);
+
+        let (page, has_more) = cache.iter_page(0, 2);
+        assert_eq!(page.len(), 2);
+        assert!(has_more);
+
+        let (last_page, has_more) = cache.iter_page(4, 2);
+        assert_eq!(last_page.len(), 1);
+        assert!(!has_more);
+
+        let (empty_page, has_more) = cache.iter_page(10, 2);
+        assert!(empty_page.is_empty());
+        assert!(!has_more);
+
+        // Walking all pages visits every module exactly once.
+        let mut offset = 0;
+        let mut summaries = vec![];
+        loop {
+            let (page, has_more) = cache.iter_page(offset, 2);
+            offset += page.len();
+            summaries.extend(page);
+            if !has_more {
+                break;
+            }
+        }
+        summaries.sort_by_key(|(key, _)| *key);
+        assert_eq!(summaries.len(), 5);
+        for (key, summary) in summaries {
+            assert_eq!(summary.size_in_bytes, 8 * (key + 1));
+            assert!(summary.is_verified);
+            assert_eq!(summary.is_overridden, key == 3);
+        }
+    }
+
     #[test]
     fn test_cache_insert_verified() {
         let mut cache = GlobalModuleCache::empty();
@@ -296,4 +409,39 @@ mod test {
         assert_eq!(cache.num_modules(), 1);
         assert_eq!(cache.size_in_bytes(), 32);
     }
+
+    #[test]
+    fn test_cache_evict_unpinned() {
+        let mut cache = GlobalModuleCache::empty();
+
+        cache.insert(0, mock_verified_code(0, MockExtension::new(8)));
+        cache.insert(1, mock_verified_code(1, MockExtension::new(16)));
+        cache.insert(2, mock_verified_code(2, MockExtension::new(8)));
+        assert!(cache.pin(&1));
+        assert!(!cache.pin(&3));
+        assert!(cache.is_pinned(&1));
+        assert!(!cache.is_pinned(&0));
+
+        cache.evict_unpinned();
+        assert_eq!(cache.num_modules(), 1);
+        assert_eq!(cache.size_in_bytes(), 16);
+        assert!(cache.contains_not_overridden(&1));
+
+        cache.flush();
+        assert_eq!(cache.num_modules(), 0);
+        assert_eq!(cache.size_in_bytes(), 0);
+    }
+
+    #[test]
+    fn test_cache_insert_verified_keeps_overridden_modules_pinned() {
+        let mut cache = GlobalModuleCache::empty();
+
+        cache.insert(0, mock_verified_code(0, MockExtension::new(8)));
+        assert!(cache.pin(&0));
+        cache.mark_overridden(&0);
+
+        let new_modules = vec![(0, mock_verified_code(100, MockExtension::new(32)))];
+        assert_ok!(cache.insert_verified(new_modules.into_iter()));
+        assert!(cache.is_pinned(&0));
+    }
 }
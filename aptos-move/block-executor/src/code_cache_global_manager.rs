@@ -2,26 +2,34 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    code_cache_global::GlobalModuleCache,
+    code_cache_global::{GlobalModuleCache, ModuleSummary},
     counters::{
         GLOBAL_MODULE_CACHE_NUM_MODULES, GLOBAL_MODULE_CACHE_SIZE_IN_BYTES,
         STRUCT_NAME_INDEX_MAP_NUM_ENTRIES,
     },
 };
+use aptos_logger::{info, warn};
 use aptos_types::{
     block_executor::{
         config::BlockExecutorModuleCacheLocalConfig,
         transaction_slice_metadata::TransactionSliceMetadata,
     },
     error::PanicError,
-    state_store::StateView,
+    state_store::{
+        state_key::StateKey, state_storage_usage::StateStorageUsage, state_value::StateValue,
+        StateView, StateViewId, StateViewResult, TStateView,
+    },
+    transaction::Version,
     vm::modules::AptosModuleExtension,
 };
-use aptos_vm_environment::environment::AptosEnvironment;
+use aptos_vm_environment::environment::{
+    AptosEnvironment, EnvironmentComponent, EnvironmentFingerprint,
+};
 use aptos_vm_logging::alert;
 use aptos_vm_types::module_and_script_storage::AsAptosCodeStorage;
 use cfg_if::cfg_if;
 use move_binary_format::{
+    access::ModuleAccess,
     errors::{Location, VMError},
     CompiledModule,
 };
@@ -31,7 +39,12 @@ use move_core_types::{
 use move_vm_runtime::{Module, ModuleStorage, WithRuntimeEnvironment};
 use move_vm_types::code::WithSize;
 use parking_lot::{Mutex, MutexGuard};
-use std::{hash::Hash, ops::Deref, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    ops::Deref,
+    sync::Arc,
+};
 
 /// Raises an alert with the specified message. In case we run in testing mode, instead prints the
 /// message to standard output.
@@ -62,6 +75,9 @@ pub struct ModuleCacheManager<K, D, V, E> {
     /// responsibility of [ModuleCacheManager] to ensure it stays in sync with the environment and
     /// the state.
     module_cache: GlobalModuleCache<K, D, V, E>,
+    /// True if the module cache size was above the warning threshold when last checked. Used to
+    /// log the warning only once until the size drops below the threshold again.
+    module_cache_size_above_warning_threshold: bool,
 }
 
 impl<K, D, V, E> ModuleCacheManager<K, D, V, E>
@@ -77,6 +93,7 @@ where
             transaction_slice_metadata: TransactionSliceMetadata::unknown(),
             environment: None,
             module_cache: GlobalModuleCache::empty(),
+            module_cache_size_above_warning_threshold: false,
         }
     }
 
@@ -87,6 +104,10 @@ where
     ///   3. Checks if environment is set and is the same. If not, resets it. Module caches are
     ///      flushed in case of resets.
     ///   4. Checks sizes of type and module caches. If they are too large, caches are flushed.
+    ///      If only the module cache is too large, pinned modules (e.g., Aptos framework) are kept
+    ///      unless they alone exceed the limit.
+    ///   5. If the cross-block module cache is disabled, flushes the module cache so that no
+    ///      modules cached by previous blocks are used.
     fn check_ready(
         &mut self,
         storage_environment: AptosEnvironment,
@@ -103,10 +124,15 @@ where
 
         // Next, check the environment. If the current environment has not been set, or is
         // different, we reset it to the new one, and flush the module cache.
-        let environment_requires_update = self.environment.as_ref() != Some(&storage_environment);
-        if environment_requires_update {
-            self.environment = Some(storage_environment);
-            self.module_cache.flush();
+        if let Some(changed_components) = self.update_environment(storage_environment) {
+            if !changed_components.is_empty() {
+                let changed_components: Vec<_> =
+                    changed_components.iter().map(|c| c.as_str()).collect();
+                info!(
+                    "Environment changed ({}), module cache is flushed",
+                    changed_components.join(", ")
+                );
+            }
         }
 
         let environment = self.environment.as_ref().expect("Environment must be set");
@@ -128,13 +154,63 @@ where
         GLOBAL_MODULE_CACHE_SIZE_IN_BYTES.set(module_cache_size_in_bytes as i64);
         GLOBAL_MODULE_CACHE_NUM_MODULES.set(self.module_cache.num_modules() as i64);
 
-        // If module cache stores too many modules, flush it as well.
+        self.check_module_cache_size_warning_threshold(
+            module_cache_size_in_bytes,
+            config.module_cache_size_warning_threshold_in_bytes,
+        );
+
+        // If module cache stores too many modules, evict all modules except pinned ones. If this
+        // is not enough, flush it as well.
         if module_cache_size_in_bytes > config.max_module_cache_size_in_bytes {
+            self.module_cache.evict_unpinned();
+            if self.module_cache.size_in_bytes() > config.max_module_cache_size_in_bytes {
+                self.module_cache.flush();
+            }
+        }
+
+        if config.disable_cross_block_module_cache {
             self.module_cache.flush();
         }
 
         Ok(())
     }
+
+    /// Resets the environment if it is not set or is different from the given one, flushing the
+    /// module cache. Returns [None] if the environment stays the same, and otherwise components
+    /// which changed compared to the previous environment (empty if it was not set).
+    fn update_environment(
+        &mut self,
+        storage_environment: AptosEnvironment,
+    ) -> Option<Vec<EnvironmentComponent>> {
+        let changed_components = match &self.environment {
+            Some(environment) if environment == &storage_environment => return None,
+            Some(environment) => environment.diff(&storage_environment),
+            None => vec![],
+        };
+        self.environment = Some(storage_environment);
+        self.module_cache.flush();
+        Some(changed_components)
+    }
+
+    /// Logs a warning when the module cache size crosses the threshold. The warning is not logged
+    /// again until the size drops below the threshold. Returns true if the warning was logged.
+    fn check_module_cache_size_warning_threshold(
+        &mut self,
+        module_cache_size_in_bytes: usize,
+        threshold_in_bytes: usize,
+    ) -> bool {
+        let above_threshold = module_cache_size_in_bytes > threshold_in_bytes;
+        let should_warn = above_threshold && !self.module_cache_size_above_warning_threshold;
+        self.module_cache_size_above_warning_threshold = above_threshold;
+
+        if should_warn {
+            warn!(
+                "Module cache size ({} bytes) exceeds the warning threshold ({} bytes)",
+                module_cache_size_in_bytes, threshold_in_bytes
+            );
+        }
+        should_warn
+    }
 }
 
 /// Module cache manager used by Aptos block executor. Ensures that only one thread has exclusive
@@ -152,6 +228,19 @@ impl AptosModuleCacheManager {
         }
     }
 
+    /// Returns a page of modules stored in the module cache using [GlobalModuleCache::iter_page].
+    /// The lock is held only while the page is collected, so that listing a large cache does not
+    /// block execution for the whole scan. Returns [None] if the manager is currently locked.
+    pub fn try_module_cache_page(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> Option<(Vec<(ModuleId, ModuleSummary)>, bool)> {
+        self.inner
+            .try_lock()
+            .map(|guard| guard.module_cache.iter_page(offset, limit))
+    }
+
     /// Tries to lock the manager. If succeeds, checks if the manager (caches, environment, etc.)
     /// is ready for execution and updates states. If fails, [AptosModuleCacheManagerGuard::None]
     /// is returned with an empty module cache.
@@ -161,16 +250,24 @@ impl AptosModuleCacheManager {
         config: &BlockExecutorModuleCacheLocalConfig,
         transaction_slice_metadata: TransactionSliceMetadata,
     ) -> Result<AptosModuleCacheManagerGuard<'_>, VMStatus> {
-        // Get the current environment from storage.
-        let storage_environment =
-            AptosEnvironment::new_with_delayed_field_optimization_enabled(&state_view);
+        // Only fingerprint configs before taking the lock: creating the environment is expensive,
+        // and is not needed if the configs are the same as for the cached environment.
+        let fingerprint = EnvironmentFingerprint::new(state_view);
 
         Ok(match self.inner.try_lock() {
             Some(mut guard) => {
+                let storage_environment = match &guard.environment {
+                    Some(environment) if environment.fingerprint() == fingerprint => {
+                        environment.clone()
+                    },
+                    _ => AptosEnvironment::new_with_delayed_field_optimization_enabled(&state_view),
+                };
                 guard.check_ready(storage_environment, config, transaction_slice_metadata)?;
                 AptosModuleCacheManagerGuard::Guard { guard }
             },
             None => {
+                let storage_environment =
+                    AptosEnvironment::new_with_delayed_field_optimization_enabled(&state_view);
                 alert_or_println!("Locking module cache manager failed, fallback to empty caches");
 
                 // If this is true, we failed to acquire a lock, and so default storage environment
@@ -195,8 +292,11 @@ impl AptosModuleCacheManager {
 
         // To avoid cold starts, fetch the framework code. This ensures the state with 0 modules
         // cached is not possible for block execution (as long as the config enables the framework
-        // prefetch).
-        if guard.module_cache().num_modules() == 0 && config.prefetch_framework_code {
+        // prefetch). Prefetching is skipped if modules must not be cached across blocks.
+        if guard.module_cache().num_modules() == 0
+            && config.prefetch_framework_code
+            && !config.disable_cross_block_module_cache
+        {
             prefetch_aptos_framework(state_view, &mut guard).map_err(|err| {
                 alert_or_println!("Failed to load Aptos framework to module cache: {:?}", err);
                 VMError::from(err).into_vm_status()
@@ -278,14 +378,104 @@ impl AptosModuleCacheManagerGuard<'_> {
     }
 }
 
+/// State view which serves values fetched in batches ahead of time, and falls back to the base
+/// state view for all other keys.
+struct PrefetchedStateView<'a, S> {
+    base_view: &'a S,
+    prefetched: HashMap<StateKey, Option<StateValue>>,
+}
+
+impl<'a, S: StateView> PrefetchedStateView<'a, S> {
+    fn new(base_view: &'a S) -> Self {
+        Self {
+            base_view,
+            prefetched: HashMap::new(),
+        }
+    }
+
+    /// Starting from the specified module, fetches it and all its transitive dependencies from
+    /// the base state view. All modules at the same depth of the dependency graph are fetched in
+    /// a single batch using [TStateView::multi_get_state_values].
+    fn prefetch_transitive_closure(
+        &mut self,
+        root: ModuleId,
+        environment: &AptosEnvironment,
+    ) -> StateViewResult<()> {
+        let deserializer_config = &environment.vm_config().deserializer_config;
+
+        let mut visited = HashSet::from([root.clone()]);
+        let mut pending = vec![root];
+        while !pending.is_empty() {
+            let keys = pending.iter().map(StateKey::module_id).collect::<Vec<_>>();
+            let values = self.base_view.multi_get_state_values(&keys)?;
+
+            pending.clear();
+            for (key, value) in keys.into_iter().zip(values) {
+                if let Some(value) = &value {
+                    // If the module cannot be deserialized, we do not traverse its dependencies.
+                    // The error is surfaced later when the module is loaded.
+                    if let Ok(module) =
+                        CompiledModule::deserialize_with_config(value.bytes(), deserializer_config)
+                    {
+                        for dependency in module.immediate_dependencies() {
+                            if visited.insert(dependency.clone()) {
+                                pending.push(dependency);
+                            }
+                        }
+                    }
+                }
+                self.prefetched.insert(key, value);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S: StateView> TStateView for PrefetchedStateView<'_, S> {
+    type Key = StateKey;
+
+    fn id(&self) -> StateViewId {
+        self.base_view.id()
+    }
+
+    fn get_usage(&self) -> StateViewResult<StateStorageUsage> {
+        self.base_view.get_usage()
+    }
+
+    fn next_version(&self) -> Version {
+        self.base_view.next_version()
+    }
+
+    fn get_state_value(&self, state_key: &StateKey) -> StateViewResult<Option<StateValue>> {
+        match self.prefetched.get(state_key) {
+            Some(value) => Ok(value.clone()),
+            None => self.base_view.get_state_value(state_key),
+        }
+    }
+}
+
 /// If Aptos framework exists, loads "transaction_validation.move" and all its transitive
-/// dependencies from storage into provided module cache. If loading fails for any reason, a panic
-/// error is returned.
+/// dependencies from storage into provided module cache. Modules are fetched from storage in
+/// batches before loading, and are pinned in the cache so that they are never evicted when it
+/// grows too large. If loading fails for any reason, a panic error is returned.
 fn prefetch_aptos_framework(
     state_view: &impl StateView,
     guard: &mut AptosModuleCacheManagerGuard,
 ) -> Result<(), PanicError> {
-    let code_storage = state_view.as_aptos_code_storage(guard.environment());
+    let mut prefetched_state_view = PrefetchedStateView::new(state_view);
+    prefetched_state_view
+        .prefetch_transitive_closure(
+            ModuleId::new(
+                AccountAddress::ONE,
+                ident_str!("transaction_validation").to_owned(),
+            ),
+            guard.environment(),
+        )
+        .map_err(|err| {
+            PanicError::CodeInvariantError(format!("Unable to fetch Aptos framework: {:?}", err))
+        })?;
+
+    let code_storage = prefetched_state_view.as_aptos_code_storage(guard.environment());
 
     cfg_if! {
         if #[cfg(fuzzing)] {
@@ -307,10 +497,15 @@ fn prefetch_aptos_framework(
     if maybe_loaded.is_some() {
         // Framework must have been loaded. Drain verified modules from local cache into
         // global cache.
-        let verified_module_code_iter = code_storage.into_verified_module_code_iter()?;
-        guard
-            .module_cache_mut()
-            .insert_verified(verified_module_code_iter)?;
+        let mut framework_module_ids = vec![];
+        let verified_module_code_iter = code_storage
+            .into_verified_module_code_iter()?
+            .inspect(|(module_id, _)| framework_module_ids.push(module_id.clone()));
+        let module_cache = guard.module_cache_mut();
+        module_cache.insert_verified(verified_module_code_iter)?;
+        for module_id in &framework_module_ids {
+            module_cache.pin(module_id);
+        }
     }
     Ok(())
 }
@@ -318,7 +513,7 @@ fn prefetch_aptos_framework(
 #[cfg(test)]
 mod test {
     use super::*;
-    use aptos_transaction_simulation::InMemoryStateStore;
+    use aptos_transaction_simulation::{InMemoryStateStore, SimulationStateStore};
     use aptos_types::{
         on_chain_config::{FeatureFlag, Features, OnChainConfig},
         state_store::{state_key::StateKey, state_value::StateValue, MockStateView},
@@ -344,6 +539,11 @@ mod test {
         let result = prefetch_aptos_framework(&state_view, &mut guard);
         assert!(result.is_ok());
         assert!(guard.module_cache().num_modules() > 0);
+
+        let (modules, _) = guard.module_cache().iter_page(0, usize::MAX);
+        for (module_id, _) in &modules {
+            assert!(guard.module_cache().is_pinned(module_id));
+        }
     }
 
     #[test]
@@ -358,6 +558,190 @@ mod test {
         assert_eq!(guard.module_cache().num_modules(), 0);
     }
 
+    #[test]
+    fn test_prefetched_aptos_framework_is_closed_under_dependencies() {
+        let state_view = InMemoryStateStore::from_head_genesis();
+        let mut guard = AptosModuleCacheManagerGuard::none_for_state_view(&state_view);
+        assert_ok!(prefetch_aptos_framework(&state_view, &mut guard));
+
+        // Modules are cached only after all their dependencies are verified, so every cached
+        // module must have its immediate dependencies cached as well.
+        let (modules, _) = guard.module_cache().iter_page(0, usize::MAX);
+        assert!(!modules.is_empty());
+        for (module_id, _) in &modules {
+            let module = guard.module_cache().get(module_id).unwrap();
+            for dependency in module.code().deserialized().immediate_dependencies() {
+                assert!(
+                    guard.module_cache().get(&dependency).is_some(),
+                    "Module {} is cached before its dependency {}",
+                    module_id,
+                    dependency
+                );
+            }
+        }
+    }
+
+    /// State view which fetches values in batches, counting the number of batches.
+    struct BatchingStateView {
+        base_view: InMemoryStateStore,
+        num_batches: AtomicU64,
+    }
+
+    impl TStateView for BatchingStateView {
+        type Key = StateKey;
+
+        fn get_usage(&self) -> StateViewResult<StateStorageUsage> {
+            self.base_view.get_usage()
+        }
+
+        fn get_state_value(&self, state_key: &StateKey) -> StateViewResult<Option<StateValue>> {
+            self.base_view.get_state_value(state_key)
+        }
+
+        fn multi_get_state_values(
+            &self,
+            state_keys: &[StateKey],
+        ) -> StateViewResult<Vec<Option<StateValue>>> {
+            self.num_batches.fetch_add(1, Ordering::Relaxed);
+            state_keys
+                .iter()
+                .map(|state_key| self.base_view.get_state_value(state_key))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_prefetch_aptos_framework_batched_and_per_key_are_identical() {
+        let state_view = InMemoryStateStore::from_head_genesis();
+        let mut per_key_guard = AptosModuleCacheManagerGuard::none_for_state_view(&state_view);
+        assert_ok!(prefetch_aptos_framework(&state_view, &mut per_key_guard));
+
+        let batching_state_view = BatchingStateView {
+            base_view: InMemoryStateStore::from_head_genesis(),
+            num_batches: AtomicU64::new(0),
+        };
+        let mut batched_guard =
+            AptosModuleCacheManagerGuard::none_for_state_view(&batching_state_view);
+        assert_ok!(prefetch_aptos_framework(
+            &batching_state_view,
+            &mut batched_guard
+        ));
+
+        // Modules are fetched level by level, so there are fewer batches than modules.
+        let num_modules = batched_guard.module_cache().num_modules();
+        let num_batches = batching_state_view.num_batches.load(Ordering::Relaxed) as usize;
+        assert!(num_batches > 0);
+        assert!(num_batches < num_modules);
+
+        let (per_key_modules, _) = per_key_guard.module_cache().iter_page(0, usize::MAX);
+        let (batched_modules, _) = batched_guard.module_cache().iter_page(0, usize::MAX);
+        assert!(!per_key_modules.is_empty());
+        assert_eq!(
+            per_key_modules.into_iter().collect::<HashMap<_, _>>(),
+            batched_modules.into_iter().collect::<HashMap<_, _>>()
+        );
+    }
+
+    /// State view which counts reads of Aptos framework code.
+    struct CodeReadCountingStateView {
+        base_view: InMemoryStateStore,
+        num_code_reads: AtomicU64,
+    }
+
+    impl CodeReadCountingStateView {
+        fn count(&self, state_key: &StateKey) {
+            if state_key.is_aptos_code() {
+                self.num_code_reads.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    impl TStateView for CodeReadCountingStateView {
+        type Key = StateKey;
+
+        fn get_usage(&self) -> StateViewResult<StateStorageUsage> {
+            self.base_view.get_usage()
+        }
+
+        fn get_state_value(&self, state_key: &StateKey) -> StateViewResult<Option<StateValue>> {
+            self.count(state_key);
+            self.base_view.get_state_value(state_key)
+        }
+
+        fn multi_get_state_values(
+            &self,
+            state_keys: &[StateKey],
+        ) -> StateViewResult<Vec<Option<StateValue>>> {
+            state_keys
+                .iter()
+                .map(|state_key| self.get_state_value(state_key))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_try_lock_does_not_prefetch_cached_framework_again() {
+        let manager = AptosModuleCacheManager::new();
+        let state_view = CodeReadCountingStateView {
+            base_view: InMemoryStateStore::from_head_genesis(),
+            num_code_reads: AtomicU64::new(0),
+        };
+        let config = BlockExecutorModuleCacheLocalConfig::default();
+        assert!(config.prefetch_framework_code);
+
+        let metadata_1 = TransactionSliceMetadata::block_from_u64(0, 1);
+        let num_modules = {
+            let guard = assert_ok!(manager.try_lock(&state_view, &config, metadata_1));
+            guard.module_cache().num_modules()
+        };
+        assert!(num_modules > 0);
+        assert!(state_view.num_code_reads.swap(0, Ordering::Relaxed) > 0);
+
+        // The next block with the same state finds the framework cached, so no code is fetched or
+        // verified, and the cache is unchanged.
+        let metadata_2 = TransactionSliceMetadata::block_from_u64(1, 2);
+        let guard = assert_ok!(manager.try_lock(&state_view, &config, metadata_2));
+        assert!(matches!(guard, AptosModuleCacheManagerGuard::Guard { .. }));
+        assert_eq!(guard.module_cache().num_modules(), num_modules);
+        assert_eq!(state_view.num_code_reads.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_try_lock_invalidates_cached_framework_on_environment_change() {
+        let manager = AptosModuleCacheManager::new();
+        let state_view = CodeReadCountingStateView {
+            base_view: InMemoryStateStore::from_head_genesis(),
+            num_code_reads: AtomicU64::new(0),
+        };
+        let config = BlockExecutorModuleCacheLocalConfig::default();
+
+        let metadata_1 = TransactionSliceMetadata::block_from_u64(0, 1);
+        let (environment, cached_module) = {
+            let guard = assert_ok!(manager.try_lock(&state_view, &config, metadata_1));
+            let (modules, _) = guard.module_cache().iter_page(0, 1);
+            let (module_id, _) = modules.into_iter().next().unwrap();
+            let module = guard.module_cache().get(&module_id).unwrap();
+            (guard.environment().clone(), (module_id, module))
+        };
+        state_view.num_code_reads.store(0, Ordering::Relaxed);
+
+        // Change the environment between consecutive blocks.
+        let mut features: Features = assert_ok!(state_view.base_view.get_on_chain_config());
+        features.disable(FeatureFlag::EMIT_FEE_STATEMENT);
+        assert_ok!(state_view.base_view.set_on_chain_config(&features));
+
+        // Modules cached under the previous environment are not used, and the framework is
+        // fetched and verified again under the new one.
+        let metadata_2 = TransactionSliceMetadata::block_from_u64(1, 2);
+        let guard = assert_ok!(manager.try_lock(&state_view, &config, metadata_2));
+        assert!(guard.environment() != &environment);
+        assert!(state_view.num_code_reads.load(Ordering::Relaxed) > 0);
+
+        let (module_id, module) = cached_module;
+        let rebuilt_module = guard.module_cache().get(&module_id).unwrap();
+        assert!(!Arc::ptr_eq(&module, &rebuilt_module));
+    }
+
     fn add_struct_identifier<K, D, V, E>(manager: &mut ModuleCacheManager<K, D, V, E>, name: &str)
     where
         K: Hash + Eq + Clone,
@@ -421,7 +805,9 @@ mod test {
         let config = BlockExecutorModuleCacheLocalConfig {
             prefetch_framework_code: false,
             max_module_cache_size_in_bytes: 32,
+            module_cache_size_warning_threshold_in_bytes: 32,
             max_struct_name_index_map_num_entries: 2,
+            disable_cross_block_module_cache: false,
         };
 
         // Populate the cache for testing.
@@ -549,6 +935,160 @@ mod test {
         assert_struct_name_index_map_size_eq(&manager, 0);
     }
 
+    #[test]
+    fn test_disabled_cross_block_module_cache_has_no_hits() {
+        let state_view = MockStateView::empty();
+        let num_hits = |disable_cross_block_module_cache| {
+            let config = BlockExecutorModuleCacheLocalConfig {
+                prefetch_framework_code: false,
+                disable_cross_block_module_cache,
+                ..BlockExecutorModuleCacheLocalConfig::default()
+            };
+            let mut manager = ModuleCacheManager::new();
+            let mut num_hits = 0;
+            for block in 0..5 {
+                let metadata = TransactionSliceMetadata::block_from_u64(block, block + 1);
+                assert_ok!(manager.check_ready(
+                    AptosEnvironment::new(&state_view),
+                    &config,
+                    metadata
+                ));
+                num_hits += (0..2)
+                    .filter(|key| manager.module_cache.get(key).is_some())
+                    .count();
+
+                // Modules used by the block are cached at its end.
+                manager
+                    .module_cache
+                    .insert(0, mock_verified_code(0, MockExtension::new(8)));
+                manager
+                    .module_cache
+                    .insert(1, mock_verified_code(1, MockExtension::new(8)));
+            }
+            num_hits
+        };
+
+        assert_eq!(num_hits(false), 8);
+        assert_eq!(num_hits(true), 0);
+    }
+
+    #[test]
+    fn test_environment_reset_attributed_to_features() {
+        let mut manager = ModuleCacheManager::new();
+        let state_view = MockStateView::empty();
+
+        // Setting the initial environment is not attributed to any component.
+        assert_eq!(
+            manager.update_environment(AptosEnvironment::new(&state_view)),
+            Some(vec![])
+        );
+        assert_eq!(
+            manager.update_environment(AptosEnvironment::new(&state_view)),
+            None
+        );
+
+        manager
+            .module_cache
+            .insert(0, mock_verified_code(0, MockExtension::new(8)));
+        let state_view = state_view_with_changed_feature_flag(FeatureFlag::EMIT_FEE_STATEMENT);
+        assert_eq!(
+            manager.update_environment(AptosEnvironment::new(&state_view)),
+            Some(vec![EnvironmentComponent::Features])
+        );
+        assert_eq!(manager.module_cache.num_modules(), 0);
+    }
+
+    #[test]
+    fn test_check_ready_keeps_pinned_modules_when_cache_is_too_large() {
+        let mut manager = ModuleCacheManager::new();
+        let state_view = MockStateView::empty();
+        let config = BlockExecutorModuleCacheLocalConfig {
+            prefetch_framework_code: false,
+            max_module_cache_size_in_bytes: 32,
+            module_cache_size_warning_threshold_in_bytes: 32,
+            max_struct_name_index_map_num_entries: 2,
+            disable_cross_block_module_cache: false,
+        };
+        let metadata_1 = TransactionSliceMetadata::block_from_u64(0, 1);
+        assert_ok!(manager.check_ready(AptosEnvironment::new(&state_view), &config, metadata_1));
+
+        // Module 0 is a pinned framework module, module 1 is a user module.
+        manager
+            .module_cache
+            .insert(0, mock_verified_code(0, MockExtension::new(16)));
+        assert!(manager.module_cache.pin(&0));
+        manager
+            .module_cache
+            .insert(1, mock_verified_code(1, MockExtension::new(24)));
+
+        let metadata_2 = TransactionSliceMetadata::block_from_u64(1, 2);
+        assert_ok!(manager.check_ready(AptosEnvironment::new(&state_view), &config, metadata_2));
+        assert!(manager.module_cache.contains_not_overridden(&0));
+        assert!(!manager.module_cache.contains_not_overridden(&1));
+        assert_eq!(manager.module_cache.size_in_bytes(), 16);
+
+        // Pinned modules alone exceeding the limit are flushed.
+        manager
+            .module_cache
+            .insert(2, mock_verified_code(2, MockExtension::new(24)));
+        assert!(manager.module_cache.pin(&2));
+
+        let metadata_3 = TransactionSliceMetadata::block_from_u64(2, 3);
+        assert_ok!(manager.check_ready(AptosEnvironment::new(&state_view), &config, metadata_3));
+        assert_eq!(manager.module_cache.num_modules(), 0);
+
+        // Explicit flushes still remove pinned modules.
+        manager
+            .module_cache
+            .insert(0, mock_verified_code(0, MockExtension::new(16)));
+        assert!(manager.module_cache.pin(&0));
+
+        let metadata_4 = TransactionSliceMetadata::block_from_u64(4, 5);
+        assert!(!metadata_4.is_immediately_after(&metadata_3));
+        assert_ok!(manager.check_ready(AptosEnvironment::new(&state_view), &config, metadata_4));
+        assert_eq!(manager.module_cache.num_modules(), 0);
+    }
+
+    #[test]
+    fn test_module_cache_size_warning_threshold() {
+        let mut manager = ModuleCacheManager::new();
+        let state_view = MockStateView::empty();
+        let config = BlockExecutorModuleCacheLocalConfig {
+            prefetch_framework_code: false,
+            max_module_cache_size_in_bytes: 64,
+            module_cache_size_warning_threshold_in_bytes: 16,
+            max_struct_name_index_map_num_entries: 2,
+            disable_cross_block_module_cache: false,
+        };
+        let metadata_1 = TransactionSliceMetadata::block_from_u64(0, 1);
+        assert_ok!(manager.check_ready(AptosEnvironment::new(&state_view), &config, metadata_1));
+
+        // Below the threshold, no warning.
+        manager
+            .module_cache
+            .insert(0, mock_verified_code(0, MockExtension::new(16)));
+        assert!(!manager.check_module_cache_size_warning_threshold(16, 16));
+
+        // Crossing the threshold warns exactly once, even if the cache keeps growing.
+        manager
+            .module_cache
+            .insert(1, mock_verified_code(1, MockExtension::new(16)));
+        assert!(manager.check_module_cache_size_warning_threshold(32, 16));
+        assert!(!manager.check_module_cache_size_warning_threshold(32, 16));
+        assert!(!manager.check_module_cache_size_warning_threshold(48, 16));
+
+        // Subsequent blocks keep the cache (below the hard limit) and do not warn again.
+        let metadata_2 = TransactionSliceMetadata::block_from_u64(1, 2);
+        assert_ok!(manager.check_ready(AptosEnvironment::new(&state_view), &config, metadata_2));
+        assert_eq!(manager.module_cache.size_in_bytes(), 32);
+        assert!(manager.module_cache_size_above_warning_threshold);
+        assert!(!manager.check_module_cache_size_warning_threshold(32, 16));
+
+        // Dropping below the threshold re-arms the warning.
+        assert!(!manager.check_module_cache_size_warning_threshold(0, 16));
+        assert!(manager.check_module_cache_size_warning_threshold(32, 16));
+    }
+
     #[test]
     fn test_try_lock_inner_single_thread() {
         let manager = AptosModuleCacheManager::new();
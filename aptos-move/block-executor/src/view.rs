@@ -2549,6 +2549,44 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_module_read_hits_global_module_cache() {
+        use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+        use move_vm_types::code::{ModuleCache, ModuleCode};
+
+        let unsync_map = UnsyncMap::new();
+        let counter = RefCell::new(0);
+        let base_view = MockStateView::empty();
+        let runtime_environment = RuntimeEnvironment::new(vec![]);
+
+        // Seed the global cache directly, without publishing or initializing the cache.
+        let module_id = ModuleId::new(AccountAddress::ONE, Identifier::new("test").unwrap());
+        let cached_module = Arc::new(ModuleCode::from_arced_verified(
+            Arc::new(Module::new_for_test(module_id.clone())),
+            Arc::new(AptosModuleExtension::new(StateValue::new_legacy(
+                vec![0].into(),
+            ))),
+        ));
+        let mut global_module_cache = GlobalModuleCache::empty();
+        global_module_cache.insert(module_id.clone(), cached_module.clone());
+
+        let latest_view = LatestView::<TestTransactionType, MockStateView<KeyType<u32>>>::new(
+            &base_view,
+            &global_module_cache,
+            &runtime_environment,
+            ViewState::Unsync(SequentialState::new(&unsync_map, 0, &counter)),
+            1,
+        );
+
+        // The base view is empty, so the module can only be returned from the global cache.
+        let (module, version) = latest_view
+            .get_module_or_build_with(&module_id, &latest_view)
+            .unwrap()
+            .unwrap();
+        assert!(Arc::ptr_eq(&module, &cached_module));
+        assert_none!(version);
+    }
+
     #[test]
     fn test_id_value_exchange() {
         let unsync_map = UnsyncMap::new();
@@ -0,0 +1,173 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Expected times of the entry points, and baselines of previous runs to compare against.
+
+use crate::output::EntryPointResult;
+use aptos_transaction_workloads_lib::EntryPoints;
+use serde_json::Value;
+use std::{collections::HashMap, fs};
+
+/// Margin added on top of the measured time when suggesting a new expected time, so that the
+/// suggested baseline is not immediately regressed by noise.
+const SUGGESTED_EXPECTED_TIME_MARGIN: f64 = 0.02;
+
+const CALIBRATION_VALUES_PATH: &str = "aptos-move/e2e-benchmark/data/calibration_values.tsv";
+
+pub struct CalibrationInfo {
+    // count: usize,
+    pub expected_time_micros: f64,
+    pub min_ratio: f64,
+    pub max_ratio: f64,
+}
+
+pub fn get_parsed_calibration_values() -> HashMap<String, CalibrationInfo> {
+    let calibration_values =
+        fs::read_to_string(CALIBRATION_VALUES_PATH).expect("Unable to read file");
+    calibration_values
+        .trim()
+        .split('\n')
+        .map(|line| {
+            let parts = line.split('\t').collect::<Vec<_>>();
+            (parts[0].to_string(), CalibrationInfo {
+                // count: parts[1].parse().unwrap(),
+                expected_time_micros: parts[parts.len() - 1].parse().expect(line),
+                min_ratio: parts[2].parse().expect(line),
+                max_ratio: parts[3].parse().expect(line),
+            })
+        })
+        .collect()
+}
+
+/// Returns the expected time to use for an entry point which got faster: the measured time with
+/// a small margin, rounded up to the precision of the calibration values.
+pub fn suggested_expected_time(measured_micros: f64) -> f64 {
+    (measured_micros * (1.0 + SUGGESTED_EXPECTED_TIME_MARGIN) * 10.0).ceil() / 10.0
+}
+
+/// Writes the calibration values to the given path, replacing the expected times of entry points
+/// by the suggested ones. All other entries and columns are copied unchanged.
+pub fn write_baseline(path: &str, suggested_expected_times: &HashMap<String, f64>) {
+    let calibration_values =
+        fs::read_to_string(CALIBRATION_VALUES_PATH).expect("Unable to read file");
+    let mut baseline = String::new();
+    for line in calibration_values.trim().split('\n') {
+        let mut parts = line.split('\t').collect::<Vec<_>>();
+        let suggested = suggested_expected_times
+            .get(parts[0])
+            .map(|expected_time_micros| format!("{:.1}", expected_time_micros));
+        if let Some(suggested) = &suggested {
+            *parts.last_mut().unwrap() = suggested.as_str();
+        }
+        baseline.push_str(&parts.join("\t"));
+        baseline.push('\n');
+    }
+    fs::write(path, baseline).expect("Unable to write baseline");
+}
+
+/// Wall time and gas of an entry point in the baseline.
+pub struct BaselineEntry {
+    pub wall_time_us: f64,
+    pub execution_gas_units: f64,
+    pub io_gas_units: f64,
+}
+
+/// Loads the wall times and gas of entry points from the JSON lines of a previous run, e.g., written with
+/// `--output` or copied from stdout. Lines which are not entry point results are ignored.
+pub fn load_baseline(path: &str) -> HashMap<String, BaselineEntry> {
+    let baseline = fs::read_to_string(path).expect("Unable to read baseline");
+    baseline
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line.trim()).ok())
+        .filter(|line| line["grep"] == "grep_json_aptos_move_vm_perf")
+        .map(|line| {
+            let transaction_type = line["transaction_type"].as_str().expect("transaction_type");
+            let entry = BaselineEntry {
+                wall_time_us: line["wall_time_us"].as_f64().expect("wall_time_us"),
+                execution_gas_units: line["execution_gas_units"]
+                    .as_f64()
+                    .expect("execution_gas_units"),
+                io_gas_units: line["io_gas_units"].as_f64().expect("io_gas_units"),
+            };
+            (transaction_type.to_string(), entry)
+        })
+        .collect()
+}
+
+/// Returns a failure if the gas charged differs from the baseline. Gas is deterministic, so any
+/// increase is a regression, and any decrease means the baseline needs to be updated.
+pub fn check_gas(
+    expected: &BaselineEntry,
+    execution_gas_units: f64,
+    io_gas_units: f64,
+    entry_point: &EntryPoints,
+) -> Option<(bool, String)> {
+    if execution_gas_units == expected.execution_gas_units && io_gas_units == expected.io_gas_units
+    {
+        return None;
+    }
+    let is_regression =
+        execution_gas_units > expected.execution_gas_units || io_gas_units > expected.io_gas_units;
+    let failure = format!(
+        "Gas {} detected: {:.2} exe gas and {:.2} io gas, expected {:.2} exe gas and {:.2} io gas, for {:?}",
+        if is_regression { "regression" } else { "improvement" },
+        execution_gas_units,
+        io_gas_units,
+        expected.execution_gas_units,
+        expected.io_gas_units,
+        entry_point
+    );
+    Some((is_regression, failure))
+}
+
+/// Prints the wall time of every entry point next to the one in the baseline, and which entry
+/// points are only in the baseline or only in this run.
+pub fn print_baseline_comparison(
+    baseline: &HashMap<String, BaselineEntry>,
+    results: &[EntryPointResult],
+) {
+    println!("Comparison against baseline:");
+    println!(
+        "{:>13} {:>13} {:>13}  entry point",
+        "walltime(us)", "baseline(us)", "dif(- is impr)"
+    );
+    let mut not_in_baseline = vec![];
+    for result in results {
+        match baseline.get(&result.entry_point) {
+            Some(BaselineEntry {
+                wall_time_us: baseline_micros,
+                ..
+            }) => println!(
+                "{:13.1} {:13.1} {:12.1}%  {}",
+                result.wall_time_us,
+                baseline_micros,
+                (result.wall_time_us - baseline_micros) / baseline_micros * 100.0,
+                result.entry_point
+            ),
+            None => not_in_baseline.push(result.entry_point.as_str()),
+        }
+    }
+
+    let mut not_run = baseline
+        .keys()
+        .filter(|entry_point| {
+            !results
+                .iter()
+                .any(|result| &result.entry_point == *entry_point)
+        })
+        .collect::<Vec<_>>();
+    not_run.sort();
+    if !not_in_baseline.is_empty() || !not_run.is_empty() {
+        println!(
+            "Baseline does not match this run: {} entry points not in baseline (compared against expected times), {} baseline entry points not run",
+            not_in_baseline.len(),
+            not_run.len()
+        );
+        for entry_point in not_in_baseline {
+            println!("  not in baseline: {}", entry_point);
+        }
+        for entry_point in not_run {
+            println!("  not run: {}", entry_point);
+        }
+    }
+}
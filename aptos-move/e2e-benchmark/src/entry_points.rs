@@ -0,0 +1,300 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Entry points run by the benchmark, and whether they block landing.
+
+use aptos_transaction_workloads_lib::{EntryPoints, LoopType, MapType, OrderBookState};
+
+// making constants to allow for easier change of type and addition of othe options
+pub const LANDBLOCKING_AND_CONTINUOUS: bool = true;
+pub const ONLY_CONTINUOUS: bool = false;
+
+/// All benchmarked entry points, with whether they are run in the land blocking flow or only in
+/// the continuous one.
+pub fn benchmark_entry_points() -> Vec<(bool, EntryPoints)> {
+    vec![
+        // too fast for the timer
+        // (, EntryPoints::Nop),
+        // (, EntryPoints::BytesMakeOrChange {
+        //     data_length: Some(32),
+        // }),
+        // (, EntryPoints::IncGlobal),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::Loop {
+            loop_count: Some(100000),
+            loop_type: LoopType::NoOp,
+        }),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::Loop {
+            loop_count: Some(10000),
+            loop_type: LoopType::Arithmetic,
+        }),
+        // This is a cheap bcs (serializing vec<u8>), so not representative of what BCS native call should cost.
+        // (, EntryPoints::Loop { loop_count: Some(1000), loop_type: LoopType::BcsToBytes { len: 1024 }}),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::CreateObjects {
+            num_objects: 10,
+            object_payload_size: 0,
+        }),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::CreateObjects {
+            num_objects: 10,
+            object_payload_size: 10 * 1024,
+        }),
+        (ONLY_CONTINUOUS, EntryPoints::CreateObjects {
+            num_objects: 100,
+            object_payload_size: 0,
+        }),
+        (ONLY_CONTINUOUS, EntryPoints::CreateObjects {
+            num_objects: 100,
+            object_payload_size: 10 * 1024,
+        }),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::InitializeVectorPicture { length: 128 },
+        ),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::VectorPicture {
+            length: 128,
+        }),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::VectorPictureRead { length: 128 },
+        ),
+        (ONLY_CONTINUOUS, EntryPoints::InitializeVectorPicture {
+            length: 30 * 1024,
+        }),
+        (ONLY_CONTINUOUS, EntryPoints::VectorPicture {
+            length: 30 * 1024,
+        }),
+        (ONLY_CONTINUOUS, EntryPoints::VectorPictureRead {
+            length: 30 * 1024,
+        }),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::SmartTablePicture {
+                length: 30 * 1024,
+                num_points_per_txn: 200,
+            },
+        ),
+        (ONLY_CONTINUOUS, EntryPoints::SmartTablePicture {
+            length: 1024 * 1024,
+            num_points_per_txn: 300,
+        }),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::ResourceGroupsSenderWriteTag {
+                string_length: 1024,
+            },
+        ),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::ResourceGroupsSenderMultiChange {
+                string_length: 1024,
+            },
+        ),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::TokenV1MintAndTransferFT,
+        ),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::TokenV1MintAndTransferNFTSequential,
+        ),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::TokenV2AmbassadorMint { numbered: true },
+        ),
+        (ONLY_CONTINUOUS, EntryPoints::LiquidityPoolSwap {
+            is_stable: true,
+        }),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::LiquidityPoolSwap { is_stable: false },
+        ),
+        // Multi-agent transaction with 3 distinct signers. Its body is empty, so it is too fast to
+        // block landing on.
+        (ONLY_CONTINUOUS, EntryPoints::Nop3Signers),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::CoinInitAndMint),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::FungibleAssetMint),
+        // Same transfer through the legacy coin and the fungible asset paths, to compare costs.
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::CoinTransfer),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::FungibleAssetTransfer,
+        ),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::IncGlobalMilestoneAggV2 { milestone_every: 1 },
+        ),
+        (ONLY_CONTINUOUS, EntryPoints::IncGlobalMilestoneAggV2 {
+            milestone_every: 2,
+        }),
+        // Every transaction increments the same global aggregator. A single increment is too
+        // cheap to measure contention sequentially, so these are only meaningful as parallel
+        // blocks (with `--parallel`), where increments are deferred instead of conflicting.
+        (ONLY_CONTINUOUS, EntryPoints::IncGlobalAggV2),
+        (ONLY_CONTINUOUS, EntryPoints::ModifyGlobalBoundedAggV2 {
+            step: 10,
+        }),
+        // Many increments of the same global aggregator per transaction, so that the cost of
+        // aggregator v2 operations dominates the transaction.
+        (ONLY_CONTINUOUS, EntryPoints::IncGlobalAggV2Repeated {
+            count: 1000,
+        }),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::EmitEvents {
+            count: 1000,
+        }),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::SignerKeyedBorrows { count: 100 },
+        ),
+        // Serialization of deeply nested structs, common for resource-heavy contracts.
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::NestedStructSerialize { depth: 4, width: 8 },
+        ),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::EmitEventsSized {
+            count: 10,
+            payload_bytes: 10240,
+        }),
+        (ONLY_CONTINUOUS, EntryPoints::EmitEventsSized {
+            count: 100,
+            payload_bytes: 1024,
+        }),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::APTTransferWithPermissionedSigner,
+        ),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::APTTransferWithMasterSigner,
+        ),
+        // long vectors with small elements
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::VectorTrimAppend {
+            // baseline, only vector creation
+            vec_len: 3000,
+            element_len: 1,
+            index: 0,
+            repeats: 0,
+        }),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::VectorTrimAppend {
+            vec_len: 3000,
+            element_len: 1,
+            index: 100,
+            repeats: 1000,
+        }),
+        (ONLY_CONTINUOUS, EntryPoints::VectorTrimAppend {
+            vec_len: 3000,
+            element_len: 1,
+            index: 2990,
+            repeats: 1000,
+        }),
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::VectorRemoveInsert {
+                vec_len: 3000,
+                element_len: 1,
+                index: 100,
+                repeats: 1000,
+            },
+        ),
+        (ONLY_CONTINUOUS, EntryPoints::VectorRemoveInsert {
+            vec_len: 3000,
+            element_len: 1,
+            index: 2998,
+            repeats: 1000,
+        }),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::VectorRangeMove {
+            vec_len: 3000,
+            element_len: 1,
+            index: 1000,
+            move_len: 500,
+            repeats: 1000,
+        }),
+        // vectors with large elements
+        (ONLY_CONTINUOUS, EntryPoints::VectorTrimAppend {
+            // baseline, only vector creation
+            vec_len: 100,
+            element_len: 100,
+            index: 0,
+            repeats: 0,
+        }),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::VectorTrimAppend {
+            vec_len: 100,
+            element_len: 100,
+            index: 10,
+            repeats: 1000,
+        }),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::VectorRangeMove {
+            vec_len: 100,
+            element_len: 100,
+            index: 50,
+            move_len: 10,
+            repeats: 1000,
+        }),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::MapInsertRemove {
+            len: 100,
+            repeats: 100,
+            map_type: MapType::OrderedMap,
+        }),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::MapInsertRemove {
+            len: 100,
+            repeats: 100,
+            map_type: MapType::SimpleMap,
+        }),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::MapInsertRemove {
+            len: 100,
+            repeats: 100,
+            map_type: MapType::BigOrderedMap {
+                inner_max_degree: 4,
+                leaf_max_degree: 4,
+            },
+        }),
+        (ONLY_CONTINUOUS, EntryPoints::MapInsertRemove {
+            len: 100,
+            repeats: 100,
+            map_type: MapType::BigOrderedMap {
+                inner_max_degree: 1024,
+                leaf_max_degree: 1024,
+            },
+        }),
+        (ONLY_CONTINUOUS, EntryPoints::MapInsertRemove {
+            len: 1000,
+            repeats: 100,
+            map_type: MapType::OrderedMap,
+        }),
+        // Tables back many dApps, so this guards the cost of hashing and bucket splits of
+        // SmartTable accesses.
+        (
+            LANDBLOCKING_AND_CONTINUOUS,
+            EntryPoints::SmartTableInsertAndLookup {
+                num_ops_per_txn: 100,
+                max_key: 10000,
+            },
+        ),
+        (LANDBLOCKING_AND_CONTINUOUS, EntryPoints::OrderBook {
+            state: OrderBookState::new(),
+            overlap_ratio: 0.0, // Since we run a single txn, no matches will happen irrespectively
+            buy_frequency: 0.5,
+            max_sell_size: 1,
+            max_buy_size: 1,
+        }),
+    ]
+}
+
+/// Returns true if all transactions calling the entry point write the same resource (e.g., a
+/// global counter, aggregator or liquidity pool), so they conflict under parallel execution.
+pub fn is_contention_heavy(entry_point: &EntryPoints) -> bool {
+    matches!(
+        entry_point,
+        EntryPoints::IncGlobal
+            | EntryPoints::IncGlobalAggV2
+            | EntryPoints::IncGlobalAggV2Repeated { .. }
+            | EntryPoints::ModifyGlobalBoundedAggV2 { .. }
+            | EntryPoints::IncGlobalMilestoneAggV2 { .. }
+            | EntryPoints::ResourceGroupsGlobalWriteTag { .. }
+            | EntryPoints::ResourceGroupsGlobalWriteAndReadTag { .. }
+            | EntryPoints::TokenV1MintAndTransferFT
+            | EntryPoints::TokenV1MintAndTransferNFTSequential
+            | EntryPoints::TokenV1MintAndStoreNFTSequential
+            | EntryPoints::LiquidityPoolSwap { .. }
+            | EntryPoints::VectorPicture { .. }
+            | EntryPoints::SmartTablePicture { .. }
+    )
+}
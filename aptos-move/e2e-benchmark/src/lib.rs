@@ -0,0 +1,397 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Harness to benchmark entry points of the prebuilt Move packages on top of [FakeExecutor], used
+//! by the `aptos-move-e2e-benchmark` binary and reusable to build custom benchmark suites.
+
+pub mod calibration;
+pub mod entry_points;
+pub mod output;
+pub mod publish;
+pub mod runner;
+
+use aptos_language_e2e_tests::{
+    account::Account,
+    executor::{ExecFuncTimerDynamicArgs, FakeExecutor, GasMeterType, Measurement},
+};
+use aptos_transaction_generator_lib::{
+    entry_point_trait::{AutomaticArgs, EntryPointTrait, MultiSigConfig},
+    publishing::publish_util::{Package, PackageHandler},
+};
+use aptos_transaction_workloads_lib::EntryPoints;
+use aptos_types::{
+    account_address::AccountAddress,
+    chain_id::ChainId,
+    transaction::{SignedTransaction, TransactionOutput, TransactionPayload},
+};
+use rand::{rngs::StdRng, SeedableRng};
+use std::{fs, time::Instant};
+
+// bump after a bigger test or perf change, so you can easily distinguish runs
+// that are on top of this commit
+pub const CODE_PERF_VERSION: &str = "v1";
+
+/// Sampling frequency (in Hz) of the profiler used for `--flamegraph`.
+const PROFILER_FREQUENCY: i32 = 1000;
+
+/// Timing and gas of a benchmarked entry point.
+#[derive(Clone, Copy, Debug)]
+pub struct BenchmarkResult {
+    /// Average time (in microseconds) of a single call.
+    pub elapsed_micros: f64,
+    pub execution_gas: f64,
+    pub io_gas: f64,
+    /// Difference from the expected time, in percent. Negative if the entry point got faster.
+    pub diff: f64,
+}
+
+impl BenchmarkResult {
+    pub fn new(measurement: &Measurement, expected_time_micros: f64) -> Self {
+        let elapsed_micros = measurement.elapsed_micros_f64();
+        Self {
+            elapsed_micros,
+            execution_gas: measurement.execution_gas_units(),
+            io_gas: measurement.io_gas_units(),
+            diff: (elapsed_micros - expected_time_micros) / expected_time_micros * 100.0,
+        }
+    }
+}
+
+pub fn execute_txn(
+    executor: &mut FakeExecutor,
+    account: &Account,
+    sequence_number: u64,
+    payload: TransactionPayload,
+) -> TransactionOutput {
+    try_execute_txn(executor, account, sequence_number, payload)
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Signs a transaction with the payload from the account, with enough gas for any entry point.
+pub fn sign_txn(
+    account: &Account,
+    sequence_number: u64,
+    payload: TransactionPayload,
+) -> SignedTransaction {
+    account
+        .transaction()
+        .sequence_number(sequence_number)
+        .max_gas_amount(2_000_000)
+        .gas_unit_price(200)
+        .payload(payload)
+        .sign()
+}
+
+/// Returns an error if the transaction did not succeed.
+pub fn check_txn_succeeded(txn_output: &TransactionOutput) -> Result<(), String> {
+    if !txn_output
+        .status()
+        .status()
+        .is_ok_and(|status| status.is_success())
+    {
+        return Err(format!("txn failed with {:?}", txn_output.status()));
+    }
+    Ok(())
+}
+
+/// Executes the transaction and applies its write set. Returns an error if the transaction did
+/// not succeed.
+pub fn try_execute_txn(
+    executor: &mut FakeExecutor,
+    account: &Account,
+    sequence_number: u64,
+    payload: TransactionPayload,
+) -> Result<TransactionOutput, String> {
+    let txn_output = executor.execute_transaction(sign_txn(account, sequence_number, payload));
+    executor.apply_write_set(txn_output.write_set());
+    check_txn_succeeded(&txn_output)?;
+    Ok(txn_output)
+}
+
+/// Times `iterations` calls of the entry point of the published package, with fresh signers
+/// for every call.
+pub fn execute_and_time_entry_point(
+    entry_point: &EntryPoints,
+    package: &Package,
+    publisher_address: &AccountAddress,
+    executor: &mut FakeExecutor,
+    iterations: u64,
+    gas_meter_type: GasMeterType,
+) -> Measurement {
+    let mut rng = StdRng::seed_from_u64(14);
+    let entry_fun = entry_point
+        .create_payload(
+            package,
+            entry_point.module_name(),
+            Some(&mut rng),
+            Some(publisher_address),
+        )
+        .into_entry_function();
+
+    executor.exec_func_record_running_time(
+        entry_fun.module(),
+        entry_fun.function().as_str(),
+        entry_fun.ty_args().to_vec(),
+        entry_fun.args().to_vec(),
+        iterations,
+        match entry_point.automatic_args() {
+            AutomaticArgs::None => ExecFuncTimerDynamicArgs::NoArgs,
+            AutomaticArgs::Signer => ExecFuncTimerDynamicArgs::DistinctSigners,
+            AutomaticArgs::SignerAndMultiSig => match entry_point.multi_sig_additional_num() {
+                MultiSigConfig::Publisher => {
+                    ExecFuncTimerDynamicArgs::DistinctSignersAndFixed(vec![*publisher_address])
+                },
+                MultiSigConfig::Random(num_additional_signers) => {
+                    ExecFuncTimerDynamicArgs::MultipleDistinctSigners(num_additional_signers + 1)
+                },
+                // The fee payer is not passed to the entry function, only the sender is.
+                MultiSigConfig::None | MultiSigConfig::FeePayerPublisher => {
+                    ExecFuncTimerDynamicArgs::DistinctSigners
+                },
+            },
+        },
+        gas_meter_type,
+    )
+}
+
+/// Runs the entry point under a sampling profiler, and writes the sampled stacks in the folded
+/// format that can be rendered by `inferno-flamegraph` or `flamegraph.pl`.
+pub fn profile_entry_point(
+    entry_point: &EntryPoints,
+    package: &Package,
+    publisher_address: &AccountAddress,
+    executor: &mut FakeExecutor,
+    iterations: u64,
+    output_path: &str,
+) {
+    let guard = pprof::ProfilerGuard::new(PROFILER_FREQUENCY).expect("Failed to start profiler");
+    execute_and_time_entry_point(
+        entry_point,
+        package,
+        publisher_address,
+        executor,
+        iterations,
+        GasMeterType::RegularGasMeter,
+    );
+    let report = guard
+        .report()
+        .build()
+        .expect("Failed to build profiler report");
+
+    let mut folded = String::new();
+    for (frames, count) in report.data.iter() {
+        // Folded stacks are listed from the root (thread) to the leaf (innermost frame).
+        let stack = std::iter::once(frames.thread_name.clone())
+            .chain(
+                frames
+                    .frames
+                    .iter()
+                    .rev()
+                    .flat_map(|frame| frame.iter().rev().map(|symbol| symbol.to_string())),
+            )
+            .collect::<Vec<_>>()
+            .join(";");
+        folded.push_str(&format!("{} {}\n", stack, count));
+    }
+    fs::write(output_path, folded).expect("Unable to write folded stacks");
+    println!(
+        "Wrote folded stacks of {} samples for {:?} to {}",
+        report.data.values().sum::<isize>(),
+        entry_point,
+        output_path
+    );
+}
+
+/// Executes a block of `num_txns` transactions calling the entry point, each sent by a fresh
+/// account, and returns the average time (in microseconds) per transaction. Unlike
+/// [execute_and_time_entry_point], the whole transaction is timed, using the block executor mode
+/// of the executor. The outputs are not applied, so the state is unchanged. Returns [None] if the
+/// entry point needs more than one signer, as it cannot be sent as a regular transaction.
+pub fn execute_and_time_entry_point_block(
+    entry_point: &EntryPoints,
+    package: &Package,
+    publisher_address: &AccountAddress,
+    executor: &mut FakeExecutor,
+    num_txns: u64,
+) -> Option<f64> {
+    if matches!(
+        entry_point.automatic_args(),
+        AutomaticArgs::SignerAndMultiSig
+    ) {
+        return None;
+    }
+
+    let mut rng = StdRng::seed_from_u64(14);
+    let block = (0..num_txns)
+        .map(|_| {
+            let sender = executor.new_account_at(AccountAddress::random());
+            let payload = entry_point.create_payload(
+                package,
+                entry_point.module_name(),
+                Some(&mut rng),
+                Some(publisher_address),
+            );
+            sign_txn(&sender, 0, payload)
+        })
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+    let outputs = executor
+        .execute_block(block)
+        .expect("The VM should not fail to execute the block");
+    let elapsed_micros = start.elapsed().as_secs_f64() * 1_000_000.0;
+    for output in &outputs {
+        check_txn_succeeded(output).unwrap_or_else(|err| panic!("{}, for {:?}", err, entry_point));
+    }
+    Some(elapsed_micros / num_txns as f64)
+}
+
+/// Publishes the package of the entry point from a fresh publisher, and runs its initialization
+/// entry point if it has one. Returns the publisher and the published package, or an error if any
+/// of the transactions failed.
+pub fn publish_entry_point_package(
+    executor: &mut FakeExecutor,
+    entry_point: &EntryPoints,
+) -> Result<(Account, Package), String> {
+    let publisher = executor.new_account_at(AccountAddress::random());
+
+    let mut package_handler =
+        PackageHandler::new(entry_point.pre_built_packages(), entry_point.package_name());
+    let mut rng = StdRng::seed_from_u64(14);
+    let package = package_handler.pick_package(&mut rng, *publisher.address());
+    for payload in package.publish_transaction_payload(&ChainId::test()) {
+        try_execute_txn(executor, &publisher, 0, payload)
+            .map_err(|err| format!("Failed to publish package: {}", err))?;
+    }
+    if let Some(init_entry_point) = entry_point.initialize_entry_point() {
+        try_execute_txn(
+            executor,
+            &publisher,
+            1,
+            init_entry_point.create_payload(
+                &package,
+                init_entry_point.module_name(),
+                Some(&mut rng),
+                Some(publisher.address()),
+            ),
+        )
+        .map_err(|err| format!("Failed to initialize package: {}", err))?;
+    }
+    Ok((publisher, package))
+}
+
+/// Number of calls to time for an entry point, so that slow entry points do not take too long.
+pub fn iterations_for_expected_time(expected_time_micros: f64) -> u64 {
+    if expected_time_micros > 10000.0 {
+        6
+    } else if expected_time_micros > 1000.0 {
+        10
+    } else {
+        100
+    }
+}
+
+/// Times `iterations` calls of the entry point of the package published with
+/// [publish_entry_point_package], with regular gas metering, comparing the result against the
+/// expected time.
+pub fn run_entry_point_benchmark(
+    executor: &mut FakeExecutor,
+    entry_point: &EntryPoints,
+    package: &Package,
+    publisher_address: &AccountAddress,
+    expected_time_micros: f64,
+    iterations: u64,
+) -> BenchmarkResult {
+    let measurement = execute_and_time_entry_point(
+        entry_point,
+        package,
+        publisher_address,
+        executor,
+        iterations,
+        GasMeterType::RegularGasMeter,
+    );
+    BenchmarkResult::new(&measurement, expected_time_micros)
+}
+
+/// Median of the values, which must not be empty.
+pub fn median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+/// Population standard deviation of the values.
+pub fn std_dev(values: &[f64]) -> f64 {
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let variance = values
+        .iter()
+        .map(|value| (value - mean) * (value - mean))
+        .sum::<f64>()
+        / values.len() as f64;
+    variance.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry_points::benchmark_entry_points;
+    use aptos_language_e2e_tests::executor::ExecutorMode;
+
+    #[test]
+    fn test_run_entry_point_benchmark() {
+        let mut executor = FakeExecutor::from_head_genesis();
+        let entry_point = EntryPoints::Nop;
+        let (publisher, package) =
+            publish_entry_point_package(&mut executor, &entry_point).unwrap();
+
+        let expected_time_micros = 100.0;
+        let result = run_entry_point_benchmark(
+            &mut executor,
+            &entry_point,
+            &package,
+            publisher.address(),
+            expected_time_micros,
+            10,
+        );
+        assert!(result.elapsed_micros > 0.0);
+        assert!(result.execution_gas > 0.0);
+        assert_eq!(
+            result.diff,
+            (result.elapsed_micros - expected_time_micros) / expected_time_micros * 100.0
+        );
+
+        // Gas is deterministic, so a second run charges the same
+        let rerun = run_entry_point_benchmark(
+            &mut executor,
+            &entry_point,
+            &package,
+            publisher.address(),
+            expected_time_micros,
+            10,
+        );
+        assert_eq!(rerun.execution_gas, result.execution_gas);
+        assert_eq!(rerun.io_gas, result.io_gas);
+    }
+
+    #[test]
+    fn test_all_entry_points_execute_in_parallel_blocks() {
+        let mut executor =
+            FakeExecutor::from_head_genesis().set_executor_mode(ExecutorMode::ParallelOnly);
+        for (_, entry_point) in benchmark_entry_points() {
+            let (publisher, package) = publish_entry_point_package(&mut executor, &entry_point)
+                .unwrap_or_else(|err| panic!("{}, for {:?}", err, entry_point));
+            // Panics if any transaction of the block fails.
+            execute_and_time_entry_point_block(
+                &entry_point,
+                &package,
+                publisher.address(),
+                &mut executor,
+                2,
+            );
+        }
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Writing the results of a run to a file or a SQLite database.
+
+use clap::ValueEnum;
+use serde_json::Value;
+use std::{
+    fs,
+    io::{BufWriter, Write},
+};
+
+/// Format of the file the results are written to with `--output`.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// All JSON lines printed to stdout, as newline-delimited JSON.
+    #[default]
+    Json,
+    /// One row per entry point, with the main timing and gas columns.
+    Csv,
+}
+
+/// Quotes a CSV field, since entry point names contain commas.
+fn csv_field(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("\"{}\"", s.replace('"', "\"\"")),
+        Value::Null => String::new(),
+        _ => value.to_string(),
+    }
+}
+
+/// Writes the results to the given path. The file is flushed and closed before returning, so
+/// results are not lost if the process exits right after.
+pub fn write_output(path: &str, format: OutputFormat, json_lines: &[Value]) -> std::io::Result<()> {
+    let mut writer = BufWriter::new(fs::File::create(path)?);
+    match format {
+        OutputFormat::Json => {
+            for line in json_lines {
+                writeln!(writer, "{}", line)?;
+            }
+        },
+        OutputFormat::Csv => {
+            let columns = [
+                ("transaction_type", "transaction_type"),
+                ("wall_time_us", "wall_time_us"),
+                ("gps", "gas_units_per_second"),
+                ("execution_gas", "execution_gas_units"),
+                ("io_gas", "io_gas_units"),
+                ("expected_wall_time_us", "expected_wall_time_us"),
+            ];
+            let header = columns.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            writeln!(writer, "{}", header.join(","))?;
+            for line in json_lines
+                .iter()
+                .filter(|line| line["grep"] == "grep_json_aptos_move_vm_perf")
+            {
+                let row = columns
+                    .iter()
+                    .map(|(_, field)| csv_field(&line[*field]))
+                    .collect::<Vec<_>>();
+                writeln!(writer, "{}", row.join(","))?;
+            }
+        },
+    }
+    writer.into_inner()?.sync_all()
+}
+
+/// Result of a single entry point, as recorded in the SQLite database and compared against the
+/// baseline.
+pub struct EntryPointResult {
+    pub entry_point: String,
+    pub wall_time_us: f64,
+    /// Only recorded in the SQLite database.
+    #[cfg_attr(not(feature = "sqlite"), allow(dead_code))]
+    pub gas_units: f64,
+}
+
+/// Returns the commit the benchmark was built from, if it is run from a git checkout.
+#[cfg(feature = "sqlite")]
+fn current_git_sha() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Appends the results of the run to the SQLite database at the given path, creating the
+/// database and the results table if needed. All rows of a run share the same timestamp.
+#[cfg(feature = "sqlite")]
+pub fn record_results_in_sqlite(path: &str, results: &[EntryPointResult]) -> rusqlite::Result<()> {
+    let mut connection = rusqlite::Connection::open(path)?;
+    connection.execute(
+        "CREATE TABLE IF NOT EXISTS results (
+            run_timestamp INTEGER NOT NULL,
+            git_sha TEXT,
+            entry_point TEXT NOT NULL,
+            wall_time_us REAL NOT NULL,
+            gas REAL NOT NULL
+        )",
+        (),
+    )?;
+
+    let run_timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("Current time should be after the epoch")
+        .as_secs() as i64;
+    let git_sha = current_git_sha();
+
+    let transaction = connection.transaction()?;
+    {
+        let mut insert = transaction.prepare(
+            "INSERT INTO results (run_timestamp, git_sha, entry_point, wall_time_us, gas)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+        )?;
+        for result in results {
+            insert.execute(rusqlite::params![
+                run_timestamp,
+                git_sha,
+                result.entry_point,
+                result.wall_time_us,
+                result.gas_units,
+            ])?;
+        }
+    }
+    transaction.commit()
+}
+
+#[cfg(not(feature = "sqlite"))]
+pub fn record_results_in_sqlite(_path: &str, _results: &[EntryPointResult]) -> Result<(), String> {
+    unreachable!("--sqlite is rejected when built without the \"sqlite\" feature")
+}
@@ -0,0 +1,150 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Benchmarks of publishing packages, and of calling into them right after publishing.
+
+use crate::{check_txn_succeeded, sign_txn, CODE_PERF_VERSION};
+use aptos_language_e2e_tests::{account::Account, executor::FakeExecutor};
+use aptos_transaction_generator_lib::{
+    entry_point_trait::EntryPointTrait,
+    publishing::publish_util::{Package, PackageHandler},
+};
+use aptos_transaction_workloads_lib::EntryPoints;
+use aptos_types::{account_address::AccountAddress, chain_id::ChainId};
+use rand::{rngs::StdRng, SeedableRng};
+use serde_json::json;
+use std::time::Instant;
+
+/// Publishes `num_packages` distinct packages back-to-back, each from a fresh publisher,
+/// and reports the total and per-package time and gas.
+pub fn benchmark_publish(executor: &mut FakeExecutor, num_packages: usize) {
+    let entry_point = EntryPoints::Nop;
+    let mut package_handler =
+        PackageHandler::new(entry_point.pre_built_packages(), entry_point.package_name());
+    let mut rng = StdRng::seed_from_u64(14);
+
+    println!("{:>13} {:>13}  package", "walltime(us)", "gas used");
+
+    let mut total_micros = 0.0;
+    let mut total_gas_used = 0;
+    for index in 0..num_packages {
+        let publisher = executor.new_account_at(AccountAddress::random());
+        // A new publisher always gets a new (scrambled) package, so each publish is distinct.
+        let package = package_handler.pick_package(&mut rng, *publisher.address());
+
+        let (_, elapsed_micros, gas_used) = publish_package(executor, &publisher, &package);
+        println!("{:13.1} {:13}  {}", elapsed_micros, gas_used, index);
+
+        total_micros += elapsed_micros;
+        total_gas_used += gas_used;
+    }
+
+    if num_packages > 0 {
+        println!(
+            "Published {} packages in {:.1}us using {} gas, per package: {:.1}us, {:.1} gas",
+            num_packages,
+            total_micros,
+            total_gas_used,
+            total_micros / num_packages as f64,
+            total_gas_used as f64 / num_packages as f64,
+        );
+    }
+}
+
+/// Publishes all transactions of the package from the publisher, starting at sequence number 0.
+/// Returns the next sequence number, and the total time and gas of the publish transactions.
+pub fn publish_package(
+    executor: &mut FakeExecutor,
+    publisher: &Account,
+    package: &Package,
+) -> (u64, f64, u64) {
+    let mut sequence_number = 0;
+    let mut elapsed_micros = 0.0;
+    let mut gas_used = 0;
+    for payload in package.publish_transaction_payload(&ChainId::test()) {
+        // Only the execution is timed, signing is not part of the publish pipeline.
+        let txn = sign_txn(publisher, sequence_number, payload);
+        let start = Instant::now();
+        let txn_output = executor.execute_transaction(txn);
+        elapsed_micros += start.elapsed().as_secs_f64() * 1_000_000.0;
+        executor.apply_write_set(txn_output.write_set());
+        check_txn_succeeded(&txn_output).unwrap_or_else(|err| panic!("{}", err));
+        gas_used += txn_output.gas_used();
+        sequence_number += 1;
+    }
+    (sequence_number, elapsed_micros, gas_used)
+}
+
+/// Publishes `num_packages` distinct packages, each from a fresh publisher, and calls into every
+/// package right after publishing it. The first call has to load the freshly published module,
+/// so this measures the loader and module cache invalidation costs on top of the publish itself.
+/// The publish and call transactions of a package are signed upfront and executed as one block,
+/// and only the block execution is timed.
+pub fn benchmark_publish_and_call(executor: &mut FakeExecutor, num_packages: usize) {
+    let entry_point = EntryPoints::Nop;
+    let mut package_handler =
+        PackageHandler::new(entry_point.pre_built_packages(), entry_point.package_name());
+    let mut rng = StdRng::seed_from_u64(14);
+
+    println!("{:>13} {:>13}  package", "walltime(us)", "gas used");
+
+    let mut total_micros = 0.0;
+    let mut total_gas_used = 0;
+    for index in 0..num_packages {
+        let publisher = executor.new_account_at(AccountAddress::random());
+        let package = package_handler.pick_package(&mut rng, *publisher.address());
+
+        let mut block = package
+            .publish_transaction_payload(&ChainId::test())
+            .into_iter()
+            .enumerate()
+            .map(|(sequence_number, payload)| sign_txn(&publisher, sequence_number as u64, payload))
+            .collect::<Vec<_>>();
+        let payload = entry_point.create_payload(
+            &package,
+            entry_point.module_name(),
+            Some(&mut rng),
+            Some(publisher.address()),
+        );
+        block.push(sign_txn(&publisher, block.len() as u64, payload));
+
+        let start = Instant::now();
+        let outputs = executor
+            .execute_block(block)
+            .expect("The VM should not fail to execute the block");
+        let elapsed_micros = start.elapsed().as_secs_f64() * 1_000_000.0;
+
+        let mut gas_used = 0;
+        for output in &outputs {
+            executor.apply_write_set(output.write_set());
+            check_txn_succeeded(output).unwrap_or_else(|err| panic!("{}", err));
+            gas_used += output.gas_used();
+        }
+        println!("{:13.1} {:13}  {}", elapsed_micros, gas_used, index);
+
+        total_micros += elapsed_micros;
+        total_gas_used += gas_used;
+    }
+
+    if num_packages > 0 {
+        println!(
+            "Published and called {} packages in {:.1}us using {} gas, per package: {:.1}us, {:.1} gas",
+            num_packages,
+            total_micros,
+            total_gas_used,
+            total_micros / num_packages as f64,
+            total_gas_used as f64 / num_packages as f64,
+        );
+        println!(
+            "{}",
+            serde_json::to_string(&json!({
+                "grep": "grep_json_aptos_move_vm_perf",
+                "transaction_type": "PublishAndCall",
+                "wall_time_us": total_micros / num_packages as f64,
+                "gas_used": total_gas_used as f64 / num_packages as f64,
+                "code_perf_version": CODE_PERF_VERSION,
+            }))
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,534 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runs the entry point benchmarks, comparing them against the expected times or a baseline.
+
+use crate::{
+    calibration::{
+        check_gas, get_parsed_calibration_values, load_baseline, print_baseline_comparison,
+        suggested_expected_time, write_baseline,
+    },
+    entry_points::{is_contention_heavy, ONLY_CONTINUOUS},
+    execute_and_time_entry_point, execute_and_time_entry_point_block, iterations_for_expected_time,
+    median,
+    output::{record_results_in_sqlite, write_output, EntryPointResult, OutputFormat},
+    profile_entry_point, publish_entry_point_package, run_entry_point_benchmark, std_dev,
+    CODE_PERF_VERSION,
+};
+use aptos_language_e2e_tests::executor::{FakeExecutor, GasMeterType};
+use aptos_transaction_generator_lib::entry_point_trait::EntryPointTrait;
+use aptos_transaction_workloads_lib::EntryPoints;
+use serde_json::json;
+use std::collections::HashMap;
+
+const ALLOWED_REGRESSION: f64 = 0.15;
+const ALLOWED_IMPROVEMENT: f64 = 0.15;
+const ABSOLUTE_BUFFER_US: f64 = 2.0;
+
+/// Options of a benchmark run of the entry points.
+#[derive(clap::Args, Debug)]
+pub struct RunOptions {
+    #[clap(long, default_value = "false")]
+    pub only_landblocking: bool,
+
+    /// Only run entry points whose name, as printed in the output, contains this substring.
+    #[clap(long)]
+    pub filter: Option<String>,
+
+    /// When a regression is detected, re-run the entry point this many more times, and only
+    /// report a failure if the median of all runs still exceeds the threshold.
+    #[clap(long, default_value_t = 0)]
+    pub rerun_on_regression: usize,
+
+    /// Run each entry point this many times, and compare the median time against the expected
+    /// one. The standard deviation of the samples is reported as well.
+    #[clap(long, default_value_t = 1)]
+    pub samples: usize,
+
+    /// Relative slowdown over the expected time which is reported as a regression.
+    #[clap(long, default_value_t = ALLOWED_REGRESSION)]
+    pub allowed_regression: f64,
+
+    /// Relative speedup over the expected time which is reported as an improvement.
+    #[clap(long, default_value_t = ALLOWED_IMPROVEMENT)]
+    pub allowed_improvement: f64,
+
+    /// Absolute difference (in microseconds) from the expected time which is always tolerated,
+    /// on top of the relative thresholds.
+    #[clap(long, default_value_t = ABSOLUTE_BUFFER_US)]
+    pub absolute_buffer_us: f64,
+
+    /// Stop at the first entry point with a performance regression, print it, and exit with a
+    /// non-zero status, without running the remaining entry points. Improvements do not stop
+    /// the run. Useful when bisecting a regression.
+    #[clap(long, default_value = "false")]
+    pub fail_fast: bool,
+
+    /// Run each entry point a second time, and fail if the execution or io gas charged differs
+    /// between the two runs. Gas must be deterministic for identical inputs.
+    #[clap(long, default_value = "false")]
+    pub check_gas_determinism: bool,
+
+    /// Also run each entry point without gas metering, and report the unmetered time next to the
+    /// metered one, to separate the cost of gas metering from the cost of execution.
+    #[clap(long, default_value = "false")]
+    pub unmetered: bool,
+
+    /// Keep the executor in parallel (Block-STM) mode instead of sequential, and additionally
+    /// time each entry point as a block of transactions through the block executor. Entry points
+    /// which need more than one signer are not timed as a block.
+    #[clap(long, default_value = "false")]
+    pub parallel: bool,
+
+    /// Name of the entry point to profile, as printed in the output (e.g., "EmitEvents { count:
+    /// 1000 }"). After its regular timed run, the entry point is run again under a sampling
+    /// profiler, so the timings of all entry points are unaffected.
+    #[clap(long)]
+    pub flamegraph: Option<String>,
+
+    /// File to write the folded stacks of the profiled entry point to.
+    #[clap(long, default_value = "e2e_benchmark.folded")]
+    pub flamegraph_output: String,
+
+    /// SQLite database to append the results of the run to (created if it does not exist), to
+    /// track results over time. Requires building with the "sqlite" feature.
+    #[clap(long)]
+    pub sqlite: Option<String>,
+
+    /// Write the calibration values to this file, with the expected times of entry points which
+    /// got faster replaced by the suggested ones. Improvements written to the baseline do not
+    /// fail the run.
+    #[clap(long)]
+    pub write_baseline: Option<String>,
+
+    /// JSON results of a previous run (as written with `--output`) to compare against. Entry
+    /// points present in it are expected to take the same time as in the baseline instead of the
+    /// calibrated expected time.
+    #[clap(long)]
+    pub baseline: Option<String>,
+
+    /// Ignore wall times, and only fail if the gas charged by an entry point differs from the gas
+    /// in the baseline. Requires `--baseline`, and entry points missing from it fail the run.
+    #[clap(long, default_value = "false")]
+    pub gas_only: bool,
+
+    /// File to write the results to, in addition to printing them to stdout.
+    #[clap(long)]
+    pub output: Option<String>,
+
+    /// Format of the `--output` file.
+    #[clap(long, value_enum, default_value_t = OutputFormat::Json)]
+    pub format: OutputFormat,
+}
+
+impl RunOptions {
+    /// Returns true if the entry point is run with these options.
+    pub fn is_selected(&self, flow: bool, entry_point_name: &str) -> bool {
+        !(self.only_landblocking && flow == ONLY_CONTINUOUS)
+            && self
+                .filter
+                .as_ref()
+                .map_or(true, |filter| entry_point_name.contains(filter.as_str()))
+    }
+}
+
+/// Divides `numerator` by `denominator`, returning 0 instead of NaN or infinity if the
+/// denominator is 0.
+fn safe_ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator == 0.0 {
+        0.0
+    } else {
+        numerator / denominator
+    }
+}
+
+/// Benchmarks the selected entry points, prints and records the results, and returns the setup
+/// failures, regressions and improvements which fail the run. With `--fail-fast`, returns at the
+/// first regression, without running the remaining entry points.
+pub fn run_entry_points(
+    executor: &mut FakeExecutor,
+    options: &RunOptions,
+    entry_points: Vec<(bool, EntryPoints)>,
+) -> Vec<String> {
+    assert!(options.samples > 0, "--samples must be at least 1");
+    assert!(
+        !options.gas_only || options.baseline.is_some(),
+        "--gas-only requires --baseline with the expected gas"
+    );
+    assert!(
+        options.sqlite.is_none() || cfg!(feature = "sqlite"),
+        "--sqlite requires building with the \"sqlite\" feature"
+    );
+
+    let calibration_values = get_parsed_calibration_values();
+    let baseline = options.baseline.as_deref().map(load_baseline);
+    let execution_mode = if options.parallel {
+        "parallel"
+    } else {
+        "sequential"
+    };
+
+    let mut failures = Vec::new();
+    let mut json_lines = Vec::new();
+    let mut results = Vec::new();
+    let mut suggested_expected_times = HashMap::new();
+    let mut total_execution_gas_units = 0.0;
+    let mut total_io_gas_units = 0.0;
+
+    println!(
+        "Thresholds: allowed regression {:.1}%, allowed improvement {:.1}%, absolute buffer {:.1}us",
+        options.allowed_regression * 100.0,
+        options.allowed_improvement * 100.0,
+        options.absolute_buffer_us,
+    );
+    if options.gas_only {
+        println!("Gas-only mode: wall times are not checked, gas is compared to the baseline");
+    }
+    println!(
+        "{:>13} {:>13} {:>13}{:>13} {:>13} {:>13}  entry point",
+        "walltime(us)", "expected(us)", "dif(- is impr)", "gas/s", "exe gas", "io gas",
+    );
+
+    if let Some(name) = &options.flamegraph {
+        assert!(
+            entry_points
+                .iter()
+                .any(|(_, entry_point)| format!("{:?}", entry_point) == *name),
+            "Unknown entry point to profile: {}",
+            name
+        );
+    }
+
+    for (index, (flow, entry_point)) in entry_points.into_iter().enumerate() {
+        let entry_point_name = format!("{:?}", entry_point);
+        if !options.is_selected(flow, &entry_point_name) {
+            continue;
+        }
+        let cur_calibration = calibration_values
+            .get(&entry_point_name)
+            .expect(&entry_point_name);
+        let expected_time_micros = baseline
+            .as_ref()
+            .and_then(|baseline| baseline.get(&entry_point_name))
+            .map(|expected| expected.wall_time_us)
+            .unwrap_or(cur_calibration.expected_time_micros);
+        // A broken package should not hide the results of the other entry points, so setup
+        // failures are reported at the end of the run.
+        let (publisher, package) = match publish_entry_point_package(executor, &entry_point) {
+            Ok(published) => published,
+            Err(err) => {
+                let failure = format!("Setup failed: {}, for {:?}", err, entry_point);
+                println!("{}", failure);
+                failures.push(failure);
+                continue;
+            },
+        };
+
+        let iterations = iterations_for_expected_time(expected_time_micros);
+        let mut run = || {
+            run_entry_point_benchmark(
+                executor,
+                &entry_point,
+                &package,
+                publisher.address(),
+                expected_time_micros,
+                iterations,
+            )
+        };
+        let result = run();
+        let mut samples = vec![result.elapsed_micros];
+        for _ in 1..options.samples {
+            samples.push(run().elapsed_micros);
+        }
+        let elapsed_std_dev_micros = std_dev(&samples);
+        let elapsed_micros = median(samples);
+        let diff = (elapsed_micros - expected_time_micros) / expected_time_micros * 100.0;
+        let execution_gas_units = result.execution_gas;
+        let io_gas_units = result.io_gas;
+        total_execution_gas_units += execution_gas_units;
+        total_io_gas_units += io_gas_units;
+        let gps = (execution_gas_units + io_gas_units) / (elapsed_micros / 1_000_000.0);
+        println!(
+            "{:13.1} {:13.1} {:12.1}% {:13.0} {:13.2} {:13.2}  {:?}",
+            elapsed_micros,
+            expected_time_micros,
+            diff,
+            gps,
+            execution_gas_units,
+            io_gas_units,
+            entry_point
+        );
+        if options.samples > 1 {
+            println!(
+                "{:13.1}  std dev of {} samples for {:?}",
+                elapsed_std_dev_micros, options.samples, entry_point
+            );
+        }
+
+        let unmetered_micros = options.unmetered.then(|| {
+            let unmetered_micros = execute_and_time_entry_point(
+                &entry_point,
+                &package,
+                publisher.address(),
+                executor,
+                iterations,
+                GasMeterType::UnmeteredGasMeter,
+            )
+            .elapsed_micros_f64();
+            println!(
+                "{:13.1}  unmetered, gas metering overhead {:.1}% for {:?}",
+                unmetered_micros,
+                (elapsed_micros - unmetered_micros) / unmetered_micros * 100.0,
+                entry_point
+            );
+            unmetered_micros
+        });
+
+        let block_micros_per_txn = if options.parallel {
+            execute_and_time_entry_point_block(
+                &entry_point,
+                &package,
+                publisher.address(),
+                executor,
+                iterations,
+            )
+        } else {
+            None
+        };
+        if let Some(block_micros_per_txn) = block_micros_per_txn {
+            println!(
+                "{:13.1}  per txn in a parallel block of {} txns for {:?}{}",
+                block_micros_per_txn,
+                iterations,
+                entry_point,
+                if is_contention_heavy(&entry_point) {
+                    " (contention-heavy)"
+                } else {
+                    ""
+                }
+            );
+        }
+
+        let max_regression = f64::max(
+            expected_time_micros * (1.0 + options.allowed_regression) + options.absolute_buffer_us,
+            expected_time_micros * cur_calibration.max_ratio,
+        );
+        let max_improvement = f64::min(
+            expected_time_micros * (1.0 - options.allowed_improvement) - options.absolute_buffer_us,
+            expected_time_micros * cur_calibration.min_ratio,
+        );
+        let suggested_expected_time_micros =
+            (elapsed_micros < max_improvement).then(|| suggested_expected_time(elapsed_micros));
+
+        results.push(EntryPointResult {
+            entry_point: entry_point_name.clone(),
+            wall_time_us: elapsed_micros,
+            gas_units: execution_gas_units + io_gas_units,
+        });
+        json_lines.push(json!({
+            "grep": "grep_json_aptos_move_vm_perf",
+            "transaction_type": entry_point_name,
+            "package_name": entry_point.package_name(),
+            "module_name": entry_point.module_name(),
+            "wall_time_us": elapsed_micros,
+            "wall_time_std_dev_us": elapsed_std_dev_micros,
+            "num_samples": options.samples,
+            "unmetered_wall_time_us": unmetered_micros,
+            "execution_mode": execution_mode,
+            "block_wall_time_us_per_txn": block_micros_per_txn,
+            "gas_units_per_second": gps,
+            "execution_gas_units": execution_gas_units,
+            "io_gas_units": io_gas_units,
+            "expected_wall_time_us": expected_time_micros,
+            "expected_max_wall_time_us": max_regression,
+            "expected_min_wall_time_us": max_improvement,
+            "suggested_expected_wall_time_us": suggested_expected_time_micros,
+            "code_perf_version": CODE_PERF_VERSION,
+            "test_index": index,
+            "flow": if options.only_landblocking { "LAND_BLOCKING" } else { "CONTINUOUS" },
+        }));
+
+        // A single unlucky run can look like a regression, so if requested, re-run the entry
+        // point and only fail if the median of all runs still regresses.
+        let (checked_micros, checked_diff) = if !options.gas_only
+            && elapsed_micros > max_regression
+            && options.rerun_on_regression > 0
+        {
+            let mut runs = vec![elapsed_micros];
+            for rerun in 1..=options.rerun_on_regression {
+                let rerun_micros = execute_and_time_entry_point(
+                    &entry_point,
+                    &package,
+                    publisher.address(),
+                    executor,
+                    iterations,
+                    GasMeterType::RegularGasMeter,
+                )
+                .elapsed_micros_f64();
+                println!(
+                    "{:13.1}  rerun {}/{} after regression for {:?}",
+                    rerun_micros, rerun, options.rerun_on_regression, entry_point
+                );
+                runs.push(rerun_micros);
+            }
+            let median_micros = median(runs);
+            println!(
+                "{:13.1}  median of {} runs for {:?}",
+                median_micros,
+                options.rerun_on_regression + 1,
+                entry_point
+            );
+            (
+                median_micros,
+                (median_micros - expected_time_micros) / expected_time_micros * 100.0,
+            )
+        } else {
+            (elapsed_micros, diff)
+        };
+
+        if options.check_gas_determinism {
+            let rerun = execute_and_time_entry_point(
+                &entry_point,
+                &package,
+                publisher.address(),
+                executor,
+                iterations,
+                GasMeterType::RegularGasMeter,
+            );
+            if rerun.execution_gas_units() != execution_gas_units
+                || rerun.io_gas_units() != io_gas_units
+            {
+                failures.push(format!(
+                    "Gas nondeterminism detected: {:.2} exe gas and {:.2} io gas, then {:.2} exe gas and {:.2} io gas, for {:?}",
+                    execution_gas_units,
+                    io_gas_units,
+                    rerun.execution_gas_units(),
+                    rerun.io_gas_units(),
+                    entry_point
+                ));
+            }
+        }
+
+        if options.flamegraph.as_ref() == Some(&entry_point_name) {
+            profile_entry_point(
+                &entry_point,
+                &package,
+                publisher.address(),
+                executor,
+                iterations,
+                &options.flamegraph_output,
+            );
+        }
+
+        let (regression, improvement) = if options.gas_only {
+            // Gas cannot be checked without a baseline value, so a missing entry fails the run
+            // instead of silently passing.
+            match baseline
+                .as_ref()
+                .and_then(|baseline| baseline.get(&entry_point_name))
+            {
+                Some(expected) => {
+                    match check_gas(expected, execution_gas_units, io_gas_units, &entry_point) {
+                        Some((true, failure)) => (Some(failure), None),
+                        Some((false, failure)) => (None, Some((failure, None))),
+                        None => (None, None),
+                    }
+                },
+                None => (
+                    Some(format!(
+                        "Missing baseline gas for {:?}, cannot check gas",
+                        entry_point
+                    )),
+                    None,
+                ),
+            }
+        } else if checked_micros > max_regression {
+            let failure = format!(
+                "Performance regression detected: {:.1}us, expected: {:.1}us, limit: {:.1}us, diff: {}%, for {:?}",
+                checked_micros, expected_time_micros, max_regression, checked_diff, entry_point
+            );
+            (Some(failure), None)
+        } else if let Some(suggested) = suggested_expected_time_micros {
+            let improvement = format!(
+                "Performance improvement detected: {:.1}us, expected {:.1}us, limit {:.1}us, diff: {}%, for {:?}. You need to adjust expected time to {:.1}us!",
+                elapsed_micros, expected_time_micros, max_improvement, diff, entry_point, suggested
+            );
+            (None, Some((improvement, Some(suggested))))
+        } else {
+            (None, None)
+        };
+
+        if let Some(failure) = regression {
+            if options.fail_fast {
+                if let Some(path) = &options.output {
+                    write_output(path, options.format, &json_lines).unwrap_or_else(|err| {
+                        panic!("Failed to write results to {}: {}", path, err)
+                    });
+                }
+                println!("Failing fast, remaining entry points were not run.");
+                return vec![failure];
+            }
+            failures.push(failure);
+        } else if let Some((improvement, suggested)) = improvement {
+            match suggested {
+                Some(suggested) if options.write_baseline.is_some() => {
+                    println!("{}", improvement);
+                    suggested_expected_times.insert(entry_point_name, suggested);
+                },
+                _ => failures.push(improvement),
+            }
+        }
+    }
+
+    if let Some(baseline) = &baseline {
+        print_baseline_comparison(baseline, &results);
+    }
+
+    // Split of the gas across all entry points, to see if a change shifted cost between
+    // execution and storage.
+    // Entry points may not touch storage at all, in which case there is no IO gas to compare to.
+    let total_gas_units = total_execution_gas_units + total_io_gas_units;
+    let execution_to_io_ratio = safe_ratio(total_execution_gas_units, total_io_gas_units);
+    println!(
+        "Total gas: {:.2} exe gas ({:.1}%), {:.2} io gas ({:.1}%), exe/io ratio: {:.3}",
+        total_execution_gas_units,
+        safe_ratio(total_execution_gas_units, total_gas_units) * 100.0,
+        total_io_gas_units,
+        safe_ratio(total_io_gas_units, total_gas_units) * 100.0,
+        execution_to_io_ratio,
+    );
+    json_lines.push(json!({
+        "grep": "grep_json_aptos_move_vm_perf_summary",
+        "total_execution_gas_units": total_execution_gas_units,
+        "total_io_gas_units": total_io_gas_units,
+        "execution_to_io_gas_ratio": execution_to_io_ratio,
+        "execution_mode": execution_mode,
+        "code_perf_version": CODE_PERF_VERSION,
+        "flow": if options.only_landblocking { "LAND_BLOCKING" } else { "CONTINUOUS" },
+    }));
+
+    for line in &json_lines {
+        println!("{}", serde_json::to_string(line).unwrap());
+    }
+
+    if let Some(path) = &options.output {
+        write_output(path, options.format, &json_lines)
+            .unwrap_or_else(|err| panic!("Failed to write results to {}: {}", path, err));
+    }
+
+    if let Some(path) = &options.sqlite {
+        record_results_in_sqlite(path, &results)
+            .unwrap_or_else(|err| panic!("Failed to record results in {}: {}", path, err));
+    }
+
+    if let Some(path) = &options.write_baseline {
+        write_baseline(path, &suggested_expected_times);
+        println!(
+            "Wrote baseline with {} adjusted expected times to {}",
+            suggested_expected_times.len(),
+            path
+        );
+    }
+
+    failures
+}
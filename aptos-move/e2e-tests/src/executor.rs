@@ -195,6 +195,8 @@ pub enum ExecFuncTimerDynamicArgs {
     NoArgs,
     DistinctSigners,
     DistinctSignersAndFixed(Vec<AccountAddress>),
+    /// The given number of signers, all distinct within and across iterations.
+    MultipleDistinctSigners(usize),
 }
 
 impl FakeExecutor {
@@ -727,10 +729,36 @@ impl FakeExecutor {
         }
     }
 
+    /// Executes the given block of transactions with the provided module cache config, without
+    /// applying the results to the data store. Module caches are never shared across blocks, so
+    /// the config decides whether execution starts with the Aptos framework prefetched into the
+    /// module cache (warm) or with an empty module cache (cold).
+    pub fn execute_block_with_module_cache_config(
+        &self,
+        txn_block: Vec<SignedTransaction>,
+        module_cache_config: BlockExecutorModuleCacheLocalConfig,
+        sequential: bool,
+    ) -> Result<Vec<TransactionOutput>, VMStatus> {
+        let sig_verified_block = into_signature_verified_block(
+            txn_block
+                .into_iter()
+                .map(Transaction::UserTransaction)
+                .collect(),
+        );
+        self.execute_transaction_block_impl_with_state_view(
+            sig_verified_block,
+            BlockExecutorConfigFromOnchain::on_but_large_for_test(),
+            module_cache_config,
+            sequential,
+            &self.state_store,
+        )
+    }
+
     fn execute_transaction_block_impl_with_state_view(
         &self,
         txn_block: Vec<SignatureVerifiedTransaction>,
         onchain_config: BlockExecutorConfigFromOnchain,
+        module_cache_config: BlockExecutorModuleCacheLocalConfig,
         sequential: bool,
         state_view: &(impl StateView + Sync),
     ) -> Result<Vec<TransactionOutput>, VMStatus> {
@@ -743,7 +771,7 @@ impl FakeExecutor {
                 },
                 allow_fallback: self.allow_block_executor_fallback,
                 discard_failed_blocks: false,
-                module_cache_config: BlockExecutorModuleCacheLocalConfig::default(),
+                module_cache_config,
             },
             onchain: onchain_config,
         };
@@ -802,6 +830,7 @@ impl FakeExecutor {
             Some(self.execute_transaction_block_impl_with_state_view(
                 sig_verified_block.clone(),
                 onchain_config.clone(),
+                BlockExecutorModuleCacheLocalConfig::default(),
                 true,
                 state_view,
             ))
@@ -813,6 +842,7 @@ impl FakeExecutor {
             Some(self.execute_transaction_block_impl_with_state_view(
                 sig_verified_block,
                 onchain_config,
+                BlockExecutorModuleCacheLocalConfig::default(),
                 false,
                 state_view,
             ))
@@ -1087,6 +1117,10 @@ impl FakeExecutor {
             | ExecFuncTimerDynamicArgs::DistinctSignersAndFixed(_) => (0..iterations)
                 .map(|_| *self.new_account_at(AccountAddress::random()).address())
                 .collect::<Vec<_>>(),
+            ExecFuncTimerDynamicArgs::MultipleDistinctSigners(num_signers) => (0..iterations
+                * *num_signers as u64)
+                .map(|_| *self.new_account_at(AccountAddress::random()).address())
+                .collect::<Vec<_>>(),
             _ => vec![],
         };
 
@@ -1136,6 +1170,16 @@ impl FakeExecutor {
                             .unwrap(),
                     );
                 },
+                ExecFuncTimerDynamicArgs::MultipleDistinctSigners(num_signers) => {
+                    for _ in 0..*num_signers {
+                        arg.insert(
+                            0,
+                            MoveValue::Signer(extra_accounts.pop().unwrap())
+                                .simple_serialize()
+                                .unwrap(),
+                        );
+                    }
+                },
                 _ => {},
             }
 
@@ -20,6 +20,7 @@ mod genesis_initializations;
 mod invariant_violation;
 mod loader;
 mod mint;
+mod module_cache;
 mod on_chain_configs;
 mod peer_to_peer;
 mod scripts;
@@ -0,0 +1,80 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_cached_packages::aptos_stdlib;
+use aptos_language_e2e_tests::{
+    account::Account,
+    executor::{assert_outputs_equal, FakeExecutor},
+};
+use aptos_types::{
+    block_executor::config::BlockExecutorModuleCacheLocalConfig, transaction::SignedTransaction,
+};
+use proptest::{collection::vec, prelude::*};
+
+/// Executes the block twice, once with the Aptos framework prefetched into the module cache and
+/// once with an empty module cache, and checks that the outputs are identical.
+fn assert_warm_and_cold_module_cache_outputs_equal(
+    executor: &FakeExecutor,
+    txns: Vec<SignedTransaction>,
+    sequential: bool,
+) {
+    let warm_config = BlockExecutorModuleCacheLocalConfig::default();
+    assert!(warm_config.prefetch_framework_code);
+    let cold_config = BlockExecutorModuleCacheLocalConfig {
+        prefetch_framework_code: false,
+        ..warm_config.clone()
+    };
+
+    let warm_outputs = executor
+        .execute_block_with_module_cache_config(txns.clone(), warm_config, sequential)
+        .unwrap();
+    let cold_outputs = executor
+        .execute_block_with_module_cache_config(txns, cold_config, sequential)
+        .unwrap();
+    assert_outputs_equal(&warm_outputs, "warm", &cold_outputs, "cold");
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(8))]
+    #[test]
+    fn warm_and_cold_module_caches_produce_identical_outputs(
+        transfers in vec((0..4usize, 0..4usize, 1..1_000u64), 1..20),
+        num_new_accounts in 0..3usize,
+        sequential in any::<bool>(),
+    ) {
+        let mut executor = FakeExecutor::from_head_genesis();
+        let accounts = executor.create_accounts(4, 1_000_000_000, 0);
+        let mut sequence_numbers = vec![0; accounts.len()];
+
+        let mut txns = vec![];
+        for (sender, receiver, amount) in transfers {
+            txns.push(
+                accounts[sender]
+                    .transaction()
+                    .payload(aptos_stdlib::aptos_account_transfer(
+                        *accounts[receiver].address(),
+                        amount,
+                    ))
+                    .sequence_number(sequence_numbers[sender])
+                    .sign(),
+            );
+            sequence_numbers[sender] += 1;
+        }
+        // Transfers to non-existent accounts also create them.
+        for _ in 0..num_new_accounts {
+            txns.push(
+                accounts[0]
+                    .transaction()
+                    .payload(aptos_stdlib::aptos_account_transfer(
+                        *Account::new().address(),
+                        1_000,
+                    ))
+                    .sequence_number(sequence_numbers[0])
+                    .sign(),
+            );
+            sequence_numbers[0] += 1;
+        }
+
+        assert_warm_and_cold_module_cache_outputs_equal(&executor, txns, sequential);
+    }
+}
@@ -84,6 +84,8 @@ pub enum EntryPoints {
     NopFeePayer,
     /// Empty (NoOp) function, signed by 2 accounts
     Nop2Signers,
+    /// Empty (NoOp) function, signed by 3 accounts
+    Nop3Signers,
     /// Empty (NoOp) function, signed by 5 accounts
     Nop5Signers,
     /// Increment signer resource - COUNTER_STEP
@@ -126,6 +128,22 @@ pub enum EntryPoints {
     EmitEvents {
         count: u64,
     },
+    /// Emits `count` events, each carrying a payload of `payload_bytes` bytes.
+    EmitEventsSized {
+        count: u64,
+        payload_bytes: u64,
+    },
+    /// Mutably borrows a resource stored at the signer's address `count` times, resolving the
+    /// address from the signer for each borrow.
+    SignerKeyedBorrows {
+        count: u64,
+    },
+    /// Builds a struct nested `depth` levels deep (at most 4), with `width` children per level,
+    /// and serializes it with BCS.
+    NestedStructSerialize {
+        depth: u64,
+        width: u64,
+    },
     MakeOrChangeTable {
         offset: u64,
         count: u64,
@@ -138,6 +156,10 @@ pub enum EntryPoints {
     IncGlobal,
     /// Increment global (publisher) AggregatorV2 resource
     IncGlobalAggV2,
+    /// Increment global (publisher) AggregatorV2 resource `count` times in a single transaction
+    IncGlobalAggV2Repeated {
+        count: u64,
+    },
     /// Modify (try_add(step) or try_sub(step)) AggregatorV2 bounded counter (counter with max_value=100)
     ModifyGlobalBoundedAggV2 {
         step: u64,
@@ -203,6 +225,12 @@ pub enum EntryPoints {
         repeats: u64,
         map_type: MapType,
     },
+    /// Upserts `num_ops_per_txn` random keys into the sender's SmartTable, and then looks up
+    /// `num_ops_per_txn` random keys. Keys are picked from `0..max_key`.
+    SmartTableInsertAndLookup {
+        num_ops_per_txn: usize,
+        max_key: u64,
+    },
     /// Initialize Token V1 NFT collection
     TokenV1InitializeCollection,
     /// Mint an NFT token. Should be called only after InitializeCollection is called
@@ -215,6 +243,10 @@ pub enum EntryPoints {
     // register if not registered already
     CoinInitAndMint,
     FungibleAssetMint,
+    /// Transfer of a legacy coin from the publisher, to compare with [Self::FungibleAssetTransfer].
+    CoinTransfer,
+    /// Transfer of a fungible asset between primary stores, to compare with [Self::CoinTransfer].
+    FungibleAssetTransfer,
 
     TokenV2AmbassadorMint {
         numbered: bool,
@@ -278,6 +310,7 @@ impl EntryPointTrait for EntryPoints {
             | EntryPoints::Nop
             | EntryPoints::NopFeePayer
             | EntryPoints::Nop2Signers
+            | EntryPoints::Nop3Signers
             | EntryPoints::Nop5Signers
             | EntryPoints::Step
             | EntryPoints::GetCounter
@@ -293,11 +326,15 @@ impl EntryPointTrait for EntryPoints {
             | EntryPoints::MakeOrChange { .. }
             | EntryPoints::BytesMakeOrChange { .. }
             | EntryPoints::EmitEvents { .. }
+            | EntryPoints::EmitEventsSized { .. }
+            | EntryPoints::SignerKeyedBorrows { .. }
+            | EntryPoints::NestedStructSerialize { .. }
             | EntryPoints::MakeOrChangeTable { .. }
             | EntryPoints::MakeOrChangeTableRandom { .. }
             | EntryPoints::SimpleScript => "simple",
             EntryPoints::IncGlobal
             | EntryPoints::IncGlobalAggV2
+            | EntryPoints::IncGlobalAggV2Repeated { .. }
             | EntryPoints::ModifyGlobalBoundedAggV2 { .. }
             | EntryPoints::CreateObjects { .. }
             | EntryPoints::CreateObjectsConflict { .. }
@@ -305,6 +342,7 @@ impl EntryPointTrait for EntryPoints {
             | EntryPoints::VectorRemoveInsert { .. }
             | EntryPoints::VectorRangeMove { .. }
             | EntryPoints::MapInsertRemove { .. }
+            | EntryPoints::SmartTableInsertAndLookup { .. }
             | EntryPoints::TokenV1InitializeCollection
             | EntryPoints::TokenV1MintAndStoreNFTParallel
             | EntryPoints::TokenV1MintAndStoreNFTSequential
@@ -318,6 +356,8 @@ impl EntryPointTrait for EntryPoints {
             | EntryPoints::ResourceGroupsSenderMultiChange { .. }
             | EntryPoints::CoinInitAndMint
             | EntryPoints::FungibleAssetMint
+            | EntryPoints::CoinTransfer
+            | EntryPoints::FungibleAssetTransfer
             | EntryPoints::APTTransferWithPermissionedSigner
             | EntryPoints::APTTransferWithMasterSigner => "framework_usecases",
             EntryPoints::OrderBook { .. } => "experimental_usecases",
@@ -343,6 +383,7 @@ impl EntryPointTrait for EntryPoints {
             | EntryPoints::Nop
             | EntryPoints::NopFeePayer
             | EntryPoints::Nop2Signers
+            | EntryPoints::Nop3Signers
             | EntryPoints::Nop5Signers
             | EntryPoints::Step
             | EntryPoints::GetCounter
@@ -358,11 +399,15 @@ impl EntryPointTrait for EntryPoints {
             | EntryPoints::MakeOrChange { .. }
             | EntryPoints::BytesMakeOrChange { .. }
             | EntryPoints::EmitEvents { .. }
+            | EntryPoints::EmitEventsSized { .. }
+            | EntryPoints::SignerKeyedBorrows { .. }
+            | EntryPoints::NestedStructSerialize { .. }
             | EntryPoints::MakeOrChangeTable { .. }
             | EntryPoints::MakeOrChangeTableRandom { .. }
             | EntryPoints::SimpleScript => "simple",
             EntryPoints::IncGlobal
             | EntryPoints::IncGlobalAggV2
+            | EntryPoints::IncGlobalAggV2Repeated { .. }
             | EntryPoints::ModifyGlobalBoundedAggV2 { .. } => "aggregator_example",
             EntryPoints::CreateObjects { .. } | EntryPoints::CreateObjectsConflict { .. } => {
                 "objects"
@@ -371,6 +416,7 @@ impl EntryPointTrait for EntryPoints {
             | EntryPoints::VectorRemoveInsert { .. }
             | EntryPoints::VectorRangeMove { .. } => "vector_example",
             EntryPoints::MapInsertRemove { .. } => "maps_example",
+            EntryPoints::SmartTableInsertAndLookup { .. } => "table_example",
             EntryPoints::TokenV1InitializeCollection
             | EntryPoints::TokenV1MintAndStoreNFTParallel
             | EntryPoints::TokenV1MintAndStoreNFTSequential
@@ -382,8 +428,10 @@ impl EntryPointTrait for EntryPoints {
             | EntryPoints::ResourceGroupsGlobalWriteAndReadTag { .. }
             | EntryPoints::ResourceGroupsSenderWriteTag { .. }
             | EntryPoints::ResourceGroupsSenderMultiChange { .. } => "resource_groups_example",
-            EntryPoints::CoinInitAndMint => "coin_example",
-            EntryPoints::FungibleAssetMint => "fungible_asset_example",
+            EntryPoints::CoinInitAndMint | EntryPoints::CoinTransfer => "coin_example",
+            EntryPoints::FungibleAssetMint | EntryPoints::FungibleAssetTransfer => {
+                "fungible_asset_example"
+            },
             EntryPoints::TokenV2AmbassadorMint { .. } | EntryPoints::TokenV2AmbassadorBurn => {
                 "ambassador"
             },
@@ -428,6 +476,9 @@ impl EntryPointTrait for EntryPoints {
             EntryPoints::Nop2Signers => {
                 get_payload_void(module_id, ident_str!("nop_2_signers").to_owned())
             },
+            EntryPoints::Nop3Signers => {
+                get_payload_void(module_id, ident_str!("nop_3_signers").to_owned())
+            },
             EntryPoints::Nop5Signers => {
                 get_payload_void(module_id, ident_str!("nop_5_signers").to_owned())
             },
@@ -493,6 +544,23 @@ impl EntryPointTrait for EntryPoints {
                     bcs::to_bytes(count).unwrap(),
                 ])
             },
+            EntryPoints::SignerKeyedBorrows { count } => get_payload(
+                module_id,
+                ident_str!("signer_keyed_borrows").to_owned(),
+                vec![bcs::to_bytes(count).unwrap()],
+            ),
+            EntryPoints::NestedStructSerialize { depth, width } => get_payload(
+                module_id,
+                ident_str!("nested_struct_serialize").to_owned(),
+                vec![bcs::to_bytes(depth).unwrap(), bcs::to_bytes(width).unwrap()],
+            ),
+            EntryPoints::EmitEventsSized {
+                count,
+                payload_bytes,
+            } => get_payload(module_id, ident_str!("emit_events_sized").to_owned(), vec![
+                bcs::to_bytes(count).unwrap(),
+                bcs::to_bytes(payload_bytes).unwrap(),
+            ]),
             EntryPoints::MakeOrChangeTable { offset, count } => get_payload(
                 module_id,
                 ident_str!("make_or_change_table").to_owned(),
@@ -525,6 +593,11 @@ impl EntryPointTrait for EntryPoints {
             EntryPoints::IncGlobalAggV2 => {
                 get_payload(module_id, ident_str!("increment_agg_v2").to_owned(), vec![])
             },
+            EntryPoints::IncGlobalAggV2Repeated { count } => get_payload(
+                module_id,
+                ident_str!("increment_agg_v2_repeated").to_owned(),
+                vec![bcs::to_bytes(count).unwrap()],
+            ),
             EntryPoints::ModifyGlobalBoundedAggV2 { step } => {
                 let rng = rng.expect("Must provide RNG");
                 get_payload(
@@ -631,6 +704,26 @@ impl EntryPointTrait for EntryPoints {
 
                 get_payload(module_id, func, args)
             },
+            EntryPoints::SmartTableInsertAndLookup {
+                num_ops_per_txn,
+                max_key,
+            } => {
+                let rng: &mut StdRng = rng.expect("Must provide RNG");
+                let inserts = (0..*num_ops_per_txn)
+                    .map(|_| rng.gen_range(0u64, max_key))
+                    .collect::<Vec<_>>();
+                let lookups = (0..*num_ops_per_txn)
+                    .map(|_| rng.gen_range(0u64, max_key))
+                    .collect::<Vec<_>>();
+                get_payload(
+                    module_id,
+                    ident_str!("test_insert_and_lookup_smart_table").to_owned(),
+                    vec![
+                        bcs::to_bytes(&inserts).unwrap(),
+                        bcs::to_bytes(&lookups).unwrap(),
+                    ],
+                )
+            },
             EntryPoints::TokenV1InitializeCollection => get_payload_void(
                 module_id,
                 ident_str!("token_v1_initialize_collection").to_owned(),
@@ -718,6 +811,11 @@ impl EntryPointTrait for EntryPoints {
                     bcs::to_bytes(&1000u64).unwrap(), // amount
                 ])
             },
+            EntryPoints::CoinTransfer | EntryPoints::FungibleAssetTransfer => {
+                get_payload(module_id, ident_str!("transfer_p").to_owned(), vec![
+                    bcs::to_bytes(&1000u64).unwrap(), // amount
+                ])
+            },
             EntryPoints::TokenV2AmbassadorMint { numbered: true } => {
                 let rng: &mut StdRng = rng.expect("Must provide RNG");
                 get_payload(
@@ -906,12 +1004,14 @@ impl EntryPointTrait for EntryPoints {
             EntryPoints::Republish => MultiSigConfig::Publisher,
             EntryPoints::NopFeePayer => MultiSigConfig::FeePayerPublisher,
             EntryPoints::Nop2Signers => MultiSigConfig::Random(1),
+            EntryPoints::Nop3Signers => MultiSigConfig::Random(2),
             EntryPoints::Nop5Signers => MultiSigConfig::Random(4),
             EntryPoints::ResourceGroupsGlobalWriteTag { .. }
             | EntryPoints::ResourceGroupsGlobalWriteAndReadTag { .. } => MultiSigConfig::Publisher,
-            EntryPoints::CoinInitAndMint | EntryPoints::FungibleAssetMint => {
-                MultiSigConfig::Publisher
-            },
+            EntryPoints::CoinInitAndMint
+            | EntryPoints::FungibleAssetMint
+            | EntryPoints::CoinTransfer
+            | EntryPoints::FungibleAssetTransfer => MultiSigConfig::Publisher,
             EntryPoints::TokenV2AmbassadorMint { .. } | EntryPoints::TokenV2AmbassadorBurn => {
                 MultiSigConfig::Publisher
             },
@@ -940,12 +1040,18 @@ impl EntryPointTrait for EntryPoints {
             | EntryPoints::MakeOrChange { .. }
             | EntryPoints::BytesMakeOrChange { .. }
             | EntryPoints::EmitEvents { .. }
+            | EntryPoints::EmitEventsSized { .. }
+            | EntryPoints::SignerKeyedBorrows { .. }
+            | EntryPoints::NestedStructSerialize { .. }
             | EntryPoints::MakeOrChangeTable { .. }
             | EntryPoints::MakeOrChangeTableRandom { .. }
             | EntryPoints::SimpleScript => AutomaticArgs::Signer,
-            EntryPoints::Nop2Signers | EntryPoints::Nop5Signers => AutomaticArgs::SignerAndMultiSig,
+            EntryPoints::Nop2Signers | EntryPoints::Nop3Signers | EntryPoints::Nop5Signers => {
+                AutomaticArgs::SignerAndMultiSig
+            },
             EntryPoints::IncGlobal
             | EntryPoints::IncGlobalAggV2
+            | EntryPoints::IncGlobalAggV2Repeated { .. }
             | EntryPoints::ModifyGlobalBoundedAggV2 { .. } => AutomaticArgs::None,
             EntryPoints::CreateObjects { .. } | EntryPoints::CreateObjectsConflict { .. } => {
                 AutomaticArgs::Signer
@@ -953,7 +1059,9 @@ impl EntryPointTrait for EntryPoints {
             EntryPoints::VectorTrimAppend { .. }
             | EntryPoints::VectorRemoveInsert { .. }
             | EntryPoints::VectorRangeMove { .. } => AutomaticArgs::None,
-            EntryPoints::MapInsertRemove { .. } => AutomaticArgs::Signer,
+            EntryPoints::MapInsertRemove { .. } | EntryPoints::SmartTableInsertAndLookup { .. } => {
+                AutomaticArgs::Signer
+            },
             EntryPoints::TokenV1InitializeCollection
             | EntryPoints::TokenV1MintAndStoreNFTParallel
             | EntryPoints::TokenV1MintAndStoreNFTSequential
@@ -967,9 +1075,10 @@ impl EntryPointTrait for EntryPoints {
             },
             EntryPoints::ResourceGroupsSenderWriteTag { .. }
             | EntryPoints::ResourceGroupsSenderMultiChange { .. } => AutomaticArgs::Signer,
-            EntryPoints::CoinInitAndMint | EntryPoints::FungibleAssetMint => {
-                AutomaticArgs::SignerAndMultiSig
-            },
+            EntryPoints::CoinInitAndMint
+            | EntryPoints::FungibleAssetMint
+            | EntryPoints::CoinTransfer
+            | EntryPoints::FungibleAssetTransfer => AutomaticArgs::SignerAndMultiSig,
             EntryPoints::TokenV2AmbassadorMint { .. } | EntryPoints::TokenV2AmbassadorBurn => {
                 AutomaticArgs::SignerAndMultiSig
             },
@@ -20,10 +20,10 @@ use std::collections::HashMap;
 pub static PACKAGE_SIMPLE_METADATA: Lazy<Vec<u8>> = Lazy::new(|| {
 	vec![
 		13, 71, 101, 110, 101, 114, 105, 99, 77, 111, 100, 117, 108, 101, 1, 0, 0, 0,
-		0, 0, 0, 0, 0, 64, 57, 51, 57, 69, 67, 66, 51, 52, 55, 65, 56, 56,
-		65, 52, 66, 57, 54, 48, 54, 65, 55, 53, 54, 55, 69, 52, 48, 57, 69, 56,
-		53, 53, 65, 70, 69, 68, 51, 55, 56, 57, 68, 70, 70, 70, 65, 57, 51, 54,
-		68, 66, 53, 55, 67, 48, 50, 57, 52, 69, 50, 65, 50, 56, 54, 54, 132, 1,
+		0, 0, 0, 0, 0, 64, 56, 65, 70, 54, 48, 67, 48, 55, 48, 57, 55, 68,
+		68, 52, 66, 68, 51, 68, 53, 57, 57, 49, 67, 65, 56, 70, 67, 56, 49, 66,
+		69, 52, 48, 67, 54, 65, 65, 52, 68, 57, 48, 68, 57, 56, 57, 56, 69, 56,
+		68, 49, 53, 68, 56, 69, 66, 70, 53, 53, 48, 57, 54, 69, 48, 53, 132, 1,
 		31, 139, 8, 0, 0, 0, 0, 0, 2, 255, 77, 139, 59, 14, 194, 48, 16, 68,
 		251, 61, 133, 229, 30, 135, 11, 80, 208, 64, 197, 9, 162, 20, 43, 123, 64, 86,
 		156, 93, 203, 134, 80, 32, 238, 142, 45, 1, 138, 102, 154, 249, 188, 49, 179, 159,
@@ -46,203 +46,254 @@ pub static PACKAGE_SIMPLE_METADATA: Lazy<Vec<u8>> = Lazy::new(|| {
 #[rustfmt::skip]
 pub static MODULE_SIMPLE_SIMPLE: Lazy<Vec<u8>> = Lazy::new(|| {
 	vec![
-		161, 28, 235, 11, 7, 0, 0, 10, 12, 1, 0, 18, 2, 18, 46, 3, 64, 205,
-		1, 4, 141, 2, 10, 5, 151, 2, 159, 2, 7, 182, 4, 227, 4, 8, 153, 9,
-		64, 6, 217, 9, 105, 16, 194, 10, 93, 10, 159, 11, 51, 12, 210, 11, 185, 15,
-		13, 139, 27, 16, 0, 0, 1, 9, 1, 15, 1, 20, 1, 22, 1, 29, 1, 33,
-		1, 40, 1, 53, 0, 1, 8, 0, 0, 3, 8, 0, 0, 5, 7, 0, 0, 6,
-		8, 0, 1, 8, 4, 1, 6, 1, 0, 10, 6, 0, 0, 11, 8, 0, 2, 14,
-		7, 0, 0, 17, 8, 0, 3, 19, 4, 2, 3, 1, 0, 1, 0, 21, 0, 1,
-		0, 1, 4, 23, 0, 2, 0, 1, 2, 24, 3, 4, 0, 1, 0, 25, 7, 1,
-		0, 1, 0, 26, 9, 1, 0, 1, 0, 27, 11, 12, 0, 1, 0, 28, 14, 1,
-		0, 1, 5, 30, 0, 16, 1, 6, 1, 1, 31, 17, 1, 1, 6, 1, 0, 32,
-		0, 1, 0, 1, 6, 34, 8, 8, 0, 1, 0, 35, 14, 1, 0, 1, 0, 36,
-		0, 1, 0, 1, 0, 37, 0, 1, 0, 1, 0, 38, 14, 1, 0, 1, 0, 39,
-		21, 1, 0, 1, 7, 41, 23, 3, 1, 0, 1, 0, 42, 14, 1, 0, 1, 0,
-		43, 25, 1, 0, 1, 0, 44, 21, 1, 0, 1, 3, 45, 1, 28, 2, 3, 4,
-		1, 3, 46, 29, 30, 2, 3, 2, 1, 0, 47, 32, 1, 0, 1, 0, 48, 32,
-		1, 0, 1, 0, 49, 0, 1, 0, 1, 0, 50, 35, 1, 0, 1, 0, 51, 36,
-		1, 0, 1, 0, 52, 37, 1, 0, 1, 8, 54, 38, 1, 0, 1, 0, 55, 0,
-		1, 0, 1, 0, 56, 14, 1, 0, 1, 0, 57, 41, 1, 0, 1, 0, 58, 0,
-		1, 0, 1, 7, 15, 8, 15, 16, 22, 20, 27, 21, 27, 1, 6, 12, 0, 1,
-		5, 1, 10, 2, 1, 8, 7, 1, 2, 4, 8, 2, 8, 6, 7, 8, 6, 3,
-		2, 7, 10, 2, 6, 10, 2, 1, 3, 2, 6, 12, 10, 2, 2, 7, 10, 2,
-		8, 0, 4, 6, 8, 6, 6, 8, 6, 6, 8, 1, 6, 8, 1, 1, 6, 3,
-		4, 6, 3, 6, 3, 6, 3, 6, 3, 2, 6, 12, 3, 1, 8, 5, 1, 11,
-		4, 1, 9, 0, 2, 7, 11, 4, 1, 9, 0, 9, 0, 2, 5, 7, 8, 3,
-		3, 10, 3, 3, 10, 3, 7, 3, 3, 3, 3, 1, 1, 1, 3, 6, 12, 3,
-		3, 1, 10, 3, 1, 6, 9, 0, 3, 10, 3, 3, 10, 2, 4, 6, 12, 3,
-		8, 7, 10, 2, 6, 7, 8, 6, 7, 3, 7, 8, 7, 7, 10, 2, 8, 2,
-		8, 6, 2, 3, 3, 1, 11, 9, 2, 9, 0, 9, 1, 3, 7, 11, 9, 2,
-		9, 0, 9, 1, 9, 0, 9, 1, 1, 7, 9, 1, 3, 5, 7, 11, 9, 2,
-		3, 3, 7, 3, 2, 6, 12, 5, 7, 8, 2, 8, 6, 3, 3, 10, 2, 7,
-		8, 6, 1, 7, 8, 2, 8, 6, 6, 8, 6, 3, 3, 3, 7, 8, 6, 2,
-		6, 12, 6, 12, 5, 6, 12, 6, 12, 6, 12, 6, 12, 6, 12, 4, 6, 12,
-		6, 12, 10, 2, 10, 10, 2, 3, 6, 12, 10, 2, 10, 10, 2, 3, 7, 8,
-		6, 8, 2, 8, 6, 3, 8, 2, 8, 6, 7, 3, 2, 6, 12, 8, 7, 3,
-		8, 2, 8, 6, 7, 8, 7, 2, 5, 7, 8, 1, 6, 115, 105, 109, 112, 108,
-		101, 12, 66, 121, 116, 101, 82, 101, 115, 111, 117, 114, 99, 101, 4, 100, 97, 116,
-		97, 7, 67, 111, 117, 110, 116, 101, 114, 5, 99, 111, 117, 110, 116, 4, 68, 97,
-		116, 97, 10, 69, 118, 101, 110, 116, 83, 116, 111, 114, 101, 13, 115, 105, 109, 112,
-		108, 101, 95, 101, 118, 101, 110, 116, 115, 11, 69, 118, 101, 110, 116, 72, 97, 110,
-		100, 108, 101, 5, 101, 118, 101, 110, 116, 11, 83, 105, 109, 112, 108, 101, 69, 118,
-		101, 110, 116, 8, 82, 101, 115, 111, 117, 114, 99, 101, 2, 105, 100, 4, 110, 97,
-		109, 101, 6, 83, 116, 114, 105, 110, 103, 6, 115, 116, 114, 105, 110, 103, 8, 101,
-		118, 101, 110, 116, 95, 105, 100, 10, 84, 97, 98, 108, 101, 83, 116, 111, 114, 101,
-		13, 116, 97, 98, 108, 101, 95, 101, 110, 116, 114, 105, 101, 115, 5, 84, 97, 98,
-		108, 101, 5, 116, 97, 98, 108, 101, 6, 100, 111, 117, 98, 108, 101, 6, 115, 105,
-		103, 110, 101, 114, 10, 97, 100, 100, 114, 101, 115, 115, 95, 111, 102, 4, 117, 116,
-		102, 56, 11, 97, 112, 112, 101, 110, 100, 95, 100, 97, 116, 97, 20, 98, 121, 116,
-		101, 115, 95, 109, 97, 107, 101, 95, 111, 114, 95, 99, 104, 97, 110, 103, 101, 14,
-		99, 111, 112, 121, 95, 112, 97, 115, 116, 97, 95, 114, 101, 102, 11, 101, 109, 105,
-		116, 95, 101, 118, 101, 110, 116, 115, 7, 97, 99, 99, 111, 117, 110, 116, 16, 110,
-		101, 119, 95, 101, 118, 101, 110, 116, 95, 104, 97, 110, 100, 108, 101, 10, 101, 109,
-		105, 116, 95, 101, 118, 101, 110, 116, 11, 103, 101, 116, 95, 99, 111, 117, 110, 116,
-		101, 114, 5, 101, 114, 114, 111, 114, 16, 105, 110, 118, 97, 108, 105, 100, 95, 97,
-		114, 103, 117, 109, 101, 110, 116, 21, 103, 101, 116, 95, 102, 114, 111, 109, 95, 114,
-		97, 110, 100, 111, 109, 95, 99, 111, 110, 115, 116, 4, 104, 97, 108, 102, 11, 105,
-		110, 105, 116, 95, 109, 111, 100, 117, 108, 101, 15, 108, 111, 111, 112, 95, 97, 114,
-		105, 116, 104, 109, 101, 116, 105, 99, 8, 108, 111, 111, 112, 95, 98, 99, 115, 3,
-		98, 99, 115, 8, 116, 111, 95, 98, 121, 116, 101, 115, 8, 108, 111, 111, 112, 95,
-		110, 111, 112, 14, 109, 97, 107, 101, 95, 111, 114, 95, 99, 104, 97, 110, 103, 101,
-		20, 109, 97, 107, 101, 95, 111, 114, 95, 99, 104, 97, 110, 103, 101, 95, 116, 97,
-		98, 108, 101, 3, 110, 101, 119, 23, 98, 111, 114, 114, 111, 119, 95, 109, 117, 116,
-		95, 119, 105, 116, 104, 95, 100, 101, 102, 97, 117, 108, 116, 8, 109, 97, 120, 105,
-		109, 105, 122, 101, 8, 109, 105, 110, 105, 109, 105, 122, 101, 3, 110, 111, 112, 13,
-		110, 111, 112, 95, 50, 95, 115, 105, 103, 110, 101, 114, 115, 13, 110, 111, 112, 95,
-		53, 95, 115, 105, 103, 110, 101, 114, 115, 9, 112, 117, 98, 108, 105, 115, 104, 95,
-		112, 4, 99, 111, 100, 101, 19, 112, 117, 98, 108, 105, 115, 104, 95, 112, 97, 99,
-		107, 97, 103, 101, 95, 116, 120, 110, 10, 114, 101, 115, 101, 116, 95, 100, 97, 116,
-		97, 6, 115, 101, 116, 95, 105, 100, 8, 115, 101, 116, 95, 110, 97, 109, 101, 11,
-		115, 116, 101, 112, 95, 115, 105, 103, 110, 101, 114, 0, 0, 0, 0, 0, 0, 0,
+		161, 28, 235, 11, 7, 0, 0, 10, 13, 1, 0, 18, 2, 18, 64, 3, 82, 236,
+		1, 4, 190, 2, 30, 5, 220, 2, 144, 3, 7, 236, 5, 148, 6, 8, 128, 12,
+		64, 6, 192, 12, 105, 16, 169, 13, 131, 1, 10, 172, 14, 82, 11, 254, 14, 2,
+		12, 128, 15, 149, 19, 13, 149, 34, 20, 0, 0, 1, 9, 1, 18, 1, 28, 1,
+		30, 1, 37, 1, 42, 1, 49, 1, 65, 0, 1, 8, 0, 0, 3, 8, 0, 0,
+		5, 7, 0, 0, 6, 8, 0, 1, 8, 4, 1, 6, 1, 0, 10, 6, 0, 0,
+		11, 7, 1, 0, 0, 0, 14, 8, 0, 2, 17, 7, 0, 0, 19, 8, 0, 0,
+		21, 6, 0, 0, 23, 8, 0, 0, 25, 8, 0, 3, 27, 4, 2, 3, 1, 0,
+		1, 0, 29, 0, 1, 0, 1, 4, 31, 0, 2, 0, 1, 2, 32, 3, 4, 0,
+		1, 0, 33, 7, 1, 0, 1, 0, 34, 9, 1, 0, 1, 0, 35, 11, 12, 0,
+		1, 0, 36, 14, 1, 0, 1, 5, 38, 0, 16, 1, 6, 1, 1, 39, 17, 1,
+		1, 6, 1, 0, 40, 19, 1, 0, 1, 0, 41, 0, 1, 0, 1, 6, 43, 8,
+		8, 0, 1, 0, 44, 14, 1, 0, 1, 0, 45, 0, 1, 0, 1, 0, 46, 0,
+		1, 0, 1, 0, 47, 14, 1, 0, 1, 0, 48, 19, 1, 0, 1, 7, 50, 25,
+		3, 1, 0, 1, 0, 51, 14, 1, 0, 1, 0, 52, 27, 1, 0, 1, 0, 53,
+		19, 1, 0, 1, 3, 54, 1, 30, 2, 3, 4, 1, 3, 55, 31, 32, 2, 3,
+		2, 1, 0, 56, 34, 1, 0, 1, 0, 57, 34, 1, 0, 1, 0, 58, 37, 38,
+		1, 7, 1, 0, 59, 19, 1, 0, 1, 0, 60, 0, 1, 0, 1, 0, 61, 46,
+		1, 0, 1, 0, 62, 47, 1, 0, 1, 0, 63, 48, 1, 0, 1, 0, 64, 49,
+		1, 0, 1, 8, 66, 50, 1, 0, 1, 0, 67, 0, 1, 0, 1, 0, 68, 14,
+		1, 0, 1, 0, 69, 53, 1, 0, 1, 0, 70, 14, 1, 0, 1, 0, 71, 0,
+		1, 0, 1, 7, 15, 8, 15, 7, 20, 8, 20, 17, 24, 21, 29, 22, 29, 25,
+		8, 17, 41, 25, 41, 17, 42, 25, 42, 17, 43, 25, 43, 17, 44, 1, 6, 12,
+		0, 1, 5, 1, 10, 2, 1, 8, 8, 1, 2, 4, 8, 2, 8, 7, 7, 8,
+		7, 3, 2, 7, 10, 2, 6, 10, 2, 1, 3, 2, 6, 12, 10, 2, 2, 7,
+		10, 2, 8, 0, 4, 6, 8, 7, 6, 8, 7, 6, 8, 1, 6, 8, 1, 1,
+		6, 3, 4, 6, 3, 6, 3, 6, 3, 6, 3, 2, 6, 12, 3, 1, 8, 5,
+		1, 11, 4, 1, 9, 0, 2, 7, 11, 4, 1, 9, 0, 9, 0, 2, 5, 7,
+		8, 3, 3, 6, 12, 3, 3, 1, 8, 10, 3, 5, 10, 2, 7, 8, 11, 3,
+		10, 3, 3, 10, 3, 7, 3, 3, 3, 3, 1, 1, 1, 1, 10, 3, 1, 6,
+		9, 0, 3, 10, 3, 3, 10, 2, 4, 6, 12, 3, 8, 8, 10, 2, 6, 7,
+		8, 7, 7, 3, 7, 8, 8, 7, 10, 2, 8, 2, 8, 7, 2, 3, 3, 1,
+		11, 13, 2, 9, 0, 9, 1, 3, 7, 11, 13, 2, 9, 0, 9, 1, 9, 0,
+		9, 1, 1, 7, 9, 1, 3, 5, 7, 11, 13, 2, 3, 3, 7, 3, 2, 6,
+		12, 5, 7, 8, 2, 8, 7, 3, 3, 10, 2, 7, 8, 7, 1, 7, 8, 2,
+		8, 7, 6, 8, 7, 3, 3, 3, 7, 8, 7, 2, 9, 0, 3, 1, 11, 6,
+		1, 9, 0, 1, 9, 0, 2, 10, 9, 0, 3, 1, 11, 6, 1, 3, 1, 11,
+		6, 1, 11, 6, 1, 3, 1, 11, 6, 1, 11, 6, 1, 11, 6, 1, 3, 1,
+		11, 6, 1, 11, 6, 1, 11, 6, 1, 11, 6, 1, 3, 5, 1, 11, 6, 1,
+		3, 11, 6, 1, 11, 6, 1, 3, 11, 6, 1, 11, 6, 1, 11, 6, 1, 3,
+		11, 6, 1, 11, 6, 1, 11, 6, 1, 11, 6, 1, 3, 2, 6, 12, 6, 12,
+		3, 6, 12, 6, 12, 6, 12, 5, 6, 12, 6, 12, 6, 12, 6, 12, 6, 12,
+		4, 6, 12, 6, 12, 10, 2, 10, 10, 2, 3, 6, 12, 10, 2, 10, 10, 2,
+		3, 7, 8, 7, 8, 2, 8, 7, 3, 8, 2, 8, 7, 7, 3, 2, 6, 12,
+		8, 8, 3, 8, 2, 8, 7, 7, 8, 8, 1, 7, 8, 9, 2, 5, 7, 8,
+		1, 6, 115, 105, 109, 112, 108, 101, 12, 66, 121, 116, 101, 82, 101, 115, 111, 117,
+		114, 99, 101, 4, 100, 97, 116, 97, 7, 67, 111, 117, 110, 116, 101, 114, 5, 99,
+		111, 117, 110, 116, 4, 68, 97, 116, 97, 10, 69, 118, 101, 110, 116, 83, 116, 111,
+		114, 101, 13, 115, 105, 109, 112, 108, 101, 95, 101, 118, 101, 110, 116, 115, 11, 69,
+		118, 101, 110, 116, 72, 97, 110, 100, 108, 101, 5, 101, 118, 101, 110, 116, 11, 83,
+		105, 109, 112, 108, 101, 69, 118, 101, 110, 116, 10, 78, 101, 115, 116, 101, 100, 78,
+		111, 100, 101, 5, 118, 97, 108, 117, 101, 8, 99, 104, 105, 108, 100, 114, 101, 110,
+		8, 82, 101, 115, 111, 117, 114, 99, 101, 2, 105, 100, 4, 110, 97, 109, 101, 6,
+		83, 116, 114, 105, 110, 103, 6, 115, 116, 114, 105, 110, 103, 13, 83, 105, 103, 110,
+		101, 114, 67, 111, 117, 110, 116, 101, 114, 8, 101, 118, 101, 110, 116, 95, 105, 100,
+		10, 83, 105, 122, 101, 100, 69, 118, 101, 110, 116, 7, 112, 97, 121, 108, 111, 97,
+		100, 15, 83, 105, 122, 101, 100, 69, 118, 101, 110, 116, 83, 116, 111, 114, 101, 12,
+		115, 105, 122, 101, 100, 95, 101, 118, 101, 110, 116, 115, 10, 84, 97, 98, 108, 101,
+		83, 116, 111, 114, 101, 13, 116, 97, 98, 108, 101, 95, 101, 110, 116, 114, 105, 101,
+		115, 5, 84, 97, 98, 108, 101, 5, 116, 97, 98, 108, 101, 6, 100, 111, 117, 98,
+		108, 101, 6, 115, 105, 103, 110, 101, 114, 10, 97, 100, 100, 114, 101, 115, 115, 95,
+		111, 102, 4, 117, 116, 102, 56, 11, 97, 112, 112, 101, 110, 100, 95, 100, 97, 116,
+		97, 20, 98, 121, 116, 101, 115, 95, 109, 97, 107, 101, 95, 111, 114, 95, 99, 104,
+		97, 110, 103, 101, 14, 99, 111, 112, 121, 95, 112, 97, 115, 116, 97, 95, 114, 101,
+		102, 11, 101, 109, 105, 116, 95, 101, 118, 101, 110, 116, 115, 7, 97, 99, 99, 111,
+		117, 110, 116, 16, 110, 101, 119, 95, 101, 118, 101, 110, 116, 95, 104, 97, 110, 100,
+		108, 101, 10, 101, 109, 105, 116, 95, 101, 118, 101, 110, 116, 17, 101, 109, 105, 116,
+		95, 101, 118, 101, 110, 116, 115, 95, 115, 105, 122, 101, 100, 11, 103, 101, 116, 95,
+		99, 111, 117, 110, 116, 101, 114, 5, 101, 114, 114, 111, 114, 16, 105, 110, 118, 97,
+		108, 105, 100, 95, 97, 114, 103, 117, 109, 101, 110, 116, 21, 103, 101, 116, 95, 102,
+		114, 111, 109, 95, 114, 97, 110, 100, 111, 109, 95, 99, 111, 110, 115, 116, 4, 104,
+		97, 108, 102, 11, 105, 110, 105, 116, 95, 109, 111, 100, 117, 108, 101, 15, 108, 111,
+		111, 112, 95, 97, 114, 105, 116, 104, 109, 101, 116, 105, 99, 8, 108, 111, 111, 112,
+		95, 98, 99, 115, 3, 98, 99, 115, 8, 116, 111, 95, 98, 121, 116, 101, 115, 8,
+		108, 111, 111, 112, 95, 110, 111, 112, 14, 109, 97, 107, 101, 95, 111, 114, 95, 99,
+		104, 97, 110, 103, 101, 20, 109, 97, 107, 101, 95, 111, 114, 95, 99, 104, 97, 110,
+		103, 101, 95, 116, 97, 98, 108, 101, 3, 110, 101, 119, 23, 98, 111, 114, 114, 111,
+		119, 95, 109, 117, 116, 95, 119, 105, 116, 104, 95, 100, 101, 102, 97, 117, 108, 116,
+		8, 109, 97, 120, 105, 109, 105, 122, 101, 8, 109, 105, 110, 105, 109, 105, 122, 101,
+		11, 110, 101, 115, 116, 101, 100, 95, 110, 111, 100, 101, 23, 110, 101, 115, 116, 101,
+		100, 95, 115, 116, 114, 117, 99, 116, 95, 115, 101, 114, 105, 97, 108, 105, 122, 101,
+		3, 110, 111, 112, 13, 110, 111, 112, 95, 50, 95, 115, 105, 103, 110, 101, 114, 115,
+		13, 110, 111, 112, 95, 51, 95, 115, 105, 103, 110, 101, 114, 115, 13, 110, 111, 112,
+		95, 53, 95, 115, 105, 103, 110, 101, 114, 115, 9, 112, 117, 98, 108, 105, 115, 104,
+		95, 112, 4, 99, 111, 100, 101, 19, 112, 117, 98, 108, 105, 115, 104, 95, 112, 97,
+		99, 107, 97, 103, 101, 95, 116, 120, 110, 10, 114, 101, 115, 101, 116, 95, 100, 97,
+		116, 97, 6, 115, 101, 116, 95, 105, 100, 8, 115, 101, 116, 95, 110, 97, 109, 101,
+		20, 115, 105, 103, 110, 101, 114, 95, 107, 101, 121, 101, 100, 95, 98, 111, 114, 114,
+		111, 119, 115, 11, 115, 116, 101, 112, 95, 115, 105, 103, 110, 101, 114, 0, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 171, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 205, 0, 0, 0, 0, 0, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 1, 10, 2, 9, 8, 1, 35, 69, 103, 137, 171, 205, 239, 10, 2, 6,
-		5, 104, 101, 108, 108, 111, 10, 3, 81, 10, 0, 0, 0, 0, 0, 0, 0, 0,
-		1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0,
-		0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0,
-		0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0,
-		0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0,
-		20, 99, 111, 109, 112, 105, 108, 97, 116, 105, 111, 110, 95, 109, 101, 116, 97, 100,
-		97, 116, 97, 9, 0, 3, 50, 46, 48, 3, 50, 46, 49, 18, 97, 112, 116, 111,
-		115, 58, 58, 109, 101, 116, 97, 100, 97, 116, 97, 95, 118, 49, 42, 1, 1, 0,
-		0, 0, 0, 0, 0, 0, 29, 69, 67, 79, 85, 78, 84, 69, 82, 95, 82, 69,
-		83, 79, 85, 82, 67, 69, 95, 78, 79, 84, 95, 80, 82, 69, 83, 69, 78, 84,
-		0, 0, 0, 0, 2, 1, 2, 10, 2, 1, 2, 1, 4, 3, 2, 2, 1, 2,
-		10, 2, 3, 2, 1, 7, 11, 4, 1, 8, 5, 6, 2, 3, 12, 3, 13, 8,
-		7, 2, 8, 2, 5, 2, 1, 16, 3, 8, 2, 1, 18, 11, 9, 2, 3, 3,
-		0, 1, 4, 1, 4, 6, 44, 10, 0, 17, 1, 41, 4, 3, 17, 7, 0, 18,
-		2, 12, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 17, 2, 11, 1,
-		18, 4, 12, 2, 11, 0, 11, 2, 45, 4, 2, 11, 0, 17, 1, 42, 4, 12,
-		3, 10, 3, 16, 0, 16, 1, 65, 5, 6, 2, 0, 0, 0, 0, 0, 0, 0,
-		24, 12, 4, 10, 3, 16, 0, 16, 1, 65, 5, 10, 4, 35, 4, 41, 10, 3,
-		15, 0, 15, 1, 49, 255, 68, 5, 5, 28, 11, 3, 1, 5, 16, 3, 0, 0,
-		0, 8, 25, 10, 1, 65, 5, 12, 2, 10, 2, 6, 0, 0, 0, 0, 0, 0,
-		0, 0, 36, 4, 20, 10, 0, 10, 1, 10, 2, 6, 1, 0, 0, 0, 0, 0,
-		0, 0, 23, 66, 5, 20, 68, 5, 11, 2, 6, 1, 0, 0, 0, 0, 0, 0,
-		0, 23, 12, 2, 5, 3, 11, 0, 1, 11, 1, 1, 2, 4, 1, 4, 1, 0,
-		10, 20, 10, 0, 17, 1, 41, 0, 4, 13, 11, 0, 17, 1, 42, 0, 15, 2,
-		12, 2, 11, 1, 11, 2, 21, 2, 11, 1, 18, 0, 12, 3, 11, 0, 11, 3,
-		45, 0, 5, 12, 5, 0, 0, 0, 13, 103, 10, 0, 16, 3, 12, 4, 10, 1,
-		16, 3, 12, 5, 11, 4, 20, 10, 5, 20, 35, 4, 94, 11, 5, 12, 4, 10,
-		2, 16, 4, 12, 5, 10, 5, 20, 10, 1, 16, 3, 20, 35, 4, 67, 11, 0,
-		1, 11, 1, 1, 11, 2, 1, 11, 4, 1, 11, 5, 12, 4, 11, 3, 16, 4,
-		12, 5, 10, 4, 20, 10, 5, 20, 35, 4, 62, 11, 5, 1, 10, 4, 12, 5,
-		10, 4, 10, 5, 33, 4, 57, 11, 5, 1, 11, 4, 12, 7, 11, 7, 2, 11,
-		4, 1, 11, 5, 12, 7, 5, 55, 11, 4, 1, 10, 5, 12, 4, 5, 47, 11,
-		3, 1, 10, 4, 12, 6, 11, 0, 16, 3, 12, 7, 11, 6, 11, 7, 34, 4,
-		89, 11, 4, 1, 11, 5, 1, 11, 2, 16, 4, 12, 4, 11, 1, 16, 3, 12,
-		5, 5, 37, 11, 1, 1, 11, 2, 1, 5, 37, 11, 5, 1, 10, 1, 16, 3,
-		12, 4, 10, 3, 16, 4, 12, 5, 5, 17, 6, 0, 0, 1, 3, 18, 34, 10,
-		0, 17, 1, 12, 2, 10, 2, 41, 3, 3, 31, 10, 0, 11, 0, 56, 0, 18,
-		3, 45, 3, 11, 2, 42, 3, 12, 3, 10, 1, 6, 0, 0, 0, 0, 0, 0,
-		0, 0, 36, 4, 28, 11, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 23, 12,
-		1, 10, 3, 15, 5, 10, 1, 18, 5, 56, 1, 5, 14, 11, 3, 1, 2, 11,
-		0, 1, 5, 11, 9, 1, 4, 1, 1, 2, 15, 11, 0, 17, 1, 12, 1, 10,
-		1, 41, 1, 4, 12, 11, 1, 43, 1, 16, 4, 20, 1, 2, 6, 1, 0, 0,
-		0, 0, 0, 0, 0, 17, 10, 39, 11, 1, 4, 0, 19, 29, 7, 2, 12, 2,
-		11, 0, 1, 14, 2, 65, 8, 12, 3, 10, 3, 6, 0, 0, 0, 0, 0, 0,
-		0, 0, 34, 3, 12, 5, 28, 10, 1, 10, 3, 38, 3, 17, 5, 21, 11, 3,
-		6, 1, 0, 0, 0, 0, 0, 0, 0, 23, 12, 1, 7, 2, 12, 4, 14, 4,
-		11, 1, 66, 8, 20, 1, 2, 12, 1, 4, 1, 4, 6, 44, 10, 0, 17, 1,
-		41, 4, 3, 17, 7, 0, 18, 2, 12, 1, 6, 0, 0, 0, 0, 0, 0, 0,
-		0, 7, 1, 17, 2, 11, 1, 18, 4, 12, 2, 11, 0, 11, 2, 45, 4, 2,
-		11, 0, 17, 1, 42, 4, 12, 3, 10, 3, 16, 0, 16, 1, 65, 5, 6, 2,
-		0, 0, 0, 0, 0, 0, 0, 26, 12, 4, 10, 3, 16, 0, 16, 1, 65, 5,
-		10, 4, 36, 4, 41, 10, 3, 15, 0, 15, 1, 69, 5, 1, 5, 28, 11, 3,
-		1, 5, 16, 13, 0, 0, 0, 1, 5, 11, 0, 6, 0, 0, 0, 0, 0, 0,
-		0, 0, 18, 1, 45, 1, 2, 14, 1, 4, 0, 20, 76, 6, 0, 0, 0, 0,
-		0, 0, 0, 0, 12, 2, 11, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0,
-		12, 3, 10, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 36, 4, 75, 11, 1,
-		6, 1, 0, 0, 0, 0, 0, 0, 0, 23, 12, 1, 11, 2, 6, 1, 0, 0,
-		0, 0, 0, 0, 0, 22, 12, 4, 11, 3, 6, 1, 0, 0, 0, 0, 0, 0,
-		0, 22, 12, 5, 10, 4, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 11, 4,
-		23, 12, 3, 10, 5, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 10, 5, 23,
-		12, 4, 10, 4, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 12, 2, 10, 4,
-		10, 2, 36, 4, 72, 10, 2, 10, 5, 36, 12, 6, 11, 6, 4, 69, 11, 5,
-		10, 3, 36, 12, 7, 11, 7, 4, 66, 10, 3, 11, 4, 36, 12, 8, 11, 8,
-		3, 61, 5, 6, 11, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 12, 1,
-		5, 6, 9, 12, 8, 5, 58, 9, 12, 7, 5, 52, 9, 12, 6, 5, 46, 2,
-		15, 1, 4, 0, 24, 41, 64, 8, 0, 0, 0, 0, 0, 0, 0, 0, 12, 3,
-		11, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 12, 4, 10, 4, 10, 2,
-		35, 4, 18, 13, 3, 10, 4, 68, 8, 11, 4, 6, 1, 0, 0, 0, 0, 0,
-		0, 0, 22, 12, 4, 5, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 12, 2,
-		10, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 36, 4, 40, 14, 3, 56, 2,
-		12, 5, 11, 2, 14, 5, 6, 0, 0, 0, 0, 0, 0, 0, 0, 66, 5, 20,
-		52, 22, 12, 2, 11, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 23, 12, 1,
-		5, 20, 2, 17, 1, 4, 0, 1, 10, 10, 1, 6, 0, 0, 0, 0, 0, 0,
-		0, 0, 36, 4, 9, 11, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 23, 12,
-		1, 5, 0, 2, 18, 1, 4, 1, 4, 26, 40, 10, 0, 17, 1, 41, 4, 4,
-		28, 11, 0, 17, 1, 42, 4, 12, 4, 10, 4, 15, 3, 12, 5, 11, 1, 11,
-		5, 21, 10, 4, 15, 6, 12, 6, 11, 2, 11, 6, 21, 11, 4, 15, 0, 15,
-		1, 12, 7, 11, 3, 11, 7, 21, 2, 11, 3, 18, 2, 12, 8, 11, 1, 11,
-		2, 11, 8, 18, 4, 12, 9, 11, 0, 11, 9, 45, 4, 5, 27, 19, 0, 0,
-		1, 6, 31, 42, 10, 0, 17, 1, 12, 3, 10, 3, 41, 6, 3, 39, 11, 0,
-		56, 3, 18, 6, 45, 6, 11, 3, 42, 6, 15, 7, 12, 4, 10, 2, 6, 0,
-		0, 0, 0, 0, 0, 0, 0, 36, 4, 36, 11, 2, 6, 1, 0, 0, 0, 0,
-		0, 0, 0, 23, 12, 2, 10, 4, 10, 1, 10, 2, 22, 6, 0, 0, 0, 0,
-		0, 0, 0, 0, 56, 4, 12, 5, 10, 5, 20, 6, 1, 0, 0, 0, 0, 0,
-		0, 0, 22, 11, 5, 21, 5, 14, 11, 4, 1, 2, 11, 0, 1, 5, 10, 22,
-		1, 4, 1, 4, 33, 86, 10, 1, 41, 4, 4, 6, 11, 0, 1, 2, 10, 0,
-		17, 1, 41, 4, 4, 11, 5, 23, 7, 0, 18, 2, 12, 2, 6, 0, 0, 0,
-		0, 0, 0, 0, 0, 7, 1, 17, 2, 11, 2, 18, 4, 12, 3, 10, 0, 11,
-		3, 45, 4, 10, 0, 17, 1, 43, 4, 16, 0, 16, 1, 65, 5, 10, 1, 43,
-		4, 16, 0, 16, 1, 65, 5, 36, 4, 75, 11, 0, 17, 1, 43, 4, 16, 0,
-		16, 1, 20, 12, 6, 11, 1, 42, 4, 12, 7, 14, 6, 65, 5, 10, 7, 16,
-		0, 16, 1, 65, 5, 36, 4, 67, 8, 12, 8, 11, 8, 4, 64, 10, 7, 15,
-		0, 15, 1, 14, 6, 17, 3, 5, 46, 11, 7, 1, 2, 10, 7, 16, 0, 16,
-		1, 65, 5, 6, 16, 39, 0, 0, 0, 0, 0, 0, 35, 12, 8, 5, 56, 11,
-		1, 43, 4, 16, 0, 16, 1, 20, 12, 6, 11, 0, 17, 1, 42, 4, 12, 7,
-		5, 46, 23, 1, 4, 1, 4, 34, 76, 10, 1, 41, 4, 4, 6, 11, 0, 1,
-		2, 10, 0, 17, 1, 41, 4, 4, 11, 5, 23, 7, 0, 18, 2, 12, 2, 6,
-		0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 17, 2, 11, 2, 18, 4, 12, 3,
-		10, 0, 11, 3, 45, 4, 10, 0, 17, 1, 43, 4, 10, 1, 43, 4, 12, 4,
-		16, 0, 16, 1, 65, 5, 12, 5, 11, 4, 16, 0, 16, 1, 65, 5, 12, 6,
-		10, 5, 10, 6, 36, 4, 66, 11, 6, 6, 2, 0, 0, 0, 0, 0, 0, 0,
-		26, 12, 7, 11, 0, 17, 1, 42, 4, 12, 8, 10, 8, 16, 0, 16, 1, 65,
-		5, 10, 7, 36, 4, 63, 10, 8, 15, 0, 15, 1, 69, 5, 1, 5, 50, 11,
-		8, 1, 2, 11, 0, 1, 11, 5, 6, 2, 0, 0, 0, 0, 0, 0, 0, 26,
-		12, 7, 11, 1, 42, 4, 12, 8, 5, 50, 24, 1, 4, 0, 1, 3, 11, 0,
-		1, 2, 25, 1, 4, 0, 1, 5, 11, 0, 1, 11, 1, 1, 2, 26, 1, 4,
-		0, 1, 11, 11, 0, 1, 11, 1, 1, 11, 2, 1, 11, 3, 1, 11, 4, 1,
-		2, 27, 1, 4, 0, 1, 7, 11, 1, 11, 0, 1, 11, 2, 11, 3, 17, 28,
-		2, 29, 1, 4, 1, 4, 39, 36, 10, 0, 17, 1, 41, 4, 4, 23, 11, 0,
-		17, 1, 42, 4, 12, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 10, 1, 15,
-		3, 21, 7, 1, 17, 2, 10, 1, 15, 6, 21, 7, 0, 11, 1, 15, 0, 15,
-		1, 21, 2, 7, 0, 18, 2, 12, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0,
-		7, 1, 17, 2, 11, 2, 18, 4, 12, 3, 11, 0, 11, 3, 45, 4, 5, 22,
-		30, 1, 4, 1, 4, 40, 26, 10, 0, 17, 1, 41, 4, 3, 17, 7, 0, 18,
-		2, 12, 2, 11, 1, 7, 1, 17, 2, 11, 2, 18, 4, 12, 3, 11, 0, 11,
-		3, 45, 4, 2, 11, 0, 17, 1, 42, 4, 15, 3, 12, 4, 11, 1, 11, 4,
-		21, 5, 16, 31, 1, 4, 1, 4, 42, 25, 10, 0, 17, 1, 41, 4, 3, 16,
-		7, 0, 18, 2, 12, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 11, 1, 11,
-		2, 18, 4, 12, 3, 11, 0, 11, 3, 45, 4, 2, 11, 0, 17, 1, 42, 4,
-		15, 6, 12, 4, 11, 1, 11, 4, 21, 5, 15, 32, 1, 4, 1, 1, 43, 21,
-		11, 0, 17, 1, 12, 1, 10, 1, 41, 1, 4, 18, 11, 1, 42, 1, 12, 2,
-		10, 2, 16, 4, 20, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 11, 2, 15,
-		4, 21, 2, 6, 1, 0, 0, 0, 0, 0, 0, 0, 17, 10, 39, 4, 2, 2,
-		0, 0, 0, 4, 0, 1, 0, 3, 0, 4, 1, 6, 0, 0,
+		0, 0, 0, 0, 0, 0, 1, 10, 2, 9, 8, 1, 35, 69, 103, 137, 171, 205,
+		239, 10, 2, 6, 5, 104, 101, 108, 108, 111, 10, 3, 81, 10, 0, 0, 0, 0,
+		0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+		0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+		5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 7, 0,
+		0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0,
+		0, 0, 0, 0, 20, 99, 111, 109, 112, 105, 108, 97, 116, 105, 111, 110, 95, 109,
+		101, 116, 97, 100, 97, 116, 97, 9, 0, 3, 50, 46, 48, 3, 50, 46, 49, 18,
+		97, 112, 116, 111, 115, 58, 58, 109, 101, 116, 97, 100, 97, 116, 97, 95, 118, 49,
+		80, 2, 1, 0, 0, 0, 0, 0, 0, 0, 29, 69, 67, 79, 85, 78, 84, 69,
+		82, 95, 82, 69, 83, 79, 85, 82, 67, 69, 95, 78, 79, 84, 95, 80, 82, 69,
+		83, 69, 78, 84, 0, 2, 0, 0, 0, 0, 0, 0, 0, 28, 69, 73, 78, 86,
+		65, 76, 73, 68, 95, 78, 69, 83, 84, 69, 68, 95, 83, 84, 82, 85, 67, 84,
+		95, 68, 69, 80, 84, 72, 0, 0, 0, 0, 2, 1, 2, 10, 2, 1, 2, 1,
+		4, 3, 2, 2, 1, 2, 10, 2, 3, 2, 1, 7, 11, 4, 1, 8, 5, 6,
+		2, 2, 12, 3, 13, 10, 9, 0, 7, 2, 3, 15, 3, 16, 8, 8, 2, 8,
+		2, 9, 2, 1, 4, 3, 5, 2, 1, 20, 3, 10, 2, 2, 20, 3, 22, 10,
+		2, 11, 2, 1, 24, 11, 4, 1, 8, 10, 12, 2, 1, 26, 11, 13, 2, 3,
+		3, 4, 39, 0, 1, 4, 1, 5, 6, 44, 10, 0, 17, 1, 41, 5, 3, 17,
+		7, 0, 18, 2, 12, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 17,
+		2, 11, 1, 18, 5, 12, 2, 11, 0, 11, 2, 45, 5, 2, 11, 0, 17, 1,
+		42, 5, 12, 3, 10, 3, 16, 0, 16, 1, 65, 5, 6, 2, 0, 0, 0, 0,
+		0, 0, 0, 24, 12, 4, 10, 3, 16, 0, 16, 1, 65, 5, 10, 4, 35, 4,
+		41, 10, 3, 15, 0, 15, 1, 49, 255, 68, 5, 5, 28, 11, 3, 1, 5, 16,
+		3, 0, 0, 0, 8, 25, 10, 1, 65, 5, 12, 2, 10, 2, 6, 0, 0, 0,
+		0, 0, 0, 0, 0, 36, 4, 20, 10, 0, 10, 1, 10, 2, 6, 1, 0, 0,
+		0, 0, 0, 0, 0, 23, 66, 5, 20, 68, 5, 11, 2, 6, 1, 0, 0, 0,
+		0, 0, 0, 0, 23, 12, 2, 5, 3, 11, 0, 1, 11, 1, 1, 2, 4, 1,
+		4, 1, 0, 10, 20, 10, 0, 17, 1, 41, 0, 4, 13, 11, 0, 17, 1, 42,
+		0, 15, 2, 12, 2, 11, 1, 11, 2, 21, 2, 11, 1, 18, 0, 12, 3, 11,
+		0, 11, 3, 45, 0, 5, 12, 5, 0, 0, 0, 13, 103, 10, 0, 16, 3, 12,
+		4, 10, 1, 16, 3, 12, 5, 11, 4, 20, 10, 5, 20, 35, 4, 94, 11, 5,
+		12, 4, 10, 2, 16, 4, 12, 5, 10, 5, 20, 10, 1, 16, 3, 20, 35, 4,
+		67, 11, 0, 1, 11, 1, 1, 11, 2, 1, 11, 4, 1, 11, 5, 12, 4, 11,
+		3, 16, 4, 12, 5, 10, 4, 20, 10, 5, 20, 35, 4, 62, 11, 5, 1, 10,
+		4, 12, 5, 10, 4, 10, 5, 33, 4, 57, 11, 5, 1, 11, 4, 12, 7, 11,
+		7, 2, 11, 4, 1, 11, 5, 12, 7, 5, 55, 11, 4, 1, 10, 5, 12, 4,
+		5, 47, 11, 3, 1, 10, 4, 12, 6, 11, 0, 16, 3, 12, 7, 11, 6, 11,
+		7, 34, 4, 89, 11, 4, 1, 11, 5, 1, 11, 2, 16, 4, 12, 4, 11, 1,
+		16, 3, 12, 5, 5, 37, 11, 1, 1, 11, 2, 1, 5, 37, 11, 5, 1, 10,
+		1, 16, 3, 12, 4, 10, 3, 16, 4, 12, 5, 5, 17, 6, 1, 4, 1, 3,
+		18, 34, 10, 0, 17, 1, 12, 2, 10, 2, 41, 3, 3, 31, 10, 0, 11, 0,
+		56, 0, 18, 3, 45, 3, 11, 2, 42, 3, 12, 3, 10, 1, 6, 0, 0, 0,
+		0, 0, 0, 0, 0, 36, 4, 28, 11, 1, 6, 1, 0, 0, 0, 0, 0, 0,
+		0, 23, 12, 1, 10, 3, 15, 5, 10, 1, 18, 7, 56, 1, 5, 14, 11, 3,
+		1, 2, 11, 0, 1, 5, 11, 9, 1, 4, 1, 9, 21, 52, 10, 0, 17, 1,
+		12, 3, 10, 3, 41, 9, 3, 49, 10, 0, 11, 0, 56, 2, 18, 9, 45, 9,
+		64, 5, 0, 0, 0, 0, 0, 0, 0, 0, 12, 4, 10, 2, 6, 0, 0, 0,
+		0, 0, 0, 0, 0, 36, 4, 28, 11, 2, 6, 1, 0, 0, 0, 0, 0, 0,
+		0, 23, 12, 2, 13, 4, 10, 2, 6, 0, 1, 0, 0, 0, 0, 0, 0, 25,
+		51, 68, 5, 5, 13, 11, 3, 42, 9, 12, 5, 10, 1, 6, 0, 0, 0, 0,
+		0, 0, 0, 0, 36, 4, 46, 11, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0,
+		23, 12, 1, 10, 5, 15, 6, 10, 1, 10, 4, 18, 8, 56, 3, 5, 31, 11,
+		5, 1, 2, 11, 0, 1, 5, 11, 10, 1, 4, 1, 1, 2, 15, 11, 0, 17,
+		1, 12, 1, 10, 1, 41, 1, 4, 12, 11, 1, 43, 1, 16, 4, 20, 1, 2,
+		6, 1, 0, 0, 0, 0, 0, 0, 0, 17, 11, 39, 12, 1, 4, 0, 22, 29,
+		7, 2, 12, 2, 11, 0, 1, 14, 2, 65, 8, 12, 3, 10, 3, 6, 0, 0,
+		0, 0, 0, 0, 0, 0, 34, 3, 12, 5, 28, 10, 1, 10, 3, 38, 3, 17,
+		5, 21, 11, 3, 6, 1, 0, 0, 0, 0, 0, 0, 0, 23, 12, 1, 7, 2,
+		12, 4, 14, 4, 11, 1, 66, 8, 20, 1, 2, 13, 1, 4, 1, 5, 6, 44,
+		10, 0, 17, 1, 41, 5, 3, 17, 7, 0, 18, 2, 12, 1, 6, 0, 0, 0,
+		0, 0, 0, 0, 0, 7, 1, 17, 2, 11, 1, 18, 5, 12, 2, 11, 0, 11,
+		2, 45, 5, 2, 11, 0, 17, 1, 42, 5, 12, 3, 10, 3, 16, 0, 16, 1,
+		65, 5, 6, 2, 0, 0, 0, 0, 0, 0, 0, 26, 12, 4, 10, 3, 16, 0,
+		16, 1, 65, 5, 10, 4, 36, 4, 41, 10, 3, 15, 0, 15, 1, 69, 5, 1,
+		5, 28, 11, 3, 1, 5, 16, 14, 0, 0, 0, 1, 5, 11, 0, 6, 0, 0,
+		0, 0, 0, 0, 0, 0, 18, 1, 45, 1, 2, 15, 1, 4, 0, 23, 76, 6,
+		0, 0, 0, 0, 0, 0, 0, 0, 12, 2, 11, 0, 1, 6, 0, 0, 0, 0,
+		0, 0, 0, 0, 12, 3, 10, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 36,
+		4, 75, 11, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 23, 12, 1, 11, 2,
+		6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 12, 4, 11, 3, 6, 1, 0, 0,
+		0, 0, 0, 0, 0, 22, 12, 5, 10, 4, 6, 1, 0, 0, 0, 0, 0, 0,
+		0, 22, 11, 4, 23, 12, 3, 10, 5, 6, 1, 0, 0, 0, 0, 0, 0, 0,
+		22, 10, 5, 23, 12, 4, 10, 4, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22,
+		12, 2, 10, 4, 10, 2, 36, 4, 72, 10, 2, 10, 5, 36, 12, 6, 11, 6,
+		4, 69, 11, 5, 10, 3, 36, 12, 7, 11, 7, 4, 66, 10, 3, 11, 4, 36,
+		12, 8, 11, 8, 3, 61, 5, 6, 11, 1, 6, 1, 0, 0, 0, 0, 0, 0,
+		0, 22, 12, 1, 5, 6, 9, 12, 8, 5, 58, 9, 12, 7, 5, 52, 9, 12,
+		6, 5, 46, 2, 16, 1, 4, 0, 26, 41, 64, 8, 0, 0, 0, 0, 0, 0,
+		0, 0, 12, 3, 11, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 12, 4,
+		10, 4, 10, 2, 35, 4, 18, 13, 3, 10, 4, 68, 8, 11, 4, 6, 1, 0,
+		0, 0, 0, 0, 0, 0, 22, 12, 4, 5, 6, 6, 0, 0, 0, 0, 0, 0,
+		0, 0, 12, 2, 10, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 36, 4, 40,
+		14, 3, 56, 4, 12, 5, 11, 2, 14, 5, 6, 0, 0, 0, 0, 0, 0, 0,
+		0, 66, 5, 20, 52, 22, 12, 2, 11, 1, 6, 1, 0, 0, 0, 0, 0, 0,
+		0, 23, 12, 1, 5, 20, 2, 18, 1, 4, 0, 1, 10, 10, 1, 6, 0, 0,
+		0, 0, 0, 0, 0, 0, 36, 4, 9, 11, 1, 6, 1, 0, 0, 0, 0, 0,
+		0, 0, 23, 12, 1, 5, 0, 2, 19, 1, 4, 1, 5, 28, 40, 10, 0, 17,
+		1, 41, 5, 4, 28, 11, 0, 17, 1, 42, 5, 12, 4, 10, 4, 15, 3, 12,
+		5, 11, 1, 11, 5, 21, 10, 4, 15, 7, 12, 6, 11, 2, 11, 6, 21, 11,
+		4, 15, 0, 15, 1, 12, 7, 11, 3, 11, 7, 21, 2, 11, 3, 18, 2, 12,
+		8, 11, 1, 11, 2, 11, 8, 18, 5, 12, 9, 11, 0, 11, 9, 45, 5, 5,
+		27, 20, 0, 0, 1, 10, 33, 42, 10, 0, 17, 1, 12, 3, 10, 3, 41, 10,
+		3, 39, 11, 0, 56, 5, 18, 10, 45, 10, 11, 3, 42, 10, 15, 8, 12, 4,
+		10, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 36, 4, 36, 11, 2, 6, 1,
+		0, 0, 0, 0, 0, 0, 0, 23, 12, 2, 10, 4, 10, 1, 10, 2, 22, 6,
+		0, 0, 0, 0, 0, 0, 0, 0, 56, 6, 12, 5, 10, 5, 20, 6, 1, 0,
+		0, 0, 0, 0, 0, 0, 22, 11, 5, 21, 5, 14, 11, 4, 1, 2, 11, 0,
+		1, 5, 10, 23, 1, 4, 1, 5, 35, 86, 10, 1, 41, 5, 4, 6, 11, 0,
+		1, 2, 10, 0, 17, 1, 41, 5, 4, 11, 5, 23, 7, 0, 18, 2, 12, 2,
+		6, 0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 17, 2, 11, 2, 18, 5, 12,
+		3, 10, 0, 11, 3, 45, 5, 10, 0, 17, 1, 43, 5, 16, 0, 16, 1, 65,
+		5, 10, 1, 43, 5, 16, 0, 16, 1, 65, 5, 36, 4, 75, 11, 0, 17, 1,
+		43, 5, 16, 0, 16, 1, 20, 12, 6, 11, 1, 42, 5, 12, 7, 14, 6, 65,
+		5, 10, 7, 16, 0, 16, 1, 65, 5, 36, 4, 67, 8, 12, 8, 11, 8, 4,
+		64, 10, 7, 15, 0, 15, 1, 14, 6, 17, 3, 5, 46, 11, 7, 1, 2, 10,
+		7, 16, 0, 16, 1, 65, 5, 6, 16, 39, 0, 0, 0, 0, 0, 0, 35, 12,
+		8, 5, 56, 11, 1, 43, 5, 16, 0, 16, 1, 20, 12, 6, 11, 0, 17, 1,
+		42, 5, 12, 7, 5, 46, 24, 1, 4, 1, 5, 36, 76, 10, 1, 41, 5, 4,
+		6, 11, 0, 1, 2, 10, 0, 17, 1, 41, 5, 4, 11, 5, 23, 7, 0, 18,
+		2, 12, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 17, 2, 11, 2,
+		18, 5, 12, 3, 10, 0, 11, 3, 45, 5, 10, 0, 17, 1, 43, 5, 10, 1,
+		43, 5, 12, 4, 16, 0, 16, 1, 65, 5, 12, 5, 11, 4, 16, 0, 16, 1,
+		65, 5, 12, 6, 10, 5, 10, 6, 36, 4, 66, 11, 6, 6, 2, 0, 0, 0,
+		0, 0, 0, 0, 26, 12, 7, 11, 0, 17, 1, 42, 5, 12, 8, 10, 8, 16,
+		0, 16, 1, 65, 5, 10, 7, 36, 4, 63, 10, 8, 15, 0, 15, 1, 69, 5,
+		1, 5, 50, 11, 8, 1, 2, 11, 0, 1, 11, 5, 6, 2, 0, 0, 0, 0,
+		0, 0, 0, 26, 12, 7, 11, 1, 42, 5, 12, 8, 5, 50, 25, 0, 0, 0,
+		40, 20, 64, 39, 0, 0, 0, 0, 0, 0, 0, 0, 12, 2, 6, 0, 0, 0,
+		0, 0, 0, 0, 0, 12, 3, 10, 3, 10, 1, 35, 4, 16, 13, 2, 10, 0,
+		68, 39, 11, 3, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 12, 3, 5, 4,
+		11, 1, 11, 2, 57, 0, 2, 26, 1, 4, 0, 45, 62, 10, 1, 11, 0, 1,
+		6, 1, 0, 0, 0, 0, 0, 0, 0, 38, 4, 59, 10, 1, 6, 4, 0, 0,
+		0, 0, 0, 0, 0, 37, 12, 3, 11, 3, 4, 56, 10, 2, 10, 2, 56, 7,
+		12, 4, 10, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 33, 4, 24, 14, 4,
+		56, 8, 1, 2, 11, 4, 10, 2, 56, 9, 12, 5, 10, 1, 6, 2, 0, 0,
+		0, 0, 0, 0, 0, 33, 4, 36, 14, 5, 56, 10, 1, 2, 11, 5, 10, 2,
+		56, 11, 12, 6, 11, 1, 6, 3, 0, 0, 0, 0, 0, 0, 0, 33, 4, 48,
+		14, 6, 56, 12, 1, 2, 11, 6, 11, 2, 56, 13, 12, 7, 14, 7, 56, 14,
+		1, 2, 6, 2, 0, 0, 0, 0, 0, 0, 0, 17, 11, 39, 9, 12, 3, 5,
+		10, 27, 1, 4, 0, 1, 3, 11, 0, 1, 2, 28, 1, 4, 0, 1, 5, 11,
+		0, 1, 11, 1, 1, 2, 29, 1, 4, 0, 1, 7, 11, 0, 1, 11, 1, 1,
+		11, 2, 1, 2, 30, 1, 4, 0, 1, 11, 11, 0, 1, 11, 1, 1, 11, 2,
+		1, 11, 3, 1, 11, 4, 1, 2, 31, 1, 4, 0, 1, 7, 11, 1, 11, 0,
+		1, 11, 2, 11, 3, 17, 32, 2, 33, 1, 4, 1, 5, 51, 36, 10, 0, 17,
+		1, 41, 5, 4, 23, 11, 0, 17, 1, 42, 5, 12, 1, 6, 0, 0, 0, 0,
+		0, 0, 0, 0, 10, 1, 15, 3, 21, 7, 1, 17, 2, 10, 1, 15, 7, 21,
+		7, 0, 11, 1, 15, 0, 15, 1, 21, 2, 7, 0, 18, 2, 12, 2, 6, 0,
+		0, 0, 0, 0, 0, 0, 0, 7, 1, 17, 2, 11, 2, 18, 5, 12, 3, 11,
+		0, 11, 3, 45, 5, 5, 22, 34, 1, 4, 1, 5, 52, 26, 10, 0, 17, 1,
+		41, 5, 3, 17, 7, 0, 18, 2, 12, 2, 11, 1, 7, 1, 17, 2, 11, 2,
+		18, 5, 12, 3, 11, 0, 11, 3, 45, 5, 2, 11, 0, 17, 1, 42, 5, 15,
+		3, 12, 4, 11, 1, 11, 4, 21, 5, 16, 35, 1, 4, 1, 5, 54, 25, 10,
+		0, 17, 1, 41, 5, 3, 16, 7, 0, 18, 2, 12, 2, 6, 0, 0, 0, 0,
+		0, 0, 0, 0, 11, 1, 11, 2, 18, 5, 12, 3, 11, 0, 11, 3, 45, 5,
+		2, 11, 0, 17, 1, 42, 5, 15, 7, 12, 4, 11, 1, 11, 4, 21, 5, 15,
+		36, 1, 4, 1, 6, 55, 33, 10, 0, 17, 1, 41, 6, 4, 5, 5, 9, 10,
+		0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 18, 6, 45, 6, 10, 1, 6, 0,
+		0, 0, 0, 0, 0, 0, 0, 36, 4, 30, 11, 1, 6, 1, 0, 0, 0, 0,
+		0, 0, 0, 23, 12, 1, 10, 0, 17, 1, 42, 6, 12, 2, 10, 2, 16, 9,
+		20, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 11, 2, 15, 9, 21, 5, 9,
+		11, 0, 1, 2, 37, 1, 4, 1, 1, 56, 21, 11, 0, 17, 1, 12, 1, 10,
+		1, 41, 1, 4, 18, 11, 1, 42, 1, 12, 2, 10, 2, 16, 4, 20, 6, 1,
+		0, 0, 0, 0, 0, 0, 0, 22, 11, 2, 15, 4, 21, 2, 6, 1, 0, 0,
+		0, 0, 0, 0, 0, 17, 11, 39, 5, 2, 2, 0, 0, 0, 5, 0, 1, 0,
+		3, 0, 9, 0, 5, 1, 10, 0, 6, 0, 0,
 	]
 });
 
@@ -276,11 +327,11 @@ pub static MODULES_SIMPLE: Lazy<Vec<Vec<u8>>> = Lazy::new(|| { vec![
 pub static PACKAGE_FRAMEWORK_USECASES_METADATA: Lazy<Vec<u8>> = Lazy::new(|| {
 	vec![
 		17, 70, 114, 97, 109, 101, 119, 111, 114, 107, 85, 115, 101, 99, 97, 115, 101, 115,
-		1, 0, 0, 0, 0, 0, 0, 0, 0, 64, 57, 68, 67, 68, 67, 65, 52, 48,
-		51, 55, 68, 53, 53, 56, 70, 54, 52, 69, 68, 48, 56, 69, 50, 53, 57, 66,
-		69, 66, 66, 70, 52, 68, 69, 50, 51, 56, 66, 49, 54, 56, 52, 49, 66, 54,
-		69, 50, 56, 48, 57, 55, 70, 65, 70, 53, 48, 65, 67, 57, 54, 56, 68, 51,
-		67, 55, 215, 1, 31, 139, 8, 0, 0, 0, 0, 0, 2, 255, 165, 144, 187, 142,
+		1, 0, 0, 0, 0, 0, 0, 0, 0, 64, 54, 69, 57, 70, 52, 67, 65, 50,
+		55, 55, 67, 68, 65, 54, 55, 69, 68, 49, 48, 55, 51, 51, 54, 48, 51, 67,
+		54, 66, 49, 67, 65, 69, 67, 54, 67, 52, 49, 49, 68, 53, 65, 51, 67, 53,
+		56, 70, 54, 52, 51, 52, 70, 53, 51, 67, 55, 54, 50, 55, 66, 57, 68, 55,
+		70, 53, 215, 1, 31, 139, 8, 0, 0, 0, 0, 0, 2, 255, 165, 144, 187, 142,
 		194, 64, 12, 69, 251, 249, 10, 107, 182, 38, 236, 15, 108, 193, 238, 138, 150, 6,
 		170, 8, 33, 51, 49, 33, 100, 176, 163, 241, 240, 144, 16, 255, 78, 44, 30, 130,
 		22, 100, 23, 215, 246, 189, 167, 112, 217, 97, 104, 177, 166, 185, 99, 220, 18, 252,
@@ -292,7 +343,7 @@ pub static PACKAGE_FRAMEWORK_USECASES_METADATA: Lazy<Vec<u8>> = Lazy::new(|| {
 		134, 107, 160, 99, 88, 35, 215, 38, 101, 5, 65, 88, 51, 114, 6, 172, 170, 68,
 		170, 96, 20, 5, 236, 5, 197, 88, 184, 242, 182, 183, 231, 117, 187, 101, 108, 116,
 		77, 105, 113, 55, 219, 163, 143, 163, 223, 191, 127, 239, 46, 112, 10, 188, 112, 161,
-		1, 0, 0, 9, 18, 97, 103, 103, 114, 101, 103, 97, 116, 111, 114, 95, 101, 120,
+		1, 0, 0, 10, 18, 97, 103, 103, 114, 101, 103, 97, 116, 111, 114, 95, 101, 120,
 		97, 109, 112, 108, 101, 0, 0, 0, 12, 99, 111, 105, 110, 95, 101, 120, 97, 109,
 		112, 108, 101, 0, 0, 0, 22, 102, 117, 110, 103, 105, 98, 108, 101, 95, 97, 115,
 		115, 101, 116, 95, 101, 120, 97, 109, 112, 108, 101, 0, 0, 0, 12, 109, 97, 112,
@@ -300,124 +351,140 @@ pub static PACKAGE_FRAMEWORK_USECASES_METADATA: Lazy<Vec<u8>> = Lazy::new(|| {
 		116, 115, 0, 0, 0, 21, 112, 101, 114, 109, 105, 115, 115, 105, 111, 110, 101, 100,
 		95, 116, 114, 97, 110, 115, 102, 101, 114, 0, 0, 0, 23, 114, 101, 115, 111, 117,
 		114, 99, 101, 95, 103, 114, 111, 117, 112, 115, 95, 101, 120, 97, 109, 112, 108, 101,
-		0, 0, 0, 8, 116, 111, 107, 101, 110, 95, 118, 49, 0, 0, 0, 14, 118, 101,
-		99, 116, 111, 114, 95, 101, 120, 97, 109, 112, 108, 101, 0, 0, 0, 5, 0, 0,
+		0, 0, 0, 13, 116, 97, 98, 108, 101, 95, 101, 120, 97, 109, 112, 108, 101, 0,
+		0, 0, 8, 116, 111, 107, 101, 110, 95, 118, 49, 0, 0, 0, 14, 118, 101, 99,
+		116, 111, 114, 95, 101, 120, 97, 109, 112, 108, 101, 0, 0, 0, 5, 0, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 14, 65, 112, 116, 111, 115,
-		70, 114, 97, 109, 101, 119, 111, 114, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 14, 65, 112, 116, 111, 115, 70,
+		114, 97, 109, 101, 119, 111, 114, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 1, 11, 65, 112, 116, 111, 115, 83, 116, 100, 108, 105, 98, 0,
+		0, 0, 0, 1, 11, 65, 112, 116, 111, 115, 83, 116, 100, 108, 105, 98, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 10, 77, 111, 118, 101,
-		83, 116, 100, 108, 105, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 10, 77, 111, 118, 101, 83,
+		116, 100, 108, 105, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 3, 10, 65, 112, 116, 111, 115, 84, 111, 107, 101, 110, 0, 0, 0, 0, 0,
+		3, 10, 65, 112, 116, 111, 115, 84, 111, 107, 101, 110, 0, 0, 0, 0, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 4, 17, 65, 112, 116, 111, 115, 84, 111, 107,
-		101, 110, 79, 98, 106, 101, 99, 116, 115, 0,
+		0, 0, 0, 0, 0, 0, 0, 4, 17, 65, 112, 116, 111, 115, 84, 111, 107, 101,
+		110, 79, 98, 106, 101, 99, 116, 115, 0,
 	]
 });
 
 #[rustfmt::skip]
 pub static MODULE_FRAMEWORK_USECASES_AGGREGATOR_EXAMPLE: Lazy<Vec<u8>> = Lazy::new(|| {
 	vec![
-		161, 28, 235, 11, 7, 0, 0, 10, 12, 1, 0, 8, 2, 8, 18, 3, 26, 64,
-		4, 90, 8, 5, 98, 39, 7, 137, 1, 248, 1, 8, 129, 3, 64, 6, 193, 3,
-		34, 16, 227, 3, 238, 1, 10, 209, 5, 21, 12, 230, 5, 232, 1, 13, 206, 7,
-		6, 0, 0, 1, 4, 1, 8, 1, 13, 0, 1, 8, 0, 1, 3, 6, 1, 0,
+		161, 28, 235, 11, 7, 0, 0, 10, 12, 1, 0, 8, 2, 8, 18, 3, 26, 70,
+		4, 96, 8, 5, 104, 44, 7, 148, 1, 146, 2, 8, 166, 3, 64, 6, 230, 3,
+		34, 16, 136, 4, 238, 1, 10, 246, 5, 21, 12, 139, 6, 203, 2, 13, 214, 8,
+		6, 0, 0, 1, 4, 1, 8, 1, 14, 0, 1, 8, 0, 1, 3, 6, 1, 0,
 		0, 0, 5, 8, 0, 0, 6, 8, 0, 0, 7, 0, 0, 0, 1, 2, 9, 1,
 		1, 0, 1, 0, 10, 0, 0, 0, 1, 1, 11, 3, 4, 1, 0, 1, 0, 12,
-		5, 0, 0, 1, 3, 14, 5, 6, 0, 1, 1, 15, 0, 7, 1, 3, 1, 1,
-		16, 8, 7, 1, 3, 1, 0, 17, 9, 0, 0, 1, 1, 18, 3, 4, 1, 0,
-		1, 3, 1, 6, 1, 7, 1, 9, 1, 0, 1, 3, 1, 7, 8, 2, 2, 7,
-		11, 1, 1, 9, 0, 9, 0, 1, 1, 1, 6, 12, 1, 5, 1, 11, 1, 1,
-		9, 0, 1, 9, 0, 2, 1, 3, 1, 7, 8, 0, 18, 97, 103, 103, 114, 101,
-		103, 97, 116, 111, 114, 95, 101, 120, 97, 109, 112, 108, 101, 12, 66, 111, 117, 110,
-		100, 101, 100, 65, 103, 103, 86, 50, 5, 99, 111, 117, 110, 116, 10, 65, 103, 103,
-		114, 101, 103, 97, 116, 111, 114, 13, 97, 103, 103, 114, 101, 103, 97, 116, 111, 114,
-		95, 118, 50, 7, 67, 111, 117, 110, 116, 101, 114, 12, 67, 111, 117, 110, 116, 101,
-		114, 65, 103, 103, 86, 50, 9, 105, 110, 99, 114, 101, 109, 101, 110, 116, 5, 101,
-		114, 114, 111, 114, 16, 105, 110, 118, 97, 108, 105, 100, 95, 97, 114, 103, 117, 109,
-		101, 110, 116, 16, 105, 110, 99, 114, 101, 109, 101, 110, 116, 95, 97, 103, 103, 95,
-		118, 50, 7, 116, 114, 121, 95, 97, 100, 100, 11, 105, 110, 105, 116, 95, 109, 111,
-		100, 117, 108, 101, 6, 115, 105, 103, 110, 101, 114, 10, 97, 100, 100, 114, 101, 115,
-		115, 95, 111, 102, 27, 99, 114, 101, 97, 116, 101, 95, 117, 110, 98, 111, 117, 110,
-		100, 101, 100, 95, 97, 103, 103, 114, 101, 103, 97, 116, 111, 114, 17, 99, 114, 101,
-		97, 116, 101, 95, 97, 103, 103, 114, 101, 103, 97, 116, 111, 114, 21, 109, 111, 100,
-		105, 102, 121, 95, 98, 111, 117, 110, 100, 101, 100, 95, 97, 103, 103, 95, 118, 50,
-		7, 116, 114, 121, 95, 115, 117, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		1, 0, 0, 1, 0, 13, 6, 0, 0, 1, 3, 15, 6, 7, 0, 1, 1, 16,
+		0, 8, 1, 3, 1, 1, 17, 9, 8, 1, 3, 1, 0, 18, 10, 0, 0, 1,
+		1, 19, 3, 4, 1, 0, 1, 3, 1, 7, 1, 8, 1, 10, 1, 0, 1, 3,
+		1, 7, 8, 2, 2, 7, 11, 1, 1, 9, 0, 9, 0, 1, 1, 2, 7, 8,
+		3, 3, 1, 6, 12, 1, 5, 1, 11, 1, 1, 9, 0, 1, 9, 0, 2, 1,
+		3, 1, 7, 8, 0, 18, 97, 103, 103, 114, 101, 103, 97, 116, 111, 114, 95, 101,
+		120, 97, 109, 112, 108, 101, 12, 66, 111, 117, 110, 100, 101, 100, 65, 103, 103, 86,
+		50, 5, 99, 111, 117, 110, 116, 10, 65, 103, 103, 114, 101, 103, 97, 116, 111, 114,
+		13, 97, 103, 103, 114, 101, 103, 97, 116, 111, 114, 95, 118, 50, 7, 67, 111, 117,
+		110, 116, 101, 114, 12, 67, 111, 117, 110, 116, 101, 114, 65, 103, 103, 86, 50, 9,
+		105, 110, 99, 114, 101, 109, 101, 110, 116, 5, 101, 114, 114, 111, 114, 16, 105, 110,
+		118, 97, 108, 105, 100, 95, 97, 114, 103, 117, 109, 101, 110, 116, 16, 105, 110, 99,
+		114, 101, 109, 101, 110, 116, 95, 97, 103, 103, 95, 118, 50, 7, 116, 114, 121, 95,
+		97, 100, 100, 25, 105, 110, 99, 114, 101, 109, 101, 110, 116, 95, 97, 103, 103, 95,
+		118, 50, 95, 114, 101, 112, 101, 97, 116, 101, 100, 11, 105, 110, 105, 116, 95, 109,
+		111, 100, 117, 108, 101, 6, 115, 105, 103, 110, 101, 114, 10, 97, 100, 100, 114, 101,
+		115, 115, 95, 111, 102, 27, 99, 114, 101, 97, 116, 101, 95, 117, 110, 98, 111, 117,
+		110, 100, 101, 100, 95, 97, 103, 103, 114, 101, 103, 97, 116, 111, 114, 17, 99, 114,
+		101, 97, 116, 101, 95, 97, 103, 103, 114, 101, 103, 97, 116, 111, 114, 21, 109, 111,
+		100, 105, 102, 121, 95, 98, 111, 117, 110, 100, 101, 100, 95, 97, 103, 103, 95, 118,
+		50, 7, 116, 114, 121, 95, 115, 117, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 171, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
-		5, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 205, 20, 99,
-		111, 109, 112, 105, 108, 97, 116, 105, 111, 110, 95, 109, 101, 116, 97, 100, 97, 116,
-		97, 9, 0, 3, 50, 46, 48, 3, 50, 46, 49, 18, 97, 112, 116, 111, 115, 58,
-		58, 109, 101, 116, 97, 100, 97, 116, 97, 95, 118, 49, 186, 1, 5, 1, 0, 0,
-		0, 0, 0, 0, 0, 29, 69, 67, 79, 85, 78, 84, 69, 82, 95, 82, 69, 83,
-		79, 85, 82, 67, 69, 95, 78, 79, 84, 95, 80, 82, 69, 83, 69, 78, 84, 0,
-		2, 0, 0, 0, 0, 0, 0, 0, 33, 69, 67, 79, 85, 78, 84, 69, 82, 95,
-		65, 71, 71, 95, 82, 69, 83, 79, 85, 82, 67, 69, 95, 78, 79, 84, 95, 80,
-		82, 69, 83, 69, 78, 84, 0, 3, 0, 0, 0, 0, 0, 0, 0, 33, 69, 66,
-		79, 85, 78, 68, 69, 68, 95, 65, 71, 71, 95, 82, 69, 83, 79, 85, 82, 67,
-		69, 95, 78, 79, 84, 95, 80, 82, 69, 83, 69, 78, 84, 0, 4, 0, 0, 0,
-		0, 0, 0, 0, 23, 69, 67, 79, 85, 78, 84, 69, 82, 95, 73, 78, 67, 82,
-		69, 77, 69, 78, 84, 95, 70, 65, 73, 76, 0, 5, 0, 0, 0, 0, 0, 0,
-		0, 15, 69, 78, 79, 84, 95, 65, 85, 84, 72, 79, 82, 73, 90, 69, 68, 0,
-		0, 0, 0, 2, 1, 2, 11, 1, 1, 3, 2, 2, 1, 2, 3, 3, 2, 1,
-		2, 11, 1, 1, 3, 0, 1, 4, 1, 1, 2, 18, 7, 0, 41, 1, 4, 15,
-		7, 0, 42, 1, 12, 0, 10, 0, 16, 0, 20, 6, 1, 0, 0, 0, 0, 0,
-		0, 0, 22, 11, 0, 15, 0, 21, 2, 6, 1, 0, 0, 0, 0, 0, 0, 0,
-		17, 1, 39, 2, 1, 4, 1, 2, 0, 15, 7, 0, 41, 2, 4, 12, 7, 0,
-		42, 2, 15, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 56, 0, 4, 10, 2,
-		6, 4, 0, 0, 0, 0, 0, 0, 0, 39, 6, 2, 0, 0, 0, 0, 0, 0,
-		0, 17, 1, 39, 4, 0, 0, 0, 0, 23, 10, 0, 17, 5, 7, 0, 33, 4,
-		19, 10, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 18, 1, 45, 1, 10, 0,
-		56, 1, 18, 2, 45, 2, 11, 0, 6, 100, 0, 0, 0, 0, 0, 0, 0, 56,
-		2, 18, 0, 45, 0, 2, 11, 0, 1, 6, 5, 0, 0, 0, 0, 0, 0, 0,
-		39, 8, 1, 4, 1, 0, 10, 23, 7, 0, 41, 0, 4, 20, 7, 0, 42, 0,
-		12, 2, 11, 0, 4, 14, 11, 2, 15, 2, 11, 1, 56, 0, 1, 2, 11, 2,
-		15, 2, 11, 1, 56, 3, 1, 5, 13, 6, 3, 0, 0, 0, 0, 0, 0, 0,
-		17, 1, 39, 1, 0, 2, 0, 0, 0, 0,
+		0, 0, 0, 171, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		1, 5, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 205, 20,
+		99, 111, 109, 112, 105, 108, 97, 116, 105, 111, 110, 95, 109, 101, 116, 97, 100, 97,
+		116, 97, 9, 0, 3, 50, 46, 48, 3, 50, 46, 49, 18, 97, 112, 116, 111, 115,
+		58, 58, 109, 101, 116, 97, 100, 97, 116, 97, 95, 118, 49, 186, 1, 5, 1, 0,
+		0, 0, 0, 0, 0, 0, 29, 69, 67, 79, 85, 78, 84, 69, 82, 95, 82, 69,
+		83, 79, 85, 82, 67, 69, 95, 78, 79, 84, 95, 80, 82, 69, 83, 69, 78, 84,
+		0, 2, 0, 0, 0, 0, 0, 0, 0, 33, 69, 67, 79, 85, 78, 84, 69, 82,
+		95, 65, 71, 71, 95, 82, 69, 83, 79, 85, 82, 67, 69, 95, 78, 79, 84, 95,
+		80, 82, 69, 83, 69, 78, 84, 0, 3, 0, 0, 0, 0, 0, 0, 0, 33, 69,
+		66, 79, 85, 78, 68, 69, 68, 95, 65, 71, 71, 95, 82, 69, 83, 79, 85, 82,
+		67, 69, 95, 78, 79, 84, 95, 80, 82, 69, 83, 69, 78, 84, 0, 4, 0, 0,
+		0, 0, 0, 0, 0, 23, 69, 67, 79, 85, 78, 84, 69, 82, 95, 73, 78, 67,
+		82, 69, 77, 69, 78, 84, 95, 70, 65, 73, 76, 0, 5, 0, 0, 0, 0, 0,
+		0, 0, 15, 69, 78, 79, 84, 95, 65, 85, 84, 72, 79, 82, 73, 90, 69, 68,
+		0, 0, 0, 0, 2, 1, 2, 11, 1, 1, 3, 2, 2, 1, 2, 3, 3, 2,
+		1, 2, 11, 1, 1, 3, 0, 1, 4, 1, 1, 2, 18, 7, 0, 41, 1, 4,
+		15, 7, 0, 42, 1, 12, 0, 10, 0, 16, 0, 20, 6, 1, 0, 0, 0, 0,
+		0, 0, 0, 22, 11, 0, 15, 0, 21, 2, 6, 1, 0, 0, 0, 0, 0, 0,
+		0, 17, 1, 39, 2, 1, 4, 1, 2, 0, 15, 7, 0, 41, 2, 4, 12, 7,
+		0, 42, 2, 15, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 56, 0, 4, 10,
+		2, 6, 4, 0, 0, 0, 0, 0, 0, 0, 39, 6, 2, 0, 0, 0, 0, 0,
+		0, 0, 17, 1, 39, 4, 1, 4, 1, 2, 5, 32, 7, 0, 41, 2, 4, 29,
+		7, 0, 42, 2, 12, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 12, 2, 10,
+		2, 10, 0, 35, 4, 26, 10, 1, 15, 1, 6, 1, 0, 0, 0, 0, 0, 0,
+		0, 56, 0, 4, 22, 11, 2, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 12,
+		2, 5, 8, 11, 1, 1, 6, 4, 0, 0, 0, 0, 0, 0, 0, 39, 11, 1,
+		1, 2, 6, 2, 0, 0, 0, 0, 0, 0, 0, 17, 1, 39, 5, 0, 0, 0,
+		0, 23, 10, 0, 17, 6, 7, 0, 33, 4, 19, 10, 0, 6, 0, 0, 0, 0,
+		0, 0, 0, 0, 18, 1, 45, 1, 10, 0, 56, 1, 18, 2, 45, 2, 11, 0,
+		6, 100, 0, 0, 0, 0, 0, 0, 0, 56, 2, 18, 0, 45, 0, 2, 11, 0,
+		1, 6, 5, 0, 0, 0, 0, 0, 0, 0, 39, 9, 1, 4, 1, 0, 11, 23,
+		7, 0, 41, 0, 4, 20, 7, 0, 42, 0, 12, 2, 11, 0, 4, 14, 11, 2,
+		15, 2, 11, 1, 56, 0, 1, 2, 11, 2, 15, 2, 11, 1, 56, 3, 1, 5,
+		13, 6, 3, 0, 0, 0, 0, 0, 0, 0, 17, 1, 39, 1, 0, 2, 0, 0,
+		0, 0,
 	]
 });
 
 #[rustfmt::skip]
 pub static MODULE_FRAMEWORK_USECASES_COIN_EXAMPLE: Lazy<Vec<u8>> = Lazy::new(|| {
 	vec![
-		161, 28, 235, 11, 7, 0, 0, 10, 11, 1, 0, 6, 2, 6, 4, 3, 10, 39,
-		4, 49, 6, 5, 55, 29, 7, 84, 112, 8, 196, 1, 64, 6, 132, 2, 27, 16,
-		159, 2, 31, 10, 190, 2, 5, 12, 195, 2, 39, 0, 0, 1, 4, 1, 8, 0,
-		1, 0, 0, 0, 3, 0, 1, 0, 1, 1, 5, 3, 1, 1, 0, 1, 0, 6,
-		4, 1, 0, 1, 1, 7, 0, 1, 1, 0, 1, 2, 9, 0, 5, 0, 1, 1,
-		10, 6, 1, 1, 0, 1, 1, 2, 3, 2, 5, 2, 1, 6, 12, 0, 1, 8,
-		0, 5, 6, 12, 10, 2, 10, 2, 2, 1, 3, 6, 12, 6, 12, 3, 1, 5,
-		3, 6, 12, 5, 3, 12, 99, 111, 105, 110, 95, 101, 120, 97, 109, 112, 108, 101,
-		11, 69, 120, 97, 109, 112, 108, 101, 67, 111, 105, 110, 11, 100, 117, 109, 109, 121,
-		95, 102, 105, 101, 108, 100, 11, 105, 110, 105, 116, 95, 109, 111, 100, 117, 108, 101,
-		12, 109, 97, 110, 97, 103, 101, 100, 95, 99, 111, 105, 110, 10, 105, 110, 105, 116,
-		105, 97, 108, 105, 122, 101, 6, 109, 105, 110, 116, 95, 112, 8, 114, 101, 103, 105,
-		115, 116, 101, 114, 6, 115, 105, 103, 110, 101, 114, 10, 97, 100, 100, 114, 101, 115,
-		115, 95, 111, 102, 4, 109, 105, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		161, 28, 235, 11, 7, 0, 0, 10, 11, 1, 0, 8, 2, 8, 4, 3, 12, 59,
+		4, 71, 10, 5, 81, 31, 7, 112, 159, 1, 8, 143, 2, 64, 6, 207, 2, 27,
+		16, 234, 2, 31, 10, 137, 3, 5, 12, 142, 3, 91, 0, 0, 1, 4, 1, 7,
+		1, 12, 0, 1, 0, 0, 0, 3, 0, 1, 0, 1, 1, 5, 3, 1, 1, 0,
+		1, 1, 6, 0, 1, 1, 0, 1, 2, 8, 0, 4, 0, 1, 1, 9, 5, 1,
+		1, 0, 1, 0, 10, 6, 1, 0, 1, 0, 11, 6, 1, 0, 1, 3, 13, 4,
+		7, 1, 0, 1, 3, 14, 5, 1, 1, 0, 1, 1, 2, 2, 2, 4, 2, 7,
+		2, 8, 2, 1, 6, 12, 0, 1, 8, 0, 5, 6, 12, 10, 2, 10, 2, 2,
+		1, 1, 5, 3, 6, 12, 5, 3, 3, 6, 12, 6, 12, 3, 1, 1, 12, 99,
+		111, 105, 110, 95, 101, 120, 97, 109, 112, 108, 101, 11, 69, 120, 97, 109, 112, 108,
+		101, 67, 111, 105, 110, 11, 100, 117, 109, 109, 121, 95, 102, 105, 101, 108, 100, 11,
+		105, 110, 105, 116, 95, 109, 111, 100, 117, 108, 101, 12, 109, 97, 110, 97, 103, 101,
+		100, 95, 99, 111, 105, 110, 10, 105, 110, 105, 116, 105, 97, 108, 105, 122, 101, 8,
+		114, 101, 103, 105, 115, 116, 101, 114, 6, 115, 105, 103, 110, 101, 114, 10, 97, 100,
+		100, 114, 101, 115, 115, 95, 111, 102, 4, 109, 105, 110, 116, 6, 109, 105, 110, 116,
+		95, 112, 10, 116, 114, 97, 110, 115, 102, 101, 114, 95, 112, 4, 99, 111, 105, 110,
+		21, 105, 115, 95, 97, 99, 99, 111, 117, 110, 116, 95, 114, 101, 103, 105, 115, 116,
+		101, 114, 101, 100, 8, 116, 114, 97, 110, 115, 102, 101, 114, 0, 0, 0, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 171, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 171, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		1, 10, 2, 13, 12, 69, 120, 97, 109, 112, 108, 101, 32, 67, 111, 105, 110, 10,
-		2, 8, 7, 69, 120, 97, 109, 112, 108, 101, 20, 99, 111, 109, 112, 105, 108, 97,
-		116, 105, 111, 110, 95, 109, 101, 116, 97, 100, 97, 116, 97, 9, 0, 3, 50, 46,
-		48, 3, 50, 46, 49, 0, 2, 1, 2, 1, 0, 0, 0, 0, 1, 7, 11, 0,
-		7, 0, 7, 1, 49, 8, 9, 56, 0, 2, 2, 1, 4, 0, 1, 8, 10, 0,
-		56, 1, 11, 1, 11, 0, 17, 4, 11, 2, 56, 2, 2, 0,
+		0, 0, 0, 0, 1, 10, 2, 13, 12, 69, 120, 97, 109, 112, 108, 101, 32, 67,
+		111, 105, 110, 10, 2, 8, 7, 69, 120, 97, 109, 112, 108, 101, 20, 99, 111, 109,
+		112, 105, 108, 97, 116, 105, 111, 110, 95, 109, 101, 116, 97, 100, 97, 116, 97, 9,
+		0, 3, 50, 46, 48, 3, 50, 46, 49, 0, 2, 1, 2, 1, 0, 0, 0, 0,
+		1, 14, 10, 0, 7, 0, 7, 1, 49, 8, 9, 56, 0, 10, 0, 56, 1, 10,
+		0, 11, 0, 17, 3, 6, 0, 0, 100, 167, 179, 182, 224, 13, 56, 2, 2, 5,
+		1, 4, 0, 1, 8, 10, 0, 56, 1, 11, 1, 11, 0, 17, 3, 11, 2, 56,
+		2, 2, 6, 1, 4, 0, 1, 13, 10, 0, 17, 3, 56, 3, 4, 5, 5, 7,
+		10, 0, 56, 1, 11, 1, 11, 0, 17, 3, 11, 2, 56, 4, 2, 0,
 	]
 });
 
 #[rustfmt::skip]
 pub static MODULE_FRAMEWORK_USECASES_FUNGIBLE_ASSET_EXAMPLE: Lazy<Vec<u8>> = Lazy::new(|| {
 	vec![
-		161, 28, 235, 11, 7, 0, 0, 10, 12, 1, 0, 16, 2, 16, 48, 3, 64, 152,
-		1, 4, 216, 1, 16, 5, 232, 1, 216, 1, 7, 192, 3, 210, 4, 8, 146, 8,
-		64, 6, 210, 8, 73, 16, 155, 9, 192, 1, 10, 219, 10, 12, 12, 231, 10, 231,
-		1, 13, 206, 12, 4, 0, 0, 1, 4, 1, 12, 1, 14, 1, 16, 1, 25, 1,
+		161, 28, 235, 11, 7, 0, 0, 10, 12, 1, 0, 16, 2, 16, 48, 3, 64, 171,
+		1, 4, 235, 1, 18, 5, 253, 1, 230, 1, 7, 227, 3, 221, 4, 8, 192, 8,
+		64, 6, 128, 9, 73, 16, 201, 9, 192, 1, 10, 137, 11, 12, 12, 149, 11, 147,
+		2, 13, 168, 13, 4, 0, 0, 1, 4, 1, 12, 1, 14, 1, 16, 1, 25, 1,
 		30, 1, 33, 0, 1, 8, 0, 1, 3, 6, 0, 1, 6, 6, 0, 1, 8, 6,
 		0, 2, 11, 7, 1, 0, 1, 1, 13, 11, 0, 1, 18, 8, 0, 1, 23, 0,
 		0, 2, 29, 2, 0, 6, 32, 7, 1, 0, 0, 7, 35, 7, 0, 0, 9, 0,
@@ -428,85 +495,90 @@ pub static MODULE_FRAMEWORK_USECASES_FUNGIBLE_ASSET_EXAMPLE: Lazy<Vec<u8>> = Laz
 		18, 0, 1, 0, 27, 4, 1, 0, 1, 2, 28, 20, 21, 0, 1, 6, 31, 1,
 		23, 1, 0, 1, 7, 34, 24, 25, 0, 1, 4, 36, 26, 1, 0, 1, 1, 37,
 		27, 28, 0, 1, 1, 38, 27, 29, 0, 1, 1, 39, 27, 30, 0, 1, 2, 40,
-		27, 31, 0, 1, 0, 41, 33, 1, 0, 1, 2, 42, 34, 2, 0, 1, 2, 43,
-		2, 35, 1, 8, 1, 3, 5, 4, 5, 5, 8, 7, 5, 8, 5, 10, 8, 14,
-		22, 23, 5, 4, 6, 12, 5, 5, 3, 0, 1, 5, 1, 11, 4, 1, 8, 5,
-		1, 6, 12, 1, 8, 5, 2, 5, 11, 4, 1, 9, 0, 1, 11, 4, 1, 8,
-		6, 1, 8, 6, 4, 6, 12, 11, 4, 1, 9, 0, 11, 4, 1, 9, 0, 3,
-		3, 11, 4, 1, 8, 5, 11, 4, 1, 8, 6, 11, 4, 1, 8, 6, 3, 6,
-		12, 5, 3, 2, 11, 4, 1, 9, 0, 5, 1, 1, 1, 6, 11, 4, 1, 9,
-		0, 2, 6, 8, 1, 3, 1, 8, 7, 3, 6, 8, 2, 11, 4, 1, 9, 0,
-		8, 7, 1, 3, 5, 11, 4, 1, 8, 5, 11, 4, 1, 8, 5, 6, 8, 0,
-		11, 4, 1, 8, 6, 8, 7, 2, 6, 12, 10, 2, 1, 8, 8, 1, 4, 1,
-		11, 9, 1, 9, 0, 1, 10, 2, 1, 8, 10, 7, 6, 8, 8, 11, 9, 1,
-		4, 8, 10, 8, 10, 2, 8, 10, 8, 10, 1, 6, 8, 8, 1, 8, 1, 1,
-		8, 3, 1, 8, 2, 1, 12, 7, 8, 8, 6, 8, 8, 8, 3, 12, 8, 2,
-		8, 1, 8, 0, 3, 6, 12, 6, 12, 3, 2, 6, 5, 10, 2, 1, 11, 4,
-		1, 9, 0, 22, 102, 117, 110, 103, 105, 98, 108, 101, 95, 97, 115, 115, 101, 116,
-		95, 101, 120, 97, 109, 112, 108, 101, 20, 77, 97, 110, 97, 103, 101, 100, 70, 117,
-		110, 103, 105, 98, 108, 101, 65, 115, 115, 101, 116, 8, 109, 105, 110, 116, 95, 114,
-		101, 102, 7, 77, 105, 110, 116, 82, 101, 102, 14, 102, 117, 110, 103, 105, 98, 108,
-		101, 95, 97, 115, 115, 101, 116, 12, 116, 114, 97, 110, 115, 102, 101, 114, 95, 114,
-		101, 102, 11, 84, 114, 97, 110, 115, 102, 101, 114, 82, 101, 102, 8, 98, 117, 114,
-		110, 95, 114, 101, 102, 7, 66, 117, 114, 110, 82, 101, 102, 8, 116, 114, 97, 110,
-		115, 102, 101, 114, 12, 103, 101, 116, 95, 109, 101, 116, 97, 100, 97, 116, 97, 6,
-		79, 98, 106, 101, 99, 116, 6, 111, 98, 106, 101, 99, 116, 8, 77, 101, 116, 97,
-		100, 97, 116, 97, 6, 115, 105, 103, 110, 101, 114, 10, 97, 100, 100, 114, 101, 115,
-		115, 95, 111, 102, 22, 112, 114, 105, 109, 97, 114, 121, 95, 102, 117, 110, 103, 105,
-		98, 108, 101, 95, 115, 116, 111, 114, 101, 13, 112, 114, 105, 109, 97, 114, 121, 95,
-		115, 116, 111, 114, 101, 13, 70, 117, 110, 103, 105, 98, 108, 101, 83, 116, 111, 114,
-		101, 27, 101, 110, 115, 117, 114, 101, 95, 112, 114, 105, 109, 97, 114, 121, 95, 115,
-		116, 111, 114, 101, 95, 101, 120, 105, 115, 116, 115, 4, 109, 105, 110, 116, 8, 105,
-		115, 95, 111, 119, 110, 101, 114, 14, 111, 98, 106, 101, 99, 116, 95, 97, 100, 100,
-		114, 101, 115, 115, 13, 70, 117, 110, 103, 105, 98, 108, 101, 65, 115, 115, 101, 116,
-		16, 100, 101, 112, 111, 115, 105, 116, 95, 119, 105, 116, 104, 95, 114, 101, 102, 5,
-		101, 114, 114, 111, 114, 17, 112, 101, 114, 109, 105, 115, 115, 105, 111, 110, 95, 100,
-		101, 110, 105, 101, 100, 11, 105, 110, 105, 116, 95, 109, 111, 100, 117, 108, 101, 19,
-		99, 114, 101, 97, 116, 101, 95, 110, 97, 109, 101, 100, 95, 111, 98, 106, 101, 99,
-		116, 14, 67, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 82, 101, 102, 6, 111,
-		112, 116, 105, 111, 110, 4, 110, 111, 110, 101, 6, 79, 112, 116, 105, 111, 110, 6,
-		115, 116, 114, 105, 110, 103, 4, 117, 116, 102, 56, 6, 83, 116, 114, 105, 110, 103,
-		43, 99, 114, 101, 97, 116, 101, 95, 112, 114, 105, 109, 97, 114, 121, 95, 115, 116,
-		111, 114, 101, 95, 101, 110, 97, 98, 108, 101, 100, 95, 102, 117, 110, 103, 105, 98,
-		108, 101, 95, 97, 115, 115, 101, 116, 17, 103, 101, 110, 101, 114, 97, 116, 101, 95,
-		109, 105, 110, 116, 95, 114, 101, 102, 17, 103, 101, 110, 101, 114, 97, 116, 101, 95,
-		98, 117, 114, 110, 95, 114, 101, 102, 21, 103, 101, 110, 101, 114, 97, 116, 101, 95,
-		116, 114, 97, 110, 115, 102, 101, 114, 95, 114, 101, 102, 15, 103, 101, 110, 101, 114,
-		97, 116, 101, 95, 115, 105, 103, 110, 101, 114, 6, 109, 105, 110, 116, 95, 112, 21,
-		99, 114, 101, 97, 116, 101, 95, 111, 98, 106, 101, 99, 116, 95, 97, 100, 100, 114,
-		101, 115, 115, 17, 97, 100, 100, 114, 101, 115, 115, 95, 116, 111, 95, 111, 98, 106,
-		101, 99, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 205, 0,
+		27, 31, 0, 1, 4, 20, 32, 1, 0, 1, 0, 41, 34, 1, 0, 1, 0, 42,
+		34, 1, 0, 1, 4, 9, 35, 1, 1, 8, 1, 2, 43, 36, 2, 0, 1, 2,
+		44, 2, 37, 1, 8, 1, 3, 5, 4, 5, 5, 8, 7, 5, 8, 5, 10, 8,
+		14, 22, 24, 5, 26, 5, 4, 6, 12, 5, 5, 3, 0, 1, 5, 1, 11, 4,
+		1, 8, 5, 1, 6, 12, 1, 8, 5, 2, 5, 11, 4, 1, 9, 0, 1, 11,
+		4, 1, 8, 6, 1, 8, 6, 4, 6, 12, 11, 4, 1, 9, 0, 11, 4, 1,
+		9, 0, 3, 3, 11, 4, 1, 8, 5, 11, 4, 1, 8, 6, 11, 4, 1, 8,
+		6, 3, 6, 12, 5, 3, 2, 11, 4, 1, 9, 0, 5, 1, 1, 1, 6, 11,
+		4, 1, 9, 0, 2, 6, 8, 1, 3, 1, 8, 7, 3, 6, 8, 2, 11, 4,
+		1, 9, 0, 8, 7, 1, 3, 5, 11, 4, 1, 8, 5, 11, 4, 1, 8, 5,
+		6, 8, 0, 11, 4, 1, 8, 6, 8, 7, 2, 6, 12, 10, 2, 1, 8, 8,
+		1, 4, 1, 11, 9, 1, 9, 0, 1, 10, 2, 1, 8, 10, 7, 6, 8, 8,
+		11, 9, 1, 4, 8, 10, 8, 10, 2, 8, 10, 8, 10, 1, 6, 8, 8, 1,
+		8, 1, 1, 8, 3, 1, 8, 2, 1, 12, 3, 6, 8, 1, 5, 3, 6, 8,
+		8, 6, 8, 8, 8, 1, 8, 3, 8, 2, 12, 3, 6, 12, 6, 12, 3, 4,
+		6, 12, 11, 4, 1, 9, 0, 5, 3, 2, 6, 5, 10, 2, 1, 11, 4, 1,
+		9, 0, 22, 102, 117, 110, 103, 105, 98, 108, 101, 95, 97, 115, 115, 101, 116, 95,
+		101, 120, 97, 109, 112, 108, 101, 20, 77, 97, 110, 97, 103, 101, 100, 70, 117, 110,
+		103, 105, 98, 108, 101, 65, 115, 115, 101, 116, 8, 109, 105, 110, 116, 95, 114, 101,
+		102, 7, 77, 105, 110, 116, 82, 101, 102, 14, 102, 117, 110, 103, 105, 98, 108, 101,
+		95, 97, 115, 115, 101, 116, 12, 116, 114, 97, 110, 115, 102, 101, 114, 95, 114, 101,
+		102, 11, 84, 114, 97, 110, 115, 102, 101, 114, 82, 101, 102, 8, 98, 117, 114, 110,
+		95, 114, 101, 102, 7, 66, 117, 114, 110, 82, 101, 102, 8, 116, 114, 97, 110, 115,
+		102, 101, 114, 12, 103, 101, 116, 95, 109, 101, 116, 97, 100, 97, 116, 97, 6, 79,
+		98, 106, 101, 99, 116, 6, 111, 98, 106, 101, 99, 116, 8, 77, 101, 116, 97, 100,
+		97, 116, 97, 6, 115, 105, 103, 110, 101, 114, 10, 97, 100, 100, 114, 101, 115, 115,
+		95, 111, 102, 22, 112, 114, 105, 109, 97, 114, 121, 95, 102, 117, 110, 103, 105, 98,
+		108, 101, 95, 115, 116, 111, 114, 101, 13, 112, 114, 105, 109, 97, 114, 121, 95, 115,
+		116, 111, 114, 101, 13, 70, 117, 110, 103, 105, 98, 108, 101, 83, 116, 111, 114, 101,
+		27, 101, 110, 115, 117, 114, 101, 95, 112, 114, 105, 109, 97, 114, 121, 95, 115, 116,
+		111, 114, 101, 95, 101, 120, 105, 115, 116, 115, 4, 109, 105, 110, 116, 8, 105, 115,
+		95, 111, 119, 110, 101, 114, 14, 111, 98, 106, 101, 99, 116, 95, 97, 100, 100, 114,
+		101, 115, 115, 13, 70, 117, 110, 103, 105, 98, 108, 101, 65, 115, 115, 101, 116, 16,
+		100, 101, 112, 111, 115, 105, 116, 95, 119, 105, 116, 104, 95, 114, 101, 102, 5, 101,
+		114, 114, 111, 114, 17, 112, 101, 114, 109, 105, 115, 115, 105, 111, 110, 95, 100, 101,
+		110, 105, 101, 100, 11, 105, 110, 105, 116, 95, 109, 111, 100, 117, 108, 101, 19, 99,
+		114, 101, 97, 116, 101, 95, 110, 97, 109, 101, 100, 95, 111, 98, 106, 101, 99, 116,
+		14, 67, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 82, 101, 102, 6, 111, 112,
+		116, 105, 111, 110, 4, 110, 111, 110, 101, 6, 79, 112, 116, 105, 111, 110, 6, 115,
+		116, 114, 105, 110, 103, 4, 117, 116, 102, 56, 6, 83, 116, 114, 105, 110, 103, 43,
+		99, 114, 101, 97, 116, 101, 95, 112, 114, 105, 109, 97, 114, 121, 95, 115, 116, 111,
+		114, 101, 95, 101, 110, 97, 98, 108, 101, 100, 95, 102, 117, 110, 103, 105, 98, 108,
+		101, 95, 97, 115, 115, 101, 116, 17, 103, 101, 110, 101, 114, 97, 116, 101, 95, 109,
+		105, 110, 116, 95, 114, 101, 102, 17, 103, 101, 110, 101, 114, 97, 116, 101, 95, 98,
+		117, 114, 110, 95, 114, 101, 102, 21, 103, 101, 110, 101, 114, 97, 116, 101, 95, 116,
+		114, 97, 110, 115, 102, 101, 114, 95, 114, 101, 102, 15, 103, 101, 110, 101, 114, 97,
+		116, 101, 95, 115, 105, 103, 110, 101, 114, 6, 109, 105, 110, 116, 95, 112, 10, 116,
+		114, 97, 110, 115, 102, 101, 114, 95, 112, 21, 99, 114, 101, 97, 116, 101, 95, 111,
+		98, 106, 101, 99, 116, 95, 97, 100, 100, 114, 101, 115, 115, 17, 97, 100, 100, 114,
+		101, 115, 115, 95, 116, 111, 95, 111, 98, 106, 101, 99, 116, 0, 0, 0, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 10, 2, 3, 2, 70,
-		65, 10, 2, 8, 7, 70, 65, 32, 67, 111, 105, 110, 10, 2, 31, 30, 104, 116,
-		116, 112, 58, 47, 47, 101, 120, 97, 109, 112, 108, 101, 46, 99, 111, 109, 47, 102,
-		97, 118, 105, 99, 111, 110, 46, 105, 99, 111, 10, 2, 19, 18, 104, 116, 116, 112,
-		58, 47, 47, 101, 120, 97, 109, 112, 108, 101, 46, 99, 111, 109, 20, 99, 111, 109,
-		112, 105, 108, 97, 116, 105, 111, 110, 95, 109, 101, 116, 97, 100, 97, 116, 97, 9,
-		0, 3, 50, 46, 48, 3, 50, 46, 49, 18, 97, 112, 116, 111, 115, 58, 58, 109,
-		101, 116, 97, 100, 97, 116, 97, 95, 118, 49, 140, 1, 1, 1, 0, 0, 0, 0,
-		0, 0, 0, 10, 69, 78, 79, 84, 95, 79, 87, 78, 69, 82, 52, 79, 110, 108,
-		121, 32, 102, 117, 110, 103, 105, 98, 108, 101, 32, 97, 115, 115, 101, 116, 32, 109,
-		101, 116, 97, 100, 97, 116, 97, 32, 111, 119, 110, 101, 114, 32, 99, 97, 110, 32,
-		109, 97, 107, 101, 32, 99, 104, 97, 110, 103, 101, 115, 46, 1, 20, 77, 97, 110,
-		97, 103, 101, 100, 70, 117, 110, 103, 105, 98, 108, 101, 65, 115, 115, 101, 116, 1,
-		3, 1, 24, 48, 120, 49, 58, 58, 111, 98, 106, 101, 99, 116, 58, 58, 79, 98,
-		106, 101, 99, 116, 71, 114, 111, 117, 112, 1, 12, 103, 101, 116, 95, 109, 101, 116,
-		97, 100, 97, 116, 97, 1, 1, 0, 0, 2, 3, 2, 8, 1, 5, 8, 2, 7,
-		8, 3, 0, 1, 4, 0, 10, 18, 11, 1, 17, 1, 12, 4, 10, 0, 17, 2,
-		10, 4, 56, 0, 12, 5, 11, 2, 11, 4, 56, 1, 12, 6, 11, 0, 11, 5,
-		11, 6, 11, 3, 56, 2, 2, 6, 1, 4, 1, 0, 19, 33, 10, 0, 17, 2,
-		17, 1, 12, 3, 10, 3, 12, 4, 10, 4, 11, 0, 17, 2, 56, 3, 4, 30,
-		14, 4, 56, 4, 43, 0, 12, 5, 11, 1, 11, 3, 56, 1, 12, 6, 10, 5,
-		16, 0, 11, 2, 17, 9, 12, 7, 11, 5, 16, 1, 11, 6, 11, 7, 56, 5,
-		2, 6, 1, 0, 0, 0, 0, 0, 0, 0, 17, 11, 39, 12, 0, 0, 0, 32,
-		37, 11, 0, 7, 0, 17, 13, 12, 1, 14, 1, 12, 2, 10, 2, 56, 6, 7,
-		1, 17, 15, 7, 0, 17, 15, 49, 8, 7, 2, 17, 15, 7, 3, 17, 15, 17,
-		16, 10, 2, 17, 17, 10, 2, 17, 18, 12, 3, 10, 2, 17, 19, 11, 2, 17,
-		20, 12, 4, 14, 4, 12, 0, 11, 3, 18, 0, 12, 7, 11, 0, 11, 7, 45,
-		0, 2, 21, 1, 4, 1, 0, 1, 6, 11, 1, 11, 0, 17, 2, 11, 2, 17,
-		6, 2, 1, 1, 0, 0, 1, 5, 14, 0, 7, 0, 17, 22, 56, 7, 2, 0,
+		0, 0, 0, 0, 0, 0, 0, 171, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 1, 10, 2, 3, 2, 70, 65, 10, 2, 8, 7, 70, 65, 32,
+		67, 111, 105, 110, 10, 2, 31, 30, 104, 116, 116, 112, 58, 47, 47, 101, 120, 97,
+		109, 112, 108, 101, 46, 99, 111, 109, 47, 102, 97, 118, 105, 99, 111, 110, 46, 105,
+		99, 111, 10, 2, 19, 18, 104, 116, 116, 112, 58, 47, 47, 101, 120, 97, 109, 112,
+		108, 101, 46, 99, 111, 109, 20, 99, 111, 109, 112, 105, 108, 97, 116, 105, 111, 110,
+		95, 109, 101, 116, 97, 100, 97, 116, 97, 9, 0, 3, 50, 46, 48, 3, 50, 46,
+		49, 18, 97, 112, 116, 111, 115, 58, 58, 109, 101, 116, 97, 100, 97, 116, 97, 95,
+		118, 49, 140, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 10, 69, 78, 79, 84,
+		95, 79, 87, 78, 69, 82, 52, 79, 110, 108, 121, 32, 102, 117, 110, 103, 105, 98,
+		108, 101, 32, 97, 115, 115, 101, 116, 32, 109, 101, 116, 97, 100, 97, 116, 97, 32,
+		111, 119, 110, 101, 114, 32, 99, 97, 110, 32, 109, 97, 107, 101, 32, 99, 104, 97,
+		110, 103, 101, 115, 46, 1, 20, 77, 97, 110, 97, 103, 101, 100, 70, 117, 110, 103,
+		105, 98, 108, 101, 65, 115, 115, 101, 116, 1, 3, 1, 24, 48, 120, 49, 58, 58,
+		111, 98, 106, 101, 99, 116, 58, 58, 79, 98, 106, 101, 99, 116, 71, 114, 111, 117,
+		112, 1, 12, 103, 101, 116, 95, 109, 101, 116, 97, 100, 97, 116, 97, 1, 1, 0,
+		0, 2, 3, 2, 8, 1, 5, 8, 2, 7, 8, 3, 0, 1, 4, 0, 10, 18,
+		11, 1, 17, 1, 12, 4, 10, 0, 17, 2, 10, 4, 56, 0, 12, 5, 11, 2,
+		11, 4, 56, 1, 12, 6, 11, 0, 11, 5, 11, 6, 11, 3, 56, 2, 2, 6,
+		1, 4, 1, 0, 19, 33, 10, 0, 17, 2, 17, 1, 12, 3, 10, 3, 12, 4,
+		10, 4, 11, 0, 17, 2, 56, 3, 4, 30, 14, 4, 56, 4, 43, 0, 12, 5,
+		11, 1, 11, 3, 56, 1, 12, 6, 10, 5, 16, 0, 11, 2, 17, 9, 12, 7,
+		11, 5, 16, 1, 11, 6, 11, 7, 56, 5, 2, 6, 1, 0, 0, 0, 0, 0,
+		0, 0, 17, 11, 39, 12, 0, 0, 0, 33, 42, 10, 0, 7, 0, 17, 13, 12,
+		1, 14, 1, 12, 2, 10, 2, 56, 6, 7, 1, 17, 15, 7, 0, 17, 15, 49,
+		8, 7, 2, 17, 15, 7, 3, 17, 15, 17, 16, 10, 2, 17, 17, 12, 3, 10,
+		2, 17, 18, 12, 4, 10, 2, 17, 19, 12, 5, 11, 2, 17, 20, 12, 6, 14,
+		3, 11, 0, 17, 2, 6, 0, 0, 100, 167, 179, 182, 224, 13, 17, 21, 14, 6,
+		11, 3, 11, 5, 11, 4, 18, 0, 45, 0, 2, 22, 1, 4, 1, 0, 1, 6,
+		11, 1, 11, 0, 17, 2, 11, 2, 17, 6, 2, 23, 1, 4, 0, 3, 11, 10,
+		1, 17, 2, 17, 1, 12, 3, 11, 1, 11, 3, 11, 0, 17, 2, 11, 2, 56,
+		7, 2, 1, 1, 0, 0, 1, 5, 14, 0, 7, 0, 17, 25, 56, 8, 2, 0,
 		0, 0, 1, 0,
 	]
 });
@@ -865,6 +937,51 @@ pub static MODULE_FRAMEWORK_USECASES_RESOURCE_GROUPS_EXAMPLE: Lazy<Vec<u8>> = La
 			]
 });
 
+#[rustfmt::skip]
+pub static MODULE_FRAMEWORK_USECASES_TABLE_EXAMPLE: Lazy<Vec<u8>> = Lazy::new(|| {
+	vec![
+		161, 28, 235, 11, 7, 0, 0, 10, 11, 1, 0, 8, 2, 8, 12, 3, 20, 51,
+		4, 71, 10, 5, 81, 80, 7, 161, 1, 157, 1, 8, 190, 2, 64, 16, 254, 2,
+		31, 10, 157, 3, 9, 12, 166, 3, 219, 1, 13, 129, 5, 2, 0, 0, 1, 4,
+		1, 6, 1, 9, 0, 1, 8, 0, 1, 3, 4, 2, 0, 0, 0, 0, 0, 5,
+		0, 1, 0, 1, 2, 7, 2, 3, 0, 1, 1, 8, 1, 5, 2, 7, 4, 1,
+		3, 10, 7, 1, 1, 0, 1, 1, 11, 8, 1, 2, 3, 2, 1, 1, 12, 9,
+		10, 2, 2, 0, 1, 1, 13, 9, 11, 2, 2, 0, 1, 2, 4, 3, 6, 4,
+		4, 5, 4, 6, 4, 3, 6, 12, 10, 3, 10, 3, 0, 1, 6, 12, 1, 5,
+		2, 3, 3, 1, 11, 1, 2, 9, 0, 9, 1, 1, 3, 1, 7, 10, 9, 0,
+		3, 7, 11, 1, 2, 9, 0, 9, 1, 9, 0, 9, 1, 2, 6, 11, 1, 2,
+		9, 0, 9, 1, 9, 0, 1, 1, 1, 6, 9, 1, 9, 5, 7, 11, 1, 2,
+		3, 3, 10, 3, 10, 3, 3, 3, 10, 3, 10, 3, 3, 13, 116, 97, 98, 108,
+		101, 95, 101, 120, 97, 109, 112, 108, 101, 18, 83, 109, 97, 114, 116, 84, 97, 98,
+		108, 101, 82, 101, 115, 111, 117, 114, 99, 101, 5, 118, 97, 108, 117, 101, 10, 83,
+		109, 97, 114, 116, 84, 97, 98, 108, 101, 11, 115, 109, 97, 114, 116, 95, 116, 97,
+		98, 108, 101, 34, 116, 101, 115, 116, 95, 105, 110, 115, 101, 114, 116, 95, 97, 110,
+		100, 95, 108, 111, 111, 107, 117, 112, 95, 115, 109, 97, 114, 116, 95, 116, 97, 98,
+		108, 101, 6, 115, 105, 103, 110, 101, 114, 10, 97, 100, 100, 114, 101, 115, 115, 95,
+		111, 102, 3, 110, 101, 119, 6, 118, 101, 99, 116, 111, 114, 7, 114, 101, 118, 101,
+		114, 115, 101, 6, 117, 112, 115, 101, 114, 116, 8, 99, 111, 110, 116, 97, 105, 110,
+		115, 6, 98, 111, 114, 114, 111, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 171, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+		20, 99, 111, 109, 112, 105, 108, 97, 116, 105, 111, 110, 95, 109, 101, 116, 97, 100,
+		97, 116, 97, 9, 0, 3, 50, 46, 48, 3, 50, 46, 49, 0, 2, 1, 2, 11,
+		1, 2, 3, 3, 0, 1, 4, 1, 0, 12, 86, 10, 0, 17, 1, 12, 3, 10,
+		3, 41, 0, 3, 83, 11, 0, 56, 0, 18, 0, 45, 0, 11, 3, 42, 0, 15,
+		0, 12, 4, 11, 1, 12, 5, 13, 5, 56, 1, 11, 5, 12, 6, 14, 6, 65,
+		6, 12, 7, 10, 7, 6, 0, 0, 0, 0, 0, 0, 0, 0, 36, 4, 39, 13,
+		6, 69, 6, 12, 8, 10, 4, 10, 8, 11, 8, 56, 2, 11, 7, 6, 1, 0,
+		0, 0, 0, 0, 0, 0, 23, 12, 7, 5, 23, 11, 6, 70, 6, 0, 0, 0,
+		0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 12, 7, 11, 2,
+		12, 9, 13, 9, 56, 1, 11, 9, 12, 10, 14, 10, 65, 6, 12, 8, 10, 8,
+		6, 0, 0, 0, 0, 0, 0, 0, 0, 36, 4, 78, 13, 10, 69, 6, 12, 11,
+		10, 4, 46, 10, 11, 56, 3, 3, 65, 5, 73, 11, 7, 10, 4, 46, 11, 11,
+		56, 4, 20, 22, 12, 7, 11, 8, 6, 1, 0, 0, 0, 0, 0, 0, 0, 23,
+		12, 8, 5, 52, 11, 4, 1, 11, 10, 70, 6, 0, 0, 0, 0, 0, 0, 0,
+		0, 2, 11, 0, 1, 5, 10, 0, 0, 0,
+	]
+});
+
 #[rustfmt::skip]
 pub static MODULE_FRAMEWORK_USECASES_TOKEN_V1: Lazy<Vec<u8>> = Lazy::new(|| {
 	vec![
@@ -1071,6 +1188,7 @@ pub static MODULES_FRAMEWORK_USECASES: Lazy<Vec<Vec<u8>>> = Lazy::new(|| { vec![
 	MODULE_FRAMEWORK_USECASES_OBJECTS.to_vec(),
 	MODULE_FRAMEWORK_USECASES_PERMISSIONED_TRANSFER.to_vec(),
 	MODULE_FRAMEWORK_USECASES_RESOURCE_GROUPS_EXAMPLE.to_vec(),
+	MODULE_FRAMEWORK_USECASES_TABLE_EXAMPLE.to_vec(),
 	MODULE_FRAMEWORK_USECASES_TOKEN_V1.to_vec(),
 	MODULE_FRAMEWORK_USECASES_VECTOR_EXAMPLE.to_vec(),
 ]});
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS nft_metadata_crawler.content_hashes;
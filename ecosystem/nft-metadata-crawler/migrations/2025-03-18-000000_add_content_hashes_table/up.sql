@@ -0,0 +1,5 @@
+CREATE TABLE nft_metadata_crawler.content_hashes (
+  content_hash VARCHAR PRIMARY KEY,
+  cdn_image_uri VARCHAR NOT NULL,
+  inserted_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,2 @@
+ALTER TABLE IF EXISTS nft_metadata_crawler.parsed_asset_uris DROP COLUMN IF EXISTS json_etag;
+ALTER TABLE IF EXISTS nft_metadata_crawler.parsed_asset_uris DROP COLUMN IF EXISTS json_last_modified;
@@ -0,0 +1,2 @@
+ALTER TABLE IF EXISTS nft_metadata_crawler.parsed_asset_uris ADD COLUMN IF NOT EXISTS json_etag VARCHAR;
+ALTER TABLE IF EXISTS nft_metadata_crawler.parsed_asset_uris ADD COLUMN IF NOT EXISTS json_last_modified VARCHAR;
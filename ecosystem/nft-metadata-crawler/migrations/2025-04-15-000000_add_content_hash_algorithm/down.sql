@@ -0,0 +1,4 @@
+ALTER TABLE IF EXISTS nft_metadata_crawler.content_hashes DROP CONSTRAINT IF EXISTS content_hashes_pkey;
+DELETE FROM nft_metadata_crawler.content_hashes WHERE hash_algorithm != 'sha256';
+ALTER TABLE IF EXISTS nft_metadata_crawler.content_hashes ADD PRIMARY KEY (content_hash);
+ALTER TABLE IF EXISTS nft_metadata_crawler.content_hashes DROP COLUMN IF EXISTS hash_algorithm;
@@ -0,0 +1,3 @@
+ALTER TABLE IF EXISTS nft_metadata_crawler.content_hashes ADD COLUMN IF NOT EXISTS hash_algorithm VARCHAR NOT NULL DEFAULT 'sha256';
+ALTER TABLE IF EXISTS nft_metadata_crawler.content_hashes DROP CONSTRAINT IF EXISTS content_hashes_pkey;
+ALTER TABLE IF EXISTS nft_metadata_crawler.content_hashes ADD PRIMARY KEY (content_hash, hash_algorithm);
@@ -0,0 +1,66 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Mutex;
+
+/// Buffers items so that they can be committed in batches of a fixed size
+pub struct BatchBuffer<T> {
+    batch_size: usize,
+    items: Mutex<Vec<T>>,
+}
+
+impl<T> BatchBuffer<T> {
+    pub fn new(batch_size: usize) -> Self {
+        Self {
+            batch_size,
+            items: Mutex::new(Vec::with_capacity(batch_size)),
+        }
+    }
+
+    /// Adds an item to the buffer. Returns a full batch to commit if the buffer reached the batch size
+    pub fn push(&self, item: T) -> Option<Vec<T>> {
+        let mut items = self.items.lock().unwrap();
+        items.push(item);
+        (items.len() >= self.batch_size).then(|| std::mem::take(&mut *items))
+    }
+
+    /// Returns true if any buffered item, not yet committed, matches the predicate
+    pub fn any(&self, predicate: impl Fn(&T) -> bool) -> bool {
+        self.items.lock().unwrap().iter().any(predicate)
+    }
+
+    /// Takes all buffered items, e.g., to flush a partial batch on a timer or on shutdown
+    pub fn take_all(&self) -> Vec<T> {
+        std::mem::take(&mut *self.items.lock().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_returns_full_batches() {
+        let buffer = BatchBuffer::new(2);
+
+        let batches = (0..5).filter_map(|i| buffer.push(i)).collect::<Vec<_>>();
+
+        assert_eq!(batches, vec![vec![0, 1], vec![2, 3]]);
+    }
+
+    #[test]
+    fn test_take_all_flushes_partial_batch() {
+        let buffer = BatchBuffer::new(3);
+        assert!(buffer.push(0).is_none());
+        assert!(buffer.push(1).is_none());
+
+        // Simulates a shutdown before the batch is full
+        assert_eq!(buffer.take_all(), vec![0, 1]);
+        assert!(buffer.take_all().is_empty());
+
+        // The buffer keeps batching after a flush
+        assert!(buffer.push(2).is_none());
+        assert!(buffer.push(3).is_none());
+        assert_eq!(buffer.push(4), Some(vec![2, 3, 4]));
+    }
+}
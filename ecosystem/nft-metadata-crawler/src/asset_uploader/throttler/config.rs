@@ -1,7 +1,39 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::utils::constants::DEFAULT_PER_ITEM_TIMEOUT_SECONDS;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
+
+/// Algorithm used to hash asset content for deduplication
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentHashAlgorithm {
+    #[default]
+    Sha256,
+    Sha512,
+    Blake3,
+}
+
+impl ContentHashAlgorithm {
+    /// Returns the hex-encoded digest of the content
+    pub fn digest(&self, content: &[u8]) -> String {
+        match self {
+            Self::Sha256 => sha256::digest(content),
+            Self::Sha512 => format!("{:x}", Sha512::digest(content)),
+            Self::Blake3 => blake3::hash(content).to_hex().to_string(),
+        }
+    }
+
+    /// Name stored alongside the hash, so it is known which algorithm produced it
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Sha512 => "sha512",
+            Self::Blake3 => "blake3",
+        }
+    }
+}
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -20,6 +52,28 @@ pub struct AssetUploaderThrottlerConfig {
     pub cloudflare_image_delivery_prefix: String,
     /// In addition to on the fly transformations, Cloudflare images can be returned in preset variants. This is the default variant used with the saved CDN image URLs.
     pub cloudflare_default_variant: String,
+    /// If true, download each asset and skip the upload if an asset with identical content was already uploaded
+    #[serde(default)]
+    pub dedup_by_content_hash: bool,
+    /// Algorithm used to hash asset content if deduplicating by content hash
+    #[serde(default)]
+    pub content_hash_algo: ContentHashAlgorithm,
+    /// Maximum number of seconds to spend uploading a single asset before giving up on the attempt
+    #[serde(default = "AssetUploaderThrottlerConfig::default_per_item_timeout_secs")]
+    pub per_item_timeout_secs: u64,
+    /// Number of successful uploads to buffer before committing them to Postgres in a single transaction.
+    /// A value of 1 commits each upload immediately.
+    #[serde(default = "AssetUploaderThrottlerConfig::default_batch_commit_size")]
+    pub batch_commit_size: usize,
+    /// Interval in seconds to commit a partially filled batch of successful uploads
+    #[serde(default = "AssetUploaderThrottlerConfig::default_batch_commit_interval_secs")]
+    pub batch_commit_interval_secs: u64,
+    /// URL notified with a JSON payload once each asset is uploaded
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+    /// Whether to also notify the webhook when an upload attempt fails
+    #[serde(default)]
+    pub notify_webhook_on_failure: bool,
 }
 
 impl AssetUploaderThrottlerConfig {
@@ -30,4 +84,16 @@ impl AssetUploaderThrottlerConfig {
     pub const fn default_poll_rows_limit() -> u64 {
         600
     }
+
+    pub const fn default_per_item_timeout_secs() -> u64 {
+        DEFAULT_PER_ITEM_TIMEOUT_SECONDS
+    }
+
+    pub const fn default_batch_commit_size() -> usize {
+        1
+    }
+
+    pub const fn default_batch_commit_interval_secs() -> u64 {
+        5
+    }
 }
@@ -7,19 +7,27 @@ use crate::{
     models::{
         asset_uploader_request_statuses::AssetUploaderRequestStatuses,
         asset_uploader_request_statuses_query::AssetUploaderRequestStatusesQuery,
+        content_hashes::ContentHashes, content_hashes_query::ContentHashesQuery,
         parsed_asset_uris::ParsedAssetUris, parsed_asset_uris_query::ParsedAssetUrisQuery,
     },
     schema::{self},
-    utils::database::upsert_uris,
+    utils::{
+        constants::MAX_IMAGE_REQUEST_RETRY_SECONDS,
+        counters::ASSET_UPLOAD_FAILURE_KIND_COUNT,
+        database::{insert_content_hash, upsert_uris},
+        failure::process_with_timeout,
+        webhook::{AssetNotification, WebhookNotifier},
+    },
 };
 use ahash::{AHashMap, AHashSet};
 use anyhow::Context;
 use axum::{http::StatusCode as AxumStatusCode, response::IntoResponse, routing::post, Extension};
-use config::AssetUploaderThrottlerConfig;
+use batch_buffer::BatchBuffer;
+use config::{AssetUploaderThrottlerConfig, ContentHashAlgorithm};
 use diesel::{
-    r2d2::{ConnectionManager, Pool},
+    r2d2::{ConnectionManager, Pool, PooledConnection},
     upsert::excluded,
-    ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl,
+    Connection, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl,
 };
 use reqwest::{Client, StatusCode as ReqwestStatusCode};
 use serde::Deserialize;
@@ -36,6 +44,7 @@ use tokio::sync::{Mutex, Notify};
 use tracing::{debug, error, info, warn};
 use url::Url;
 
+pub mod batch_buffer;
 pub mod config;
 
 const FIVE_MINUTES: Duration = Duration::from_secs(60 * 5);
@@ -79,6 +88,8 @@ pub struct AssetUploaderThrottlerContext {
     is_rate_limited: Arc<AtomicBool>,
     rate_limit_over_notify: Arc<Notify>,
     client: Arc<Client>,
+    pending_commits: Arc<BatchBuffer<AssetUploaderRequestStatuses>>,
+    webhook: Option<WebhookNotifier>,
 }
 
 impl AssetUploaderThrottlerContext {
@@ -86,6 +97,11 @@ impl AssetUploaderThrottlerContext {
         config: AssetUploaderThrottlerConfig,
         pool: Pool<ConnectionManager<PgConnection>>,
     ) -> Self {
+        let batch_commit_size = config.batch_commit_size;
+        let webhook = config
+            .webhook_url
+            .clone()
+            .map(|url| WebhookNotifier::new(url, config.notify_webhook_on_failure));
         Self {
             config,
             pool,
@@ -97,13 +113,60 @@ impl AssetUploaderThrottlerContext {
             is_rate_limited: Arc::new(AtomicBool::new(false)),
             rate_limit_over_notify: Arc::new(Notify::new()),
             client: Arc::new(Client::new()),
+            pending_commits: Arc::new(BatchBuffer::new(batch_commit_size)),
+            webhook,
         }
     }
 
+    /// Downloads the asset and returns the hash of its content
+    async fn get_content_hash(&self, asset_uri: &str) -> anyhow::Result<String> {
+        let content = self
+            .client
+            .get(asset_uri)
+            .timeout(Duration::from_secs(MAX_IMAGE_REQUEST_RETRY_SECONDS))
+            .send()
+            .await
+            .context("Error downloading asset")?
+            .error_for_status()?
+            .bytes()
+            .await
+            .context("Error reading asset content")?;
+
+        Ok(compute_content_hash(
+            &content,
+            self.config.content_hash_algo,
+        ))
+    }
+
     async fn upload_asset(
         &self,
         asset: AssetUploaderRequestStatuses,
     ) -> anyhow::Result<AssetUploaderRequestStatuses> {
+        // If enabled, reuse the CDN URI of a previous upload with identical content
+        let content_hash = if self.config.dedup_by_content_hash {
+            let content_hash = self.get_content_hash(&asset.asset_uri).await?;
+            if let Some(existing) = ContentHashesQuery::get_by_content_hash(
+                &mut self.pool.get()?,
+                &content_hash,
+                self.config.content_hash_algo.as_str(),
+            ) {
+                info!(
+                    asset_uri = ?asset.asset_uri,
+                    content_hash = content_hash,
+                    "Asset with identical content already uploaded, skipping upload"
+                );
+                let mut asset = asset;
+                asset.status_code = ReqwestStatusCode::OK.as_u16() as i64;
+                asset.cdn_image_uri = Some(existing.cdn_image_uri);
+
+                self.commit_successful_upload(&asset)?;
+                return Ok(asset);
+            }
+            Some(content_hash)
+        } else {
+            None
+        };
+
         // Make a request to the worker to upload the asset
         info!(asset_uri = ?asset.asset_uri, "Requesting worker to upload asset");
         let res = self
@@ -134,10 +197,19 @@ impl AssetUploaderThrottlerContext {
 
             asset.cdn_image_uri.clone_from(&cdn_image_uri);
 
-            // Update the asset URI in the parsed_asset_uris table
-            let mut parsed_asset_uri = ParsedAssetUris::new(&asset.asset_uri);
-            parsed_asset_uri.set_cdn_image_uri(cdn_image_uri);
-            upsert_uris(&mut self.pool.get()?, &parsed_asset_uri, 0)?;
+            // Save the content hash so later assets with identical content can reuse this upload
+            if let (Some(content_hash), Some(cdn_image_uri)) = (&content_hash, &cdn_image_uri) {
+                insert_content_hash(
+                    &mut self.pool.get()?,
+                    &ContentHashes::new(
+                        content_hash,
+                        self.config.content_hash_algo.as_str(),
+                        cdn_image_uri,
+                    ),
+                )?;
+            }
+
+            self.commit_successful_upload(&asset)?;
         } else {
             asset.num_failures += 1;
             asset.error_messages = Some(
@@ -146,9 +218,9 @@ impl AssetUploaderThrottlerContext {
                     .map(|err| Some(err.to_string()))
                     .collect::<Vec<_>>(),
             );
+            self.update_request_status(&asset)?;
         }
 
-        self.update_request_status(&asset)?;
         Ok(asset)
     }
 
@@ -187,18 +259,14 @@ impl AssetUploaderThrottlerContext {
                 self.config.cloudflare_default_variant,
             ));
 
-            asset.cdn_image_uri.clone_from(&cdn_image_uri);
-
-            // Update the asset URI in the parsed_asset_uris table
-            let mut parsed_asset_uri = ParsedAssetUris::new(&asset.asset_uri);
-            parsed_asset_uri.set_cdn_image_uri(cdn_image_uri);
-            upsert_uris(&mut self.pool.get()?, &parsed_asset_uri, 0)?;
+            asset.cdn_image_uri = cdn_image_uri;
+            self.commit_successful_upload(&asset)?;
         } else {
             asset.num_failures += 1;
             asset.error_messages = Some(vec![Some("Asset not found in Cloudflare".to_string())]);
+            self.update_request_status(&asset)?;
         }
 
-        self.update_request_status(&asset)?;
         Ok(asset)
     }
 
@@ -238,12 +306,17 @@ impl AssetUploaderThrottlerContext {
             tokio::spawn(async move {
                 // Handle upload depending on previous attempt status.
                 // If previous attempt resulted in a 409, the asset likely already exists, so we call a different endpoint on the worker to perform the lookup.
-                let upload_res = match ReqwestStatusCode::from_u16(asset.status_code as u16)? {
-                    ReqwestStatusCode::CONFLICT => {
-                        self_clone.get_from_cloudflare(asset.clone()).await
-                    },
-                    _ => self_clone.upload_asset(asset.clone()).await,
-                };
+                // The attempt is cut off after the per-item timeout so a hanging request cannot hold the task forever.
+                let upload_res =
+                    process_with_timeout(self_clone.config.per_item_timeout_secs, async {
+                        match ReqwestStatusCode::from_u16(asset.status_code as u16)? {
+                            ReqwestStatusCode::CONFLICT => {
+                                self_clone.get_from_cloudflare(asset.clone()).await
+                            },
+                            _ => self_clone.upload_asset(asset.clone()).await,
+                        }
+                    })
+                    .await;
 
                 let mut upload_queue = self_clone.upload_queue.lock().await;
                 match upload_res {
@@ -253,6 +326,12 @@ impl AssetUploaderThrottlerContext {
                             ReqwestStatusCode::OK => {
                                 // If success, remove asset from in-progress set and end early
                                 upload_queue.in_progress_assets.remove(&asset);
+                                if let Some(webhook) = &self_clone.webhook {
+                                    webhook.notify(AssetNotification::processed(
+                                        &asset.asset_uri,
+                                        asset.cdn_image_uri.clone(),
+                                    ));
+                                }
                                 anyhow::Ok(())
                             },
                             ReqwestStatusCode::TOO_MANY_REQUESTS => {
@@ -294,8 +373,14 @@ impl AssetUploaderThrottlerContext {
                             _ => Ok(()),
                         }
                     },
-                    Err(e) => {
-                        error!(error = ?e, asset_uri = asset.asset_uri, "[Asset Uploader Throttler] Error uploading asset");
+                    Err((kind, e)) => {
+                        error!(error = ?e, failure_kind = kind.as_str(), asset_uri = asset.asset_uri, "[Asset Uploader Throttler] Error uploading asset");
+                        ASSET_UPLOAD_FAILURE_KIND_COUNT
+                            .with_label_values(&[kind.as_str()])
+                            .inc();
+                        if let Some(webhook) = &self_clone.webhook {
+                            webhook.notify(AssetNotification::failed(&asset.asset_uri, kind));
+                        }
                         upload_queue.asset_queue.insert(asset);
                         Ok(())
                     },
@@ -320,7 +405,16 @@ impl AssetUploaderThrottlerContext {
         for row in rows {
             let row: AssetUploaderRequestStatuses = (&row).into();
             let upload_queue = &mut self.upload_queue.lock().await;
-            if !upload_queue.in_progress_assets.contains(&row) {
+            // Skip assets whose successful upload is buffered but not yet committed
+            if !upload_queue.in_progress_assets.contains(&row)
+                && !self.pending_commits.any(|pending| {
+                    (
+                        &pending.idempotency_key,
+                        &pending.application_id,
+                        &pending.asset_uri,
+                    ) == (&row.idempotency_key, &row.application_id, &row.asset_uri)
+                })
+            {
                 upload_queue.asset_queue.insert(row);
                 num_queued += 1;
             }
@@ -351,7 +445,63 @@ impl AssetUploaderThrottlerContext {
         }
     }
 
+    /// Commits a successful upload, buffering it until a full batch is ready if batching is enabled
+    fn commit_successful_upload(&self, asset: &AssetUploaderRequestStatuses) -> anyhow::Result<()> {
+        if self.config.batch_commit_size <= 1 {
+            return self.write_successful_uploads(std::slice::from_ref(asset));
+        }
+
+        match self.pending_commits.push(asset.clone()) {
+            Some(batch) => self.write_successful_uploads(&batch),
+            None => Ok(()),
+        }
+    }
+
+    /// Commits all buffered successful uploads, returning the number of uploads committed
+    fn flush_pending_commits(&self) -> anyhow::Result<usize> {
+        let batch = self.pending_commits.take_all();
+        if !batch.is_empty() {
+            self.write_successful_uploads(&batch)?;
+        }
+        Ok(batch.len())
+    }
+
+    async fn start_batch_commit_loop(&self) {
+        let batch_commit_interval = Duration::from_secs(self.config.batch_commit_interval_secs);
+        loop {
+            tokio::time::sleep(batch_commit_interval).await;
+            if let Err(e) = self.flush_pending_commits() {
+                error!(error = ?e, "[Asset Uploader Throttler] Error committing batch of uploads");
+            }
+        }
+    }
+
+    /// Writes the CDN image URIs and request statuses of successful uploads in a single transaction
+    fn write_successful_uploads(
+        &self,
+        assets: &[AssetUploaderRequestStatuses],
+    ) -> anyhow::Result<()> {
+        self.pool.get()?.transaction::<_, anyhow::Error, _>(|conn| {
+            for asset in assets {
+                // Update the asset URI in the parsed_asset_uris table
+                let mut parsed_asset_uri = ParsedAssetUris::new(&asset.asset_uri);
+                parsed_asset_uri.set_cdn_image_uri(asset.cdn_image_uri.clone());
+                upsert_uris(conn, &parsed_asset_uri, 0)?;
+
+                Self::upsert_request_status(conn, asset)?;
+            }
+            Ok(())
+        })
+    }
+
     fn update_request_status(&self, asset: &AssetUploaderRequestStatuses) -> anyhow::Result<()> {
+        Self::upsert_request_status(&mut self.pool.get()?, asset)
+    }
+
+    fn upsert_request_status(
+        conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+        asset: &AssetUploaderRequestStatuses,
+    ) -> anyhow::Result<()> {
         use schema::nft_metadata_crawler::asset_uploader_request_statuses::dsl::*;
 
         let query = diesel::insert_into(asset_uploader_request_statuses)
@@ -368,7 +518,7 @@ impl AssetUploaderThrottlerContext {
 
         let debug_query = diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string();
         debug!("Executing Query: {}", debug_query);
-        query.execute(&mut self.pool.get()?)?;
+        query.execute(conn)?;
         Ok(())
     }
 }
@@ -388,8 +538,220 @@ impl Server for AssetUploaderThrottlerContext {
             anyhow::Ok(())
         });
 
+        if self.config.batch_commit_size > 1 {
+            let self_arc_clone = self_arc.clone();
+            tokio::spawn(async move {
+                self_arc_clone.start_batch_commit_loop().await;
+            });
+        }
+
         axum::Router::new()
             .route("/update_queue", post(Self::handle_update_queue))
             .layer(Extension(self_arc.clone()))
     }
+
+    fn shutdown(&self) {
+        // Commit the partial batch so buffered uploads are not lost
+        match self.flush_pending_commits() {
+            Ok(num_committed) => info!(
+                num_committed = num_committed,
+                "[Asset Uploader Throttler] Committed pending uploads on shutdown"
+            ),
+            Err(e) => {
+                error!(error = ?e, "[Asset Uploader Throttler] Error committing pending uploads on shutdown")
+            },
+        }
+    }
+}
+
+/// Computes the hash of an asset's content, used to deduplicate uploads of identical assets
+pub fn compute_content_hash(content: &[u8], algorithm: ContentHashAlgorithm) -> String {
+    algorithm.digest(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{asset_uploader::api::IdempotencyTuple, utils::database::setup_test_database};
+    use httpmock::{Mock, MockServer};
+    use serde_json::json;
+
+    const IMAGE_BYTES: &str = "identical image bytes";
+
+    fn test_config(
+        server: &MockServer,
+        algorithm: ContentHashAlgorithm,
+    ) -> AssetUploaderThrottlerConfig {
+        AssetUploaderThrottlerConfig {
+            asset_uploader_worker_uri: server.url("/upload"),
+            poll_interval_seconds: AssetUploaderThrottlerConfig::default_poll_interval_seconds(),
+            poll_rows_limit: AssetUploaderThrottlerConfig::default_poll_rows_limit(),
+            cloudflare_account_hash: "account_hash".to_string(),
+            cloudflare_image_delivery_prefix: "https://cdn.com".to_string(),
+            cloudflare_default_variant: "public".to_string(),
+            dedup_by_content_hash: true,
+            content_hash_algo: algorithm,
+            per_item_timeout_secs: AssetUploaderThrottlerConfig::default_per_item_timeout_secs(),
+            batch_commit_size: AssetUploaderThrottlerConfig::default_batch_commit_size(),
+            batch_commit_interval_secs:
+                AssetUploaderThrottlerConfig::default_batch_commit_interval_secs(),
+            webhook_url: None,
+            notify_webhook_on_failure: false,
+        }
+    }
+
+    /// Serves identical content for every asset, and uploads it under the given image id
+    fn mock_assets_and_worker<'a>(server: &'a MockServer, image_id: &str) -> Mock<'a> {
+        server.mock(|when, then| {
+            when.method("GET").path_contains("/assets/");
+            then.status(200).body(IMAGE_BYTES);
+        });
+        server.mock(|when, then| {
+            when.method("POST").path("/upload");
+            then.status(200)
+                .json_body(json!({ "errors": [], "result": { "id": image_id } }));
+        })
+    }
+
+    fn request(server: &MockServer, asset: &str) -> AssetUploaderRequestStatuses {
+        AssetUploaderRequestStatuses::new(
+            &IdempotencyTuple {
+                idempotency_key: "key".to_string(),
+                application_id: "app".to_string(),
+            },
+            &server.url(format!("/assets/{}", asset)),
+        )
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database at NFT_METADATA_CRAWLER_DATABASE_URL"]
+    async fn test_identical_assets_produce_one_upload() {
+        let (pool, _guard) = setup_test_database().await;
+        let server = MockServer::start();
+        let upload = mock_assets_and_worker(&server, "image_id");
+        let context = AssetUploaderThrottlerContext::new(
+            test_config(&server, ContentHashAlgorithm::default()),
+            pool.clone(),
+        );
+
+        let first = context
+            .upload_asset(request(&server, "first.png"))
+            .await
+            .unwrap();
+        let second = context
+            .upload_asset(request(&server, "second.png"))
+            .await
+            .unwrap();
+
+        // Only the first asset is uploaded, the second one reuses its CDN URI
+        upload.assert_hits(1);
+        let cdn_image_uri = Some("https://cdn.com/account_hash/image_id/public".to_string());
+        assert_eq!(first.cdn_image_uri, cdn_image_uri);
+        assert_eq!(second.cdn_image_uri, cdn_image_uri);
+        assert_eq!(second.status_code, ReqwestStatusCode::OK.as_u16() as i64);
+
+        // Both assets are committed with the shared CDN URI
+        for asset in [&first, &second] {
+            let parsed_asset_uri =
+                ParsedAssetUrisQuery::get_by_asset_uri(&mut pool.get().unwrap(), &asset.asset_uri)
+                    .unwrap();
+            assert_eq!(parsed_asset_uri.cdn_image_uri, cdn_image_uri);
+        }
+    }
+
+    #[test]
+    fn test_different_assets_produce_different_hashes() {
+        assert_ne!(
+            compute_content_hash(b"first image bytes", ContentHashAlgorithm::default()),
+            compute_content_hash(b"second image bytes", ContentHashAlgorithm::default())
+        );
+    }
+
+    #[test]
+    fn test_content_hash_algorithms() {
+        let algorithms = [
+            (ContentHashAlgorithm::Sha256, 64),
+            (ContentHashAlgorithm::Sha512, 128),
+            (ContentHashAlgorithm::Blake3, 64),
+        ];
+        for (algorithm, hex_len) in algorithms {
+            let hash = compute_content_hash(b"image bytes", algorithm);
+            assert_eq!(hash.len(), hex_len, "{:?}", algorithm);
+            assert_eq!(hash, compute_content_hash(b"image bytes", algorithm));
+            assert_ne!(hash, compute_content_hash(b"other image bytes", algorithm));
+        }
+        assert_ne!(
+            compute_content_hash(b"image bytes", ContentHashAlgorithm::Sha256),
+            compute_content_hash(b"image bytes", ContentHashAlgorithm::Blake3)
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database at NFT_METADATA_CRAWLER_DATABASE_URL"]
+    async fn test_dedup_is_per_algorithm() {
+        let (pool, _guard) = setup_test_database().await;
+        let server = MockServer::start();
+        let upload = mock_assets_and_worker(&server, "image_id");
+        let sha256_context = AssetUploaderThrottlerContext::new(
+            test_config(&server, ContentHashAlgorithm::Sha256),
+            pool.clone(),
+        );
+        let blake3_context = AssetUploaderThrottlerContext::new(
+            test_config(&server, ContentHashAlgorithm::Blake3),
+            pool.clone(),
+        );
+
+        // Identical content hashed with different algorithms is uploaded once per algorithm
+        for (context, asset) in [
+            (&sha256_context, "first.png"),
+            (&blake3_context, "second.png"),
+            (&sha256_context, "third.png"),
+            (&blake3_context, "fourth.png"),
+        ] {
+            context.upload_asset(request(&server, asset)).await.unwrap();
+        }
+        upload.assert_hits(2);
+
+        // Each hash is only found under the algorithm which produced it
+        let content = IMAGE_BYTES.as_bytes();
+        for algorithm in [ContentHashAlgorithm::Sha256, ContentHashAlgorithm::Blake3] {
+            let content_hash = compute_content_hash(content, algorithm);
+            let existing = ContentHashesQuery::get_by_content_hash(
+                &mut pool.get().unwrap(),
+                &content_hash,
+                algorithm.as_str(),
+            )
+            .unwrap();
+            assert_eq!(existing.hash_algorithm, algorithm.as_str());
+            assert!(ContentHashesQuery::get_by_content_hash(
+                &mut pool.get().unwrap(),
+                &content_hash,
+                ContentHashAlgorithm::Sha512.as_str(),
+            )
+            .is_none());
+        }
+
+        // The same hash can be stored for different algorithms, and existing entries are kept
+        let mut conn = pool.get().unwrap();
+        let content_hash = compute_content_hash(content, ContentHashAlgorithm::Sha256);
+        insert_content_hash(
+            &mut conn,
+            &ContentHashes::new(&content_hash, "sha512", "https://cdn.com/sha512"),
+        )
+        .unwrap();
+        insert_content_hash(
+            &mut conn,
+            &ContentHashes::new(&content_hash, "sha256", "https://cdn.com/other"),
+        )
+        .unwrap();
+        for (algorithm, cdn_image_uri) in [
+            ("sha512", "https://cdn.com/sha512"),
+            ("sha256", "https://cdn.com/account_hash/image_id/public"),
+        ] {
+            let existing =
+                ContentHashesQuery::get_by_content_hash(&mut conn, &content_hash, algorithm)
+                    .unwrap();
+            assert_eq!(existing.cdn_image_uri, cdn_image_uri);
+        }
+    }
 }
@@ -7,7 +7,7 @@ use crate::{
         throttler::{config::AssetUploaderThrottlerConfig, AssetUploaderThrottlerContext},
         worker::{config::AssetUploaderWorkerConfig, AssetUploaderWorkerContext},
     },
-    parser::{config::ParserConfig, ParserContext},
+    parser::{config::ParserConfig, BatchSummary, ParserContext},
     utils::database::{establish_connection_pool, run_migrations},
 };
 use aptos_indexer_grpc_server_framework::RunnableConfig;
@@ -25,6 +25,9 @@ use tracing::info;
 #[enum_dispatch]
 pub trait Server: Send + Sync {
     fn build_router(&self) -> Router;
+
+    /// Called after the server stops accepting requests, e.g., to flush buffered state
+    fn shutdown(&self) {}
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,6 +39,18 @@ pub enum ServerConfig {
     AssetUploaderThrottler(AssetUploaderThrottlerConfig),
 }
 
+/// How the crawler processes its entries
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields, tag = "type", rename_all = "snake_case")]
+pub enum RunMode {
+    /// Binds `server_port` and serves requests until shut down
+    #[default]
+    Server,
+    /// Processes up to `max_items` entries, or all of them if unset, then exits without binding
+    /// a listener. Only supported by the parser.
+    Batch { max_items: Option<usize> },
+}
+
 /// Structs to hold config from YAML
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -43,6 +58,8 @@ pub struct NFTMetadataCrawlerConfig {
     pub database_url: String,
     pub server_port: u16,
     pub server_config: ServerConfig,
+    #[serde(default)]
+    pub mode: RunMode,
 }
 
 #[derive(Clone)]
@@ -81,6 +98,16 @@ impl ServerConfig {
     }
 }
 
+impl ServerContext {
+    /// Processes a bounded number of entries instead of serving requests
+    pub async fn run_batch(&self, max_items: Option<usize>) -> anyhow::Result<BatchSummary> {
+        match self {
+            ServerContext::Parser(parser_context) => parser_context.run_batch(max_items).await,
+            _ => anyhow::bail!("Batch mode is only supported by the parser"),
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl RunnableConfig for NFTMetadataCrawlerConfig {
     /// Main driver function that establishes a connection to Pubsub and parses the Pubsub entries in parallel
@@ -97,8 +124,21 @@ impl RunnableConfig for NFTMetadataCrawlerConfig {
 
         // Create request context
         let context = self.server_config.build_context(pool).await;
+        if let RunMode::Batch { max_items } = self.mode {
+            let summary = context.run_batch(max_items).await?;
+            info!(
+                summary = summary.to_string(),
+                "[NFT Metadata Crawler] Finished batch"
+            );
+            println!("{}", summary);
+            return Ok(());
+        }
+
         let listener = TcpListener::bind(format!("0.0.0.0:{}", self.server_port)).await?;
-        axum::serve(listener, context.build_router()).await?;
+        axum::serve(listener, context.build_router())
+            .with_graceful_shutdown(shutdown_signal())
+            .await?;
+        context.shutdown();
 
         Ok(())
     }
@@ -113,3 +153,29 @@ impl RunnableConfig for NFTMetadataCrawlerConfig {
         .to_string()
     }
 }
+
+/// Resolves when the process receives Ctrl+C or SIGTERM
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("Failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+    info!("[NFT Metadata Crawler] Shutting down");
+}
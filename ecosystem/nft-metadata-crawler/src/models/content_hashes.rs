@@ -0,0 +1,28 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::nft_metadata_crawler::content_hashes;
+use diesel::prelude::*;
+use field_count::FieldCount;
+use serde::{Deserialize, Serialize};
+
+/// Maps the hash of an asset's content to the CDN URI it was uploaded to, used to deduplicate uploads
+#[derive(Clone, Debug, Deserialize, FieldCount, Identifiable, Insertable, Serialize)]
+#[diesel(primary_key(content_hash, hash_algorithm))]
+#[diesel(table_name = content_hashes)]
+pub struct ContentHashes {
+    pub content_hash: String,
+    pub cdn_image_uri: String,
+    /// Algorithm which produced the content hash
+    pub hash_algorithm: String,
+}
+
+impl ContentHashes {
+    pub fn new(content_hash: &str, hash_algorithm: &str, cdn_image_uri: &str) -> Self {
+        Self {
+            content_hash: content_hash.to_string(),
+            cdn_image_uri: cdn_image_uri.to_string(),
+            hash_algorithm: hash_algorithm.to_string(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    schema::nft_metadata_crawler::content_hashes, utils::constants::MAX_RETRY_TIME_SECONDS,
+};
+use backoff::{retry, ExponentialBackoff};
+use diesel::{
+    prelude::*,
+    r2d2::{ConnectionManager, PooledConnection},
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::error;
+
+#[derive(Debug, Deserialize, Identifiable, Queryable, Serialize)]
+#[diesel(primary_key(content_hash, hash_algorithm))]
+#[diesel(table_name = content_hashes)]
+pub struct ContentHashesQuery {
+    pub content_hash: String,
+    pub cdn_image_uri: String,
+    pub inserted_at: chrono::NaiveDateTime,
+    pub hash_algorithm: String,
+}
+
+impl ContentHashesQuery {
+    pub fn get_by_content_hash(
+        conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+        content_hash: &str,
+        hash_algorithm: &str,
+    ) -> Option<Self> {
+        let mut op = || {
+            content_hashes::table
+                .find((content_hash, hash_algorithm))
+                .first::<ContentHashesQuery>(conn)
+                .optional()
+                .map_err(Into::into)
+        };
+
+        let backoff = ExponentialBackoff {
+            max_elapsed_time: Some(Duration::from_secs(MAX_RETRY_TIME_SECONDS)),
+            ..Default::default()
+        };
+
+        retry(backoff, &mut op).unwrap_or_else(|e| {
+            error!(
+                content_hash = content_hash,
+                hash_algorithm = hash_algorithm,
+                error = ?e,
+                "Failed to get_by_content_hash"
+            );
+            None
+        })
+    }
+}
@@ -3,6 +3,8 @@
 
 pub mod asset_uploader_request_statuses;
 pub mod asset_uploader_request_statuses_query;
+pub mod content_hashes;
+pub mod content_hashes_query;
 pub mod ledger_info;
 pub mod parsed_asset_uris;
 pub mod parsed_asset_uris_query;
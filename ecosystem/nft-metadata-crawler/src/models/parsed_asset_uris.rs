@@ -3,7 +3,7 @@
 
 use crate::{
     models::parsed_asset_uris_query::ParsedAssetUrisQuery,
-    schema::nft_metadata_crawler::parsed_asset_uris,
+    schema::nft_metadata_crawler::parsed_asset_uris, utils::json_parser::CacheValidators,
 };
 use diesel::prelude::*;
 use field_count::FieldCount;
@@ -25,6 +25,8 @@ pub struct ParsedAssetUris {
     animation_optimizer_retry_count: i32,
     do_not_parse: bool,
     last_transaction_version: i64,
+    json_etag: Option<String>,
+    json_last_modified: Option<String>,
 }
 
 impl ParsedAssetUris {
@@ -41,6 +43,8 @@ impl ParsedAssetUris {
             animation_optimizer_retry_count: 0,
             do_not_parse: false,
             last_transaction_version: 0,
+            json_etag: None,
+            json_last_modified: None,
         }
     }
 
@@ -170,6 +174,18 @@ impl ParsedAssetUris {
     pub fn set_last_transaction_version(&mut self, last_transaction_version: i64) {
         self.last_transaction_version = last_transaction_version;
     }
+
+    pub fn get_json_cache_validators(&self) -> CacheValidators {
+        CacheValidators {
+            etag: self.json_etag.clone(),
+            last_modified: self.json_last_modified.clone(),
+        }
+    }
+
+    pub fn set_json_cache_validators(&mut self, validators: CacheValidators) {
+        self.json_etag = validators.etag;
+        self.json_last_modified = validators.last_modified;
+    }
 }
 
 impl From<ParsedAssetUrisQuery> for ParsedAssetUris {
@@ -186,6 +202,8 @@ impl From<ParsedAssetUrisQuery> for ParsedAssetUris {
             animation_optimizer_retry_count: query.animation_optimizer_retry_count,
             do_not_parse: query.do_not_parse,
             last_transaction_version: query.last_transaction_version,
+            json_etag: query.json_etag,
+            json_last_modified: query.json_last_modified,
         }
     }
 }
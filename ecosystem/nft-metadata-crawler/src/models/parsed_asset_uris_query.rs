@@ -29,6 +29,8 @@ pub struct ParsedAssetUrisQuery {
     pub inserted_at: chrono::NaiveDateTime,
     pub do_not_parse: bool,
     pub last_transaction_version: i64,
+    pub json_etag: Option<String>,
+    pub json_last_modified: Option<String>,
 }
 
 impl ParsedAssetUrisQuery {
@@ -1,9 +1,13 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::utils::constants::{
-    DEFAULT_IMAGE_QUALITY, DEFAULT_MAX_FILE_SIZE_BYTES, DEFAULT_MAX_IMAGE_DIMENSIONS,
-    DEFAULT_MAX_NUM_PARSE_RETRIES,
+use crate::{
+    parser::message_source::MessageSourceConfig,
+    utils::constants::{
+        DEFAULT_IMAGE_QUALITY, DEFAULT_MAX_FILE_SIZE_BYTES, DEFAULT_MAX_IMAGE_DIMENSIONS,
+        DEFAULT_MAX_NUM_PARSE_RETRIES, DEFAULT_NUM_PARSER_WORKERS, DEFAULT_PARSER_QUEUE_CAPACITY,
+        DEFAULT_PER_ITEM_TIMEOUT_SECONDS,
+    },
 };
 use serde::{Deserialize, Serialize};
 
@@ -27,6 +31,33 @@ pub struct ParserConfig {
     pub ack_parsed_uris: bool,
     #[serde(default)]
     pub uri_blacklist: Vec<String>,
+    /// Hosts which are never fetched. Entries starting with `.` also match all subdomains.
+    #[serde(default)]
+    pub denied_hosts: Vec<String>,
+    #[serde(default = "ParserConfig::default_per_item_timeout_secs")]
+    pub per_item_timeout_secs: u64,
+    #[serde(default)]
+    pub message_source: MessageSourceConfig,
+    /// If true, forced reparses revalidate JSON already stored for an asset with a conditional
+    /// request and reuse it if unchanged.
+    #[serde(default)]
+    pub enable_conditional_get: bool,
+    /// Number of entries parsed concurrently
+    #[serde(default = "ParserConfig::default_num_parser_workers")]
+    pub num_parser_workers: usize,
+    /// Number of entries waiting to be parsed. Once full, new entries wait until a worker is free.
+    #[serde(default = "ParserConfig::default_parser_queue_capacity")]
+    pub parser_queue_capacity: usize,
+    /// URL notified with a JSON payload once each entry is parsed
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+    /// Whether to also notify the webhook when parsing an entry fails
+    #[serde(default)]
+    pub notify_webhook_on_failure: bool,
+    /// Whether to optimize and store images and animations. If false, only the parsed metadata
+    /// is stored.
+    #[serde(default = "ParserConfig::default_store_assets")]
+    pub store_assets: bool,
 }
 
 impl ParserConfig {
@@ -45,4 +76,53 @@ impl ParserConfig {
     pub const fn default_max_num_parse_retries() -> i32 {
         DEFAULT_MAX_NUM_PARSE_RETRIES
     }
+
+    pub const fn default_per_item_timeout_secs() -> u64 {
+        DEFAULT_PER_ITEM_TIMEOUT_SECONDS
+    }
+
+    pub const fn default_num_parser_workers() -> usize {
+        DEFAULT_NUM_PARSER_WORKERS
+    }
+
+    pub const fn default_parser_queue_capacity() -> usize {
+        DEFAULT_PARSER_QUEUE_CAPACITY
+    }
+
+    pub const fn default_store_assets() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+impl ParserConfig {
+    /// Returns a config with the required fields set, and the given fields overridden
+    pub fn for_test(overrides: serde_json::Value) -> Self {
+        let mut config = serde_json::json!({
+            "bucket": "bucket",
+            "cdn_prefix": "https://cdn.example.com/",
+            "ipfs_prefix": "https://ipfs.example.com/ipfs/",
+        });
+        config
+            .as_object_mut()
+            .unwrap()
+            .extend(overrides.as_object().unwrap().clone());
+        serde_json::from_value(config).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_assets_are_stored_by_default() {
+        assert!(ParserConfig::for_test(json!({})).store_assets);
+    }
+
+    #[test]
+    fn test_storing_assets_can_be_disabled() {
+        assert!(!ParserConfig::for_test(json!({ "store_assets": false })).store_assets);
+    }
 }
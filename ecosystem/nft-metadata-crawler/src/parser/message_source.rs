@@ -0,0 +1,190 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::ParseOutcome;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, future::Future};
+use tokio::sync::{
+    mpsc::{self, Receiver, Sender},
+    oneshot,
+};
+
+/// Where the parser receives its entries from
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields, tag = "type", rename_all = "snake_case")]
+pub enum MessageSourceConfig {
+    /// Entries are pushed by Pubsub to the parser's HTTP endpoint
+    #[default]
+    Pubsub,
+    /// Entries are read from a file, one entry per line
+    File { path: String },
+    /// Entries are sent through the in-memory channel returned by the parser context, useful for
+    /// local development and tests
+    InMemory { capacity: usize },
+}
+
+/// Entry to parse, in the same format as Pubsub messages
+#[derive(Debug)]
+pub struct SourceMessage {
+    pub payload: Bytes,
+    /// Notified once the entry is parsed, for sources which only acknowledge parsed entries
+    pub done: Option<oneshot::Sender<ParseOutcome>>,
+}
+
+impl From<Bytes> for SourceMessage {
+    fn from(payload: Bytes) -> Self {
+        Self {
+            payload,
+            done: None,
+        }
+    }
+}
+
+/// Source of entries to parse
+#[async_trait::async_trait]
+pub trait MessageSource: Send {
+    /// Returns the next entry, or None if the source is exhausted
+    async fn next_message(&mut self) -> Option<SourceMessage>;
+}
+
+/// Receives entries pushed by Pubsub to the parser's HTTP endpoint. Each entry carries a channel
+/// notified once it is parsed, so that it is only acked after parsing.
+pub struct PubsubMessageSource {
+    receiver: Receiver<SourceMessage>,
+}
+
+impl PubsubMessageSource {
+    /// Returns the source and the sender used by the HTTP endpoint to feed it
+    pub fn new(capacity: usize) -> (Sender<SourceMessage>, Self) {
+        let (sender, receiver) = mpsc::channel(capacity.max(1));
+        (sender, Self { receiver })
+    }
+}
+
+#[async_trait::async_trait]
+impl MessageSource for PubsubMessageSource {
+    async fn next_message(&mut self) -> Option<SourceMessage> {
+        self.receiver.recv().await
+    }
+}
+
+/// Reads entries from a file, one entry per line
+pub struct FileMessageSource {
+    messages: VecDeque<Bytes>,
+}
+
+impl FileMessageSource {
+    pub fn new(path: &str) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        let messages = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Bytes::from(line.to_string()))
+            .collect();
+        Ok(Self { messages })
+    }
+}
+
+#[async_trait::async_trait]
+impl MessageSource for FileMessageSource {
+    async fn next_message(&mut self) -> Option<SourceMessage> {
+        self.messages.pop_front().map(SourceMessage::from)
+    }
+}
+
+/// Receives entries sent through an in-memory channel, useful for local development and tests
+pub struct InMemoryMessageSource {
+    receiver: Receiver<Bytes>,
+}
+
+impl InMemoryMessageSource {
+    /// Returns the source and the sender used to feed it. The source is exhausted once all
+    /// senders are dropped.
+    pub fn new(capacity: usize) -> (Sender<Bytes>, Self) {
+        let (sender, receiver) = mpsc::channel(capacity.max(1));
+        (sender, Self { receiver })
+    }
+}
+
+#[async_trait::async_trait]
+impl MessageSource for InMemoryMessageSource {
+    async fn next_message(&mut self) -> Option<SourceMessage> {
+        self.receiver.recv().await.map(SourceMessage::from)
+    }
+}
+
+/// Pulls up to `max_messages` entries from the source, or all of them if no limit is given
+pub async fn take_messages(
+    source: &mut dyn MessageSource,
+    max_messages: Option<usize>,
+) -> Vec<SourceMessage> {
+    let mut messages = vec![];
+    while max_messages.map_or(true, |max| messages.len() < max) {
+        match source.next_message().await {
+            Some(message) => messages.push(message),
+            None => break,
+        }
+    }
+    messages
+}
+
+/// Pulls entries from the source until it is exhausted, handling them one at a time
+pub async fn consume_messages<F, Fut>(source: &mut dyn MessageSource, mut handle: F)
+where
+    F: FnMut(SourceMessage) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    while let Some(message) = source.next_message().await {
+        handle(message).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_consume_in_memory_source() {
+        let messages = vec![
+            "0x1,https://example.com/1.json,1,2024-01-01 00:00:00 UTC,1,false",
+            "0x2,https://example.com/2.json,2,2024-01-01 00:00:00 UTC,1,false",
+        ];
+
+        let (sender, mut source) = InMemoryMessageSource::new(messages.len());
+        for message in &messages {
+            sender.send(Bytes::from(message.to_string())).await.unwrap();
+        }
+        drop(sender);
+
+        let mut received = vec![];
+        consume_messages(&mut source, |message| {
+            received.push(String::from_utf8(message.payload.to_vec()).unwrap());
+            async {}
+        })
+        .await;
+        assert_eq!(received, messages);
+    }
+
+    #[tokio::test]
+    async fn test_take_messages_stops_at_limit() {
+        let (sender, mut source) = InMemoryMessageSource::new(3);
+        for i in 0..3 {
+            sender.send(Bytes::from(i.to_string())).await.unwrap();
+        }
+        drop(sender);
+
+        let payloads = |messages: Vec<SourceMessage>| {
+            messages
+                .into_iter()
+                .map(|message| message.payload)
+                .collect::<Vec<_>>()
+        };
+        let taken = take_messages(&mut source, Some(2)).await;
+        assert_eq!(payloads(taken), vec![Bytes::from("0"), Bytes::from("1")]);
+        // The remaining entry is left in the source
+        assert_eq!(payloads(take_messages(&mut source, None).await), vec![
+            Bytes::from("2")
+        ]);
+    }
+}
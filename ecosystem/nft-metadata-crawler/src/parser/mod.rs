@@ -5,12 +5,16 @@ use crate::{
     config::Server,
     utils::{
         counters::{
-            GOT_CONNECTION_COUNT, PARSER_FAIL_COUNT, PARSER_INVOCATIONS_COUNT,
-            PUBSUB_ACK_SUCCESS_COUNT, SKIP_URI_COUNT, UNABLE_TO_GET_CONNECTION_COUNT,
+            GOT_CONNECTION_COUNT, PARSER_FAILURE_KIND_COUNT, PARSER_FAIL_COUNT,
+            PARSER_INVOCATIONS_COUNT, PARSER_QUEUE_DEPTH, PUBSUB_ACK_SUCCESS_COUNT, SKIP_URI_COUNT,
+            UNABLE_TO_GET_CONNECTION_COUNT,
         },
         database::check_or_update_chain_id,
+        failure::process_with_timeout,
+        webhook::{AssetNotification, WebhookNotifier},
     },
 };
+use anyhow::Context;
 use axum::{http::StatusCode, response::Response, routing::post, Router};
 use bytes::Bytes;
 use config::ParserConfig;
@@ -19,19 +23,71 @@ use diesel::{
     PgConnection,
 };
 use google_cloud_storage::client::{Client as GCSClient, ClientConfig as GCSClientConfig};
-use std::sync::Arc;
+use message_source::{
+    consume_messages, take_messages, FileMessageSource, InMemoryMessageSource, MessageSource,
+    MessageSourceConfig, PubsubMessageSource, SourceMessage,
+};
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::{mpsc::Sender, oneshot};
 use tracing::{error, info, warn};
+use work_queue::WorkQueue;
 use worker::Worker;
 
 pub mod config;
+pub mod message_source;
+pub mod work_queue;
 mod worker;
 
+/// Result of parsing a single entry
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseOutcome {
+    Parsed,
+    Skipped,
+    Failed,
+}
+
+/// Number of entries per outcome, printed once a batch run finishes
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    pub parsed: usize,
+    pub skipped: usize,
+    pub failed: usize,
+}
+
+impl BatchSummary {
+    fn record(&mut self, outcome: ParseOutcome) {
+        match outcome {
+            ParseOutcome::Parsed => self.parsed += 1,
+            ParseOutcome::Skipped => self.skipped += 1,
+            ParseOutcome::Failed => self.failed += 1,
+        }
+    }
+}
+
+impl fmt::Display for BatchSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "parsed: {}, skipped: {}, failed: {}",
+            self.parsed, self.skipped, self.failed
+        )
+    }
+}
+
 /// Struct to hold context required for parsing
 #[derive(Clone)]
 pub struct ParserContext {
     pub parser_config: Arc<ParserConfig>,
     pub pool: Pool<ConnectionManager<PgConnection>>,
     pub gcs_client: Arc<GCSClient>,
+    pub webhook: Option<WebhookNotifier>,
+    /// Sender feeding the in-memory message source, if configured
+    message_sender: Option<Sender<Bytes>>,
+    /// In-memory message source, until parsing from it starts
+    in_memory_source: Arc<Mutex<Option<InMemoryMessageSource>>>,
 }
 
 impl ParserContext {
@@ -62,15 +118,130 @@ impl ParserContext {
                 panic!();
             });
 
+        Self::new_with_gcs_client(parser_config, pool, GCSClient::new(gcs_config))
+    }
+
+    /// Same as [Self::new], but uploads to the given GCS client instead of authenticating one
+    pub fn new_with_gcs_client(
+        parser_config: ParserConfig,
+        pool: Pool<ConnectionManager<PgConnection>>,
+        gcs_client: GCSClient,
+    ) -> Self {
+        let webhook = parser_config
+            .webhook_url
+            .clone()
+            .map(|url| WebhookNotifier::new(url, parser_config.notify_webhook_on_failure));
+
+        let (message_sender, in_memory_source) = match parser_config.message_source {
+            MessageSourceConfig::InMemory { capacity } => {
+                let (sender, source) = InMemoryMessageSource::new(capacity);
+                (Some(sender), Some(source))
+            },
+            _ => (None, None),
+        };
+
         Self {
             parser_config: Arc::new(parser_config),
             pool,
-            gcs_client: Arc::new(GCSClient::new(gcs_config)),
+            gcs_client: Arc::new(gcs_client),
+            webhook,
+            message_sender,
+            in_memory_source: Arc::new(Mutex::new(in_memory_source)),
+        }
+    }
+
+    /// Returns the sender feeding the in-memory message source, if configured. The source is
+    /// never exhausted while the context is alive.
+    pub fn message_sender(&self) -> Option<Sender<Bytes>> {
+        self.message_sender.clone()
+    }
+
+    /// Opens the configured message source. Pubsub pushes entries to the HTTP endpoint, so its
+    /// source is created by the router instead.
+    fn open_message_source(&self) -> anyhow::Result<Box<dyn MessageSource>> {
+        match &self.parser_config.message_source {
+            MessageSourceConfig::Pubsub => {
+                anyhow::bail!("Pubsub pushes entries to the HTTP endpoint, it cannot be opened")
+            },
+            MessageSourceConfig::File { path } => Ok(Box::new(FileMessageSource::new(path)?)),
+            MessageSourceConfig::InMemory { .. } => {
+                let source = self
+                    .in_memory_source
+                    .lock()
+                    .unwrap()
+                    .take()
+                    .context("In-memory message source is already in use")?;
+                Ok(Box::new(source))
+            },
+        }
+    }
+
+    /// Pulls entries from the source until it is exhausted and queues them for parsing. Pulling
+    /// waits while the queue is full.
+    async fn run_from_source(
+        source: &mut dyn MessageSource,
+        work_queue: &WorkQueue<SourceMessage>,
+    ) {
+        consume_messages(source, |message| async move {
+            if let Err(e) = work_queue.push(message).await {
+                error!(error = ?e, "[NFT Metadata Crawler] Failed to queue message");
+            }
+        })
+        .await;
+        info!("[NFT Metadata Crawler] Message source exhausted");
+    }
+
+    /// Spawns the workers parsing the entries pushed to the returned queue
+    fn spawn_work_queue(self: &Arc<Self>) -> WorkQueue<SourceMessage> {
+        let self_clone = self.clone();
+        WorkQueue::spawn(
+            self.parser_config.num_parser_workers,
+            self.parser_config.parser_queue_capacity,
+            PARSER_QUEUE_DEPTH.clone(),
+            move |message: SourceMessage| {
+                let self_clone = self_clone.clone();
+                async move {
+                    let outcome = self_clone.spawn_parser(message.payload).await;
+                    if let Some(done) = message.done {
+                        let _ = done.send(outcome);
+                    }
+                }
+            },
+        )
+    }
+
+    /// Parses up to `max_items` entries from the configured message source, or all of them if no
+    /// limit is given, and returns once they are all parsed. Pubsub pushes entries to the HTTP
+    /// endpoint, so it cannot be used as a source in batch mode.
+    pub async fn run_batch(&self, max_items: Option<usize>) -> anyhow::Result<BatchSummary> {
+        let mut source = self
+            .open_message_source()
+            .context("Batch mode requires a file or in-memory message source")?;
+        let messages = take_messages(source.as_mut(), max_items).await;
+        info!(
+            num_messages = messages.len(),
+            "[NFT Metadata Crawler] Parsing batch"
+        );
+
+        let work_queue = Arc::new(self.clone()).spawn_work_queue();
+        let mut receivers = Vec::with_capacity(messages.len());
+        for mut message in messages {
+            let (done_sender, done_receiver) = oneshot::channel();
+            message.done = Some(done_sender);
+            work_queue.push(message).await?;
+            receivers.push(done_receiver);
+        }
+
+        // A dropped sender means that parsing panicked
+        let mut summary = BatchSummary::default();
+        for receiver in receivers {
+            summary.record(receiver.await.unwrap_or(ParseOutcome::Failed));
         }
+        Ok(summary)
     }
 
     /// Repeatedly pulls workers from Channel and perform parsing operations
-    async fn spawn_parser(&self, msg_base64: Bytes) {
+    async fn spawn_parser(&self, msg_base64: Bytes) -> ParseOutcome {
         PARSER_INVOCATIONS_COUNT.inc();
         let pubsub_message = String::from_utf8(msg_base64.to_vec())
             .unwrap_or_else(|e| {
@@ -96,7 +267,7 @@ impl ParserContext {
                 "[NFT Metadata Crawler] Number of commans != 5, skipping message"
             );
             SKIP_URI_COUNT.with_label_values(&["invalid"]).inc();
-            return;
+            return ParseOutcome::Skipped;
         }
 
         // Parse PubSub message
@@ -167,29 +338,89 @@ impl ParserContext {
             "[NFT Metadata Crawler] Starting worker"
         );
 
-        if let Err(e) = worker.parse().await {
+        let asset_uri = parts[1];
+        let outcome = if let Err((kind, e)) =
+            process_with_timeout(self.parser_config.per_item_timeout_secs, worker.parse()).await
+        {
+            if let Some(webhook) = &self.webhook {
+                webhook.notify(AssetNotification::failed(asset_uri, kind));
+            }
             warn!(
                 pubsub_message = pubsub_message,
                 error = ?e,
+                failure_kind = kind.as_str(),
                 "[NFT Metadata Crawler] Parsing failed"
             );
             PARSER_FAIL_COUNT.inc();
-        }
+            PARSER_FAILURE_KIND_COUNT
+                .with_label_values(&[kind.as_str()])
+                .inc();
+            ParseOutcome::Failed
+        } else {
+            if let Some(webhook) = &self.webhook {
+                webhook.notify(AssetNotification::processed(asset_uri, worker.stored_uri()));
+            }
+            ParseOutcome::Parsed
+        };
 
         info!(
             pubsub_message = pubsub_message,
             "[NFT Metadata Crawler] Worker finished"
         );
+        outcome
     }
 }
 
 impl Server for ParserContext {
     fn build_router(&self) -> Router {
         let self_arc = Arc::new(self.clone());
+
+        // Entries are parsed by a fixed number of workers, so that producers are blocked instead
+        // of spawning unbounded parsing tasks when parsing falls behind
+        let work_queue = self_arc.spawn_work_queue();
+
+        // Pubsub pushes entries to the router, which feeds them to its source. Entries from
+        // other sources are pulled in the background, and nothing is pushed to the router.
+        let (pubsub_sender, mut source) = match &self.parser_config.message_source {
+            MessageSourceConfig::Pubsub => {
+                let (sender, source) =
+                    PubsubMessageSource::new(self.parser_config.parser_queue_capacity);
+                (Some(sender), Box::new(source) as Box<dyn MessageSource>)
+            },
+            message_source => {
+                let source = self.open_message_source().unwrap_or_else(|e| {
+                    error!(
+                        message_source = ?message_source,
+                        error = ?e,
+                        "[NFT Metadata Crawler] Failed to open message source"
+                    );
+                    panic!();
+                });
+                (None, source)
+            },
+        };
+        tokio::spawn(async move {
+            Self::run_from_source(source.as_mut(), &work_queue).await;
+        });
+
+        let Some(pubsub_sender) = pubsub_sender else {
+            return Router::new();
+        };
         Router::new().route(
             "/",
             post(|bytes| async move {
-                self_arc.spawn_parser(bytes).await;
+                // Only respond once the entry is parsed, so that it is not acked before
+                let (done_sender, done_receiver) = oneshot::channel();
+                let message = SourceMessage {
+                    payload: bytes,
+                    done: Some(done_sender),
+                };
+                if pubsub_sender.send(message).await.is_err() || done_receiver.await.is_err() {
+                    return Response::builder()
+                        .status(StatusCode::INTERNAL_SERVER_ERROR)
+                        .body("".to_string())
+                        .unwrap();
+                }
 
                 if !self_arc.parser_config.ack_parsed_uris {
                     return Response::builder()
@@ -207,3 +438,62 @@ impl Server for ParserContext {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::parsed_asset_uris_query::ParsedAssetUrisQuery,
+        utils::{database::setup_test_database, gcs::mock_gcs_client},
+    };
+    use httpmock::MockServer;
+    use serde_json::json;
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database at NFT_METADATA_CRAWLER_DATABASE_URL"]
+    async fn test_run_batch_from_in_memory_source() {
+        let (pool, _guard) = setup_test_database().await;
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.path_contains("/metadata/");
+            then.status(200).json_body(json!({ "name": "token" }));
+        });
+        let gcs_server = MockServer::start();
+        let (gcs_client, gcs_upload) = mock_gcs_client(&gcs_server);
+
+        let parser_config = ParserConfig::for_test(json!({
+            "message_source": { "type": "in_memory", "capacity": 2 },
+            "store_assets": false,
+        }));
+        let context = ParserContext::new_with_gcs_client(parser_config, pool.clone(), gcs_client);
+
+        let asset_uris = [
+            server.url("/metadata/1.json"),
+            server.url("/metadata/2.json"),
+        ];
+        let sender = context.message_sender().unwrap();
+        for (i, asset_uri) in asset_uris.iter().enumerate() {
+            let message = format!(
+                "0x{},{},{},2024-01-01 00:00:00 UTC,1,false",
+                i, asset_uri, i
+            );
+            sender.send(Bytes::from(message)).await.unwrap();
+        }
+
+        let summary = context.run_batch(Some(asset_uris.len())).await.unwrap();
+        assert_eq!(summary, BatchSummary {
+            parsed: 2,
+            skipped: 0,
+            failed: 0,
+        });
+        gcs_upload.assert_hits(2);
+        let mut conn = pool.get().unwrap();
+        for asset_uri in &asset_uris {
+            let parsed_asset_uri = ParsedAssetUrisQuery::get_by_asset_uri(&mut conn, asset_uri);
+            assert!(parsed_asset_uri.unwrap().cdn_json_uri.is_some());
+        }
+
+        // The source is handed out once, so it cannot be parsed again
+        assert!(context.run_batch(None).await.is_err());
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_metrics_core::IntGauge;
+use std::{future::Future, sync::Arc};
+use tokio::sync::{mpsc, Mutex};
+use tracing::error;
+
+/// Bounded queue of items handled by a fixed number of workers. Pushing to a full queue waits
+/// until a worker takes an item, so producers are slowed down to the pace of the workers instead
+/// of accumulating items in memory.
+pub struct WorkQueue<T> {
+    sender: mpsc::Sender<T>,
+    depth: IntGauge,
+}
+
+impl<T: Send + 'static> WorkQueue<T> {
+    /// Spawns `num_workers` workers which handle the items pushed to the queue. At most `capacity`
+    /// items wait in the queue, and `depth` tracks the number of waiting items.
+    pub fn spawn<F, Fut>(num_workers: usize, capacity: usize, depth: IntGauge, handle: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel(capacity.max(1));
+        let receiver = Arc::new(Mutex::new(receiver));
+        let handle = Arc::new(handle);
+        for _ in 0..num_workers.max(1) {
+            let receiver = receiver.clone();
+            let handle = handle.clone();
+            let depth = depth.clone();
+            tokio::spawn(async move {
+                loop {
+                    let Some(item) = receiver.lock().await.recv().await else {
+                        break;
+                    };
+                    depth.dec();
+
+                    // Handle each item in its own task, so that a panic does not stop the worker
+                    if let Err(e) = tokio::spawn(handle(item)).await {
+                        error!(error = ?e, "[NFT Metadata Crawler] Worker failed to handle item");
+                    }
+                }
+            });
+        }
+
+        Self { sender, depth }
+    }
+
+    /// Adds the item to the queue, waiting while the queue is full
+    pub async fn push(&self, item: T) -> anyhow::Result<()> {
+        self.depth.inc();
+        if self.sender.send(item).await.is_err() {
+            self.depth.dec();
+            anyhow::bail!("All workers have stopped");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::sync::Semaphore;
+
+    #[tokio::test]
+    async fn test_slow_worker_blocks_producer() {
+        let depth = IntGauge::new("test_work_queue_depth", "Test work queue depth").unwrap();
+        let (started_sender, mut started_receiver) = mpsc::unbounded_channel();
+        let release = Arc::new(Semaphore::new(0));
+
+        let queue = {
+            let release = release.clone();
+            WorkQueue::spawn(1, 2, depth.clone(), move |item: u64| {
+                let started_sender = started_sender.clone();
+                let release = release.clone();
+                async move {
+                    started_sender.send(item).unwrap();
+                    release.acquire().await.unwrap().forget();
+                }
+            })
+        };
+
+        // The only worker takes the first item and is stuck handling it
+        queue.push(0).await.unwrap();
+        assert_eq!(started_receiver.recv().await, Some(0));
+
+        // The next items fill the queue, after which the producer has to wait
+        queue.push(1).await.unwrap();
+        queue.push(2).await.unwrap();
+        assert_eq!(depth.get(), 2);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), queue.push(3))
+                .await
+                .is_err()
+        );
+        assert_eq!(depth.get(), 2);
+
+        // Once the worker makes progress, the producer can push again
+        release.add_permits(1);
+        assert_eq!(started_receiver.recv().await, Some(1));
+        queue.push(3).await.unwrap();
+        assert_eq!(depth.get(), 2);
+
+        release.add_permits(3);
+        for expected in 2..4 {
+            assert_eq!(started_receiver.recv().await, Some(expected));
+        }
+    }
+}
@@ -11,9 +11,10 @@ use crate::{
             PARSE_URI_TYPE_COUNT, SKIP_URI_COUNT,
         },
         database::upsert_uris,
+        failure::DeniedHostError,
         gcs::{write_image_to_gcs, write_json_to_gcs},
         image_optimizer::ImageOptimizer,
-        json_parser::JSONParser,
+        json_parser::{CacheValidators, JSONParseResult, JSONParser},
         uri_parser::URIParser,
     },
 };
@@ -74,6 +75,13 @@ impl Worker {
         worker
     }
 
+    /// Returns the URI the parsed asset is stored at, preferring the image over the JSON
+    pub fn stored_uri(&self) -> Option<String> {
+        self.model
+            .get_cdn_image_uri()
+            .or_else(|| self.model.get_cdn_json_uri())
+    }
+
     /// Main parsing flow
     pub async fn parse(&mut self) -> anyhow::Result<()> {
         // Deduplicate asset_uri
@@ -107,6 +115,12 @@ impl Worker {
             return Ok(());
         }
 
+        // On forced reparses, revalidate previously stored JSON with a conditional request if
+        // enabled, so that unchanged JSON is not downloaded again
+        let revalidate = self.parser_config.enable_conditional_get
+            && self.force
+            && self.model.get_cdn_json_uri().is_some();
+
         if self.force || self.model.get_cdn_json_uri().is_none() {
             // Parse asset_uri
             self.log_info("Parsing asset_uri");
@@ -121,20 +135,55 @@ impl Worker {
                 self.model.get_asset_uri()
             });
 
+            let validators = revalidate.then(|| self.model.get_json_cache_validators());
+
             // Parse JSON for raw_image_uri and raw_animation_uri
+            self.check_denied_host(&json_uri)?;
             self.log_info("Starting JSON parsing");
-            let (raw_image_uri, raw_animation_uri, json) =
-                JSONParser::parse(json_uri, self.parser_config.max_file_size_bytes)
-                    .await
-                    .unwrap_or_else(|e| {
-                        // Increment retry count if JSON parsing fails
-                        self.log_warn("JSON parsing failed", Some(&e));
-                        self.model.increment_json_parser_retry_count();
-                        (None, None, Value::Null)
-                    });
+            let parse_result = JSONParser::parse(
+                json_uri,
+                self.parser_config.max_file_size_bytes,
+                validators.as_ref(),
+            )
+            .await
+            .unwrap_or_else(|e| {
+                // Increment retry count if JSON parsing fails
+                self.log_warn("JSON parsing failed", Some(&e));
+                self.model.increment_json_parser_retry_count();
+                JSONParseResult::Parsed {
+                    raw_image_uri: None,
+                    raw_animation_uri: None,
+                    json: Value::Null,
+                    validators: CacheValidators::default(),
+                }
+            });
+
+            let (raw_image_uri, raw_animation_uri, json, validators) = match parse_result {
+                JSONParseResult::NotModified => {
+                    // JSON is unchanged, reuse stored JSON
+                    self.log_info("JSON not modified, reusing stored JSON");
+                    SKIP_URI_COUNT.with_label_values(&["not_modified"]).inc();
+                    return self.parse_media().await;
+                },
+                JSONParseResult::Parsed {
+                    raw_image_uri,
+                    raw_animation_uri,
+                    json,
+                    validators,
+                } => (raw_image_uri, raw_animation_uri, json, validators),
+            };
+
+            // If the JSON points to new media, drop the stored media so that it is optimized again
+            if raw_image_uri != self.model.get_raw_image_uri() {
+                self.model.set_cdn_image_uri(None);
+            }
+            if raw_animation_uri != self.model.get_raw_animation_uri() {
+                self.model.set_cdn_animation_uri(None);
+            }
 
             self.model.set_raw_image_uri(raw_image_uri);
             self.model.set_raw_animation_uri(raw_animation_uri);
+            self.model.set_json_cache_validators(validators);
 
             // Save parsed JSON to GCS
             if json != Value::Null {
@@ -165,6 +214,21 @@ impl Worker {
             self.upsert();
         }
 
+        self.parse_media().await
+    }
+
+    /// Optimizes and uploads the image and animation of the asset
+    async fn parse_media(&mut self) -> anyhow::Result<()> {
+        if !self.parser_config.store_assets {
+            self.log_info("Storing assets is disabled, skipping image and animation");
+            SKIP_URI_COUNT
+                .with_label_values(&["assets_not_stored"])
+                .inc();
+            self.check_retry_count();
+            PARSER_SUCCESSES_COUNT.inc();
+            return Ok(());
+        }
+
         // Should I optimize image?
         // if force: true
         // else if cdn_image_uri already exists: false
@@ -223,6 +287,7 @@ impl Worker {
             });
 
             // Resize and optimize image
+            self.check_denied_host(&img_uri)?;
             self.log_info("Starting image optimization");
             OPTIMIZE_IMAGE_TYPE_COUNT
                 .with_label_values(&["image"])
@@ -316,6 +381,7 @@ impl Worker {
             });
 
             // Resize and optimize animation
+            self.check_denied_host(&animation_uri)?;
             self.log_info("Starting animation optimization");
             OPTIMIZE_IMAGE_TYPE_COUNT
                 .with_label_values(&["animation"])
@@ -361,6 +427,13 @@ impl Worker {
             self.upsert();
         }
 
+        self.check_retry_count();
+        PARSER_SUCCESSES_COUNT.inc();
+        Ok(())
+    }
+
+    /// Marks the asset as do_not_parse if any step has been retried too many times
+    fn check_retry_count(&mut self) {
         if self.model.get_json_parser_retry_count() >= self.max_num_retries
             || self.model.get_image_optimizer_retry_count() >= self.max_num_retries
             || self.model.get_animation_optimizer_retry_count() >= self.max_num_retries
@@ -369,9 +442,6 @@ impl Worker {
             self.model.set_do_not_parse(true);
             self.upsert();
         }
-
-        PARSER_SUCCESSES_COUNT.inc();
-        Ok(())
     }
 
     fn upsert(&mut self) {
@@ -390,6 +460,17 @@ impl Worker {
             .any(|blacklist_uri| uri.contains(blacklist_uri))
     }
 
+    /// Returns an error without fetching the URI if its host is denied
+    fn check_denied_host(&self, uri: &str) -> anyhow::Result<()> {
+        match URIParser::denied_host(uri, &self.parser_config.denied_hosts) {
+            Some(host) => {
+                self.log_info(&format!("Host {} is denied, skipping fetch", host));
+                Err(DeniedHostError { host }.into())
+            },
+            None => Ok(()),
+        }
+    }
+
     fn log_info(&self, message: &str) {
         info!(
             pubsub_message = self.pubsub_message,
@@ -428,3 +509,65 @@ impl Worker {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{database::setup_test_database, gcs::mock_gcs_client};
+    use httpmock::MockServer;
+    use serde_json::json;
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database at NFT_METADATA_CRAWLER_DATABASE_URL"]
+    async fn test_store_assets_disabled_only_stores_metadata() {
+        let (pool, _guard) = setup_test_database().await;
+        let server = MockServer::start();
+        let asset_uri = server.url("/metadata.json");
+        let raw_image_uri = server.url("/image.png");
+        server.mock(|when, then| {
+            when.path("/metadata.json");
+            then.status(200)
+                .json_body(json!({ "name": "token", "image": raw_image_uri }));
+        });
+        let image = server.mock(|when, then| {
+            when.path("/image.png");
+            then.status(200)
+                .header("content-type", "image/png")
+                .body("image bytes");
+        });
+        let gcs_server = MockServer::start();
+        let (gcs_client, gcs_upload) = mock_gcs_client(&gcs_server);
+
+        let parser_config = ParserConfig::for_test(json!({ "store_assets": false }));
+        let mut worker = Worker::new(
+            Arc::new(parser_config.clone()),
+            pool.get().unwrap(),
+            parser_config.max_num_parse_retries,
+            Arc::new(gcs_client),
+            "",
+            "0x1",
+            &asset_uri,
+            1,
+            chrono::NaiveDateTime::default(),
+            false,
+        );
+        worker.parse().await.unwrap();
+
+        // Only the parsed JSON is uploaded, the image is neither fetched nor uploaded
+        image.assert_hits(0);
+        gcs_upload.assert_hits(1);
+        let parsed_asset_uri =
+            ParsedAssetUrisQuery::get_by_asset_uri(&mut pool.get().unwrap(), &asset_uri).unwrap();
+        assert_eq!(parsed_asset_uri.raw_image_uri, Some(raw_image_uri));
+        assert_eq!(
+            parsed_asset_uri.cdn_json_uri,
+            Some(format!(
+                "{}cdn/{}.json",
+                parser_config.cdn_prefix,
+                sha256::digest(asset_uri.as_str())
+            ))
+        );
+        assert_eq!(parsed_asset_uri.cdn_image_uri, None);
+        assert_eq!(parsed_asset_uri.cdn_animation_uri, None);
+    }
+}
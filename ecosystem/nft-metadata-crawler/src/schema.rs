@@ -18,6 +18,15 @@ pub mod nft_metadata_crawler {
         }
     }
 
+    diesel::table! {
+        nft_metadata_crawler.content_hashes (content_hash, hash_algorithm) {
+            content_hash -> Varchar,
+            cdn_image_uri -> Varchar,
+            inserted_at -> Timestamp,
+            hash_algorithm -> Varchar,
+        }
+    }
+
     diesel::table! {
         nft_metadata_crawler.ledger_infos (chain_id) {
             chain_id -> Int8,
@@ -38,11 +47,14 @@ pub mod nft_metadata_crawler {
             inserted_at -> Timestamp,
             do_not_parse -> Bool,
             last_transaction_version -> Int8,
+            json_etag -> Nullable<Varchar>,
+            json_last_modified -> Nullable<Varchar>,
         }
     }
 
     diesel::allow_tables_to_appear_in_same_query!(
         asset_uploader_request_statuses,
+        content_hashes,
         ledger_infos,
         parsed_asset_uris,
     );
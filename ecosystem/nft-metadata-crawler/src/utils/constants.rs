@@ -16,6 +16,18 @@ pub const MAX_IMAGE_REQUEST_RETRY_SECONDS: u64 = 90;
 /// Allocate 180 seconds for uploading large image files
 pub const MAX_ASSET_UPLOAD_RETRY_SECONDS: u64 = 180;
 
+/// Allocate 10 seconds for delivering a webhook notification
+pub const MAX_WEBHOOK_REQUEST_SECONDS: u64 = 10;
+
+/// Allocate 10 minutes for processing a single item end to end
+pub const DEFAULT_PER_ITEM_TIMEOUT_SECONDS: u64 = 600;
+
+/// Default number of entries parsed concurrently
+pub const DEFAULT_NUM_PARSER_WORKERS: usize = 10;
+
+/// Default number of entries waiting to be parsed before producers are blocked
+pub const DEFAULT_PARSER_QUEUE_CAPACITY: usize = 100;
+
 /// Max number of retries for a given asset_uri
 pub const DEFAULT_MAX_NUM_PARSE_RETRIES: i32 = 3;
 
@@ -2,12 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use aptos_metrics_core::{
-    register_int_counter, register_int_counter_vec, IntCounter, IntCounterVec,
+    register_int_counter, register_int_counter_vec, register_int_gauge, IntCounter, IntCounterVec,
+    IntGauge,
 };
 use once_cell::sync::Lazy;
 
 // OVERALL METRICS
 
+/// Number of entries waiting to be parsed
+pub static PARSER_QUEUE_DEPTH: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "nft_metadata_crawler_parser_queue_depth",
+        "Number of entries waiting to be parsed"
+    )
+    .unwrap()
+});
+
 /// Number of times the NFT Metadata Crawler Parser has been invoked
 pub static PARSER_INVOCATIONS_COUNT: Lazy<IntCounter> = Lazy::new(|| {
     register_int_counter!(
@@ -35,6 +45,16 @@ pub static PARSER_FAIL_COUNT: Lazy<IntCounter> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Number of times the NFT Metadata Crawler Parser has failed, by failure kind
+pub static PARSER_FAILURE_KIND_COUNT: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "nft_metadata_crawler_parser_failure_kind_count",
+        "Number of times the parser has failed, by failure kind",
+        &["kind"]
+    )
+    .unwrap()
+});
+
 /// Number of times the NFT Metadata Crawler Parser has received a URI marked as not to parse
 pub static DO_NOT_PARSE_COUNT: Lazy<IntCounter> = Lazy::new(|| {
     register_int_counter!(
@@ -165,6 +185,15 @@ pub static FAILED_TO_PARSE_JSON_COUNT: Lazy<IntCounterVec> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Number of times a conditional JSON request returned 304 Not Modified
+pub static JSON_NOT_MODIFIED_COUNT: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "nft_metadata_crawler_parser_json_not_modified_count",
+        "Number of times a conditional JSON request returned 304 Not Modified"
+    )
+    .unwrap()
+});
+
 // IMAGE OPTIMIZER METRICS
 
 /// Number of times the NFT Metadata Crawler Parser has invocated the Image Optimizer for an image
@@ -233,3 +262,27 @@ pub static FAILED_TO_UPLOAD_TO_GCS_COUNT: Lazy<IntCounter> = Lazy::new(|| {
     )
     .unwrap()
 });
+
+// ASSET UPLOADER THROTTLER METRICS
+
+/// Number of times the Asset Uploader Throttler has failed to upload an asset, by failure kind
+pub static ASSET_UPLOAD_FAILURE_KIND_COUNT: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "nft_metadata_crawler_asset_upload_failure_kind_count",
+        "Number of times the asset uploader throttler has failed to upload an asset, by failure kind",
+        &["kind"]
+    )
+    .unwrap()
+});
+
+// WEBHOOK METRICS
+
+/// Number of webhook notifications, by whether they were delivered
+pub static WEBHOOK_NOTIFICATION_COUNT: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "nft_metadata_crawler_webhook_notification_count",
+        "Number of webhook notifications, by whether they were delivered",
+        &["result"]
+    )
+    .unwrap()
+});
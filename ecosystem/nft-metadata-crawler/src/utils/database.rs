@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    models::{ledger_info::LedgerInfo, parsed_asset_uris::ParsedAssetUris},
+    models::{
+        content_hashes::ContentHashes, ledger_info::LedgerInfo, parsed_asset_uris::ParsedAssetUris,
+    },
     schema,
 };
 use anyhow::Context;
@@ -32,6 +34,36 @@ pub fn run_migrations(pool: &Pool<ConnectionManager<PgConnection>>) {
         .expect("[NFT Metadata Crawler] migrations failed!");
 }
 
+/// Returns a connection pool to a freshly migrated test database, together with a guard that
+/// serializes tests sharing the database. Panics unless `NFT_METADATA_CRAWLER_DATABASE_URL` is
+/// set, so tests using it are ignored by default and run with `cargo test -- --ignored`.
+#[cfg(test)]
+pub async fn setup_test_database() -> (
+    Pool<ConnectionManager<PgConnection>>,
+    tokio::sync::MutexGuard<'static, ()>,
+) {
+    static TEST_DATABASE_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+    let database_url = std::env::var("NFT_METADATA_CRAWLER_DATABASE_URL")
+        .expect("NFT_METADATA_CRAWLER_DATABASE_URL must be set to run database tests");
+    let guard = TEST_DATABASE_LOCK.lock().await;
+
+    let pool = establish_connection_pool(&database_url);
+    let mut conn = pool.get().expect("Failed to connect to test database");
+    for command in [
+        "DROP SCHEMA IF EXISTS nft_metadata_crawler CASCADE",
+        "DROP TABLE IF EXISTS __diesel_schema_migrations",
+    ] {
+        diesel::sql_query(command)
+            .execute(&mut conn)
+            .expect("Failed to wipe test database");
+    }
+    drop(conn);
+    run_migrations(&pool);
+
+    (pool, guard)
+}
+
 /// Upserts URIs into database
 pub fn upsert_uris(
     conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
@@ -56,6 +88,8 @@ pub fn upsert_uris(
             inserted_at.eq(excluded(inserted_at)),
             do_not_parse.eq(excluded(do_not_parse)),
             last_transaction_version.eq(ltv),
+            json_etag.eq(excluded(json_etag)),
+            json_last_modified.eq(excluded(json_last_modified)),
         ));
 
     let debug_query = diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string();
@@ -63,6 +97,23 @@ pub fn upsert_uris(
     query.execute(conn).context(debug_query)
 }
 
+/// Inserts the CDN URI for an asset's content hash into database, keeping the existing entry on conflict
+pub fn insert_content_hash(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    entry: &ContentHashes,
+) -> anyhow::Result<usize> {
+    use schema::nft_metadata_crawler::content_hashes::dsl::*;
+
+    let query = diesel::insert_into(schema::nft_metadata_crawler::content_hashes::table)
+        .values(entry)
+        .on_conflict((content_hash, hash_algorithm))
+        .do_nothing();
+
+    let debug_query = diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string();
+    debug!("Executing Query: {}", debug_query);
+    query.execute(conn).context(debug_query)
+}
+
 /// Verify the chain id from PubSub against the database.
 pub fn check_or_update_chain_id(
     conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
@@ -0,0 +1,102 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+use std::{fmt, future::Future, time::Duration};
+
+/// Kind of failure recorded when processing a single item fails
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureKind {
+    /// Processing returned an error
+    Error,
+    /// Processing did not finish within the configured per-item timeout
+    Timeout,
+    /// Processing was stopped because a URI points to a denied host
+    Denied,
+}
+
+impl FailureKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FailureKind::Error => "error",
+            FailureKind::Timeout => "timeout",
+            FailureKind::Denied => "denied",
+        }
+    }
+}
+
+/// Error returned when a URI to fetch points to a host on the denylist
+#[derive(Debug)]
+pub struct DeniedHostError {
+    pub host: String,
+}
+
+impl fmt::Display for DeniedHostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Host {} is denied", self.host)
+    }
+}
+
+impl std::error::Error for DeniedHostError {}
+
+/// Processes a single item, cutting it off if it takes longer than `per_item_timeout_secs`.
+/// This frees the worker even if a fetch hangs indefinitely.
+pub async fn process_with_timeout<T>(
+    per_item_timeout_secs: u64,
+    process: impl Future<Output = anyhow::Result<T>>,
+) -> Result<T, (FailureKind, anyhow::Error)> {
+    match tokio::time::timeout(Duration::from_secs(per_item_timeout_secs), process).await {
+        Ok(Ok(res)) => Ok(res),
+        Ok(Err(e)) if e.is::<DeniedHostError>() => Err((FailureKind::Denied, e)),
+        Ok(Err(e)) => Err((FailureKind::Error, e)),
+        Err(_) => Err((
+            FailureKind::Timeout,
+            anyhow::anyhow!(
+                "Processing timed out after {} seconds",
+                per_item_timeout_secs
+            ),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[tokio::test]
+    async fn test_slow_item_is_cut_off_at_deadline() {
+        let start = Instant::now();
+        let res = process_with_timeout(1, async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            anyhow::Ok(())
+        })
+        .await;
+
+        let (kind, _) = res.unwrap_err();
+        assert_eq!(kind, FailureKind::Timeout);
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_secs(1) && elapsed < Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn test_fast_item_is_not_cut_off() {
+        let res = process_with_timeout(10, async { anyhow::Ok(1) }).await;
+        assert_eq!(res.unwrap(), 1);
+
+        let res = process_with_timeout(10, async { Err::<(), _>(anyhow::anyhow!("Failed")) }).await;
+        assert_eq!(res.unwrap_err().0, FailureKind::Error);
+    }
+
+    #[tokio::test]
+    async fn test_denied_host_is_recorded_as_denied() {
+        let res = process_with_timeout(10, async {
+            Err::<(), _>(anyhow::Error::new(DeniedHostError {
+                host: "example.com".to_string(),
+            }))
+        })
+        .await;
+        assert_eq!(res.unwrap_err().0, FailureKind::Denied);
+    }
+}
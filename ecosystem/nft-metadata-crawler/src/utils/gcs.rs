@@ -131,3 +131,49 @@ pub async fn write_image_to_gcs(
         },
     }
 }
+
+/// Returns a client uploading to the mock server, and the mock accepting every upload
+#[cfg(test)]
+pub fn mock_gcs_client(server: &httpmock::MockServer) -> (Client, httpmock::Mock<'_>) {
+    use google_cloud_storage::client::ClientConfig;
+    use google_cloud_token::{TokenSource, TokenSourceProvider};
+    use std::sync::Arc;
+
+    #[derive(Debug)]
+    struct TestTokenSource;
+
+    #[async_trait::async_trait]
+    impl TokenSource for TestTokenSource {
+        async fn token(&self) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+            Ok("Bearer test".to_string())
+        }
+    }
+
+    impl TokenSourceProvider for TestTokenSource {
+        fn token_source(&self) -> Arc<dyn TokenSource> {
+            Arc::new(TestTokenSource)
+        }
+    }
+
+    let upload = server.mock(|when, then| {
+        when.method("POST").path_contains("/upload/storage/v1/b/");
+        // Only the fields required to deserialize the uploaded object
+        then.status(200).json_body(serde_json::json!({
+            "selfLink": "",
+            "mediaLink": "",
+            "etag": "",
+            "id": "",
+            "name": "",
+            "bucket": "",
+            "metageneration": "1",
+            "size": "0",
+            "generation": "1",
+        }));
+    });
+    let client = Client::new(ClientConfig {
+        storage_endpoint: server.base_url(),
+        token_source_provider: Box::new(TestTokenSource),
+        ..Default::default()
+    });
+    (client, upload)
+}
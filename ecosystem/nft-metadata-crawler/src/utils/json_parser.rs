@@ -6,7 +6,8 @@ use crate::{
     utils::{
         constants::{MAX_JSON_REQUEST_RETRY_SECONDS, MAX_RETRY_TIME_SECONDS},
         counters::{
-            FAILED_TO_PARSE_JSON_COUNT, PARSE_JSON_INVOCATION_COUNT, SUCCESSFULLY_PARSED_JSON_COUNT,
+            FAILED_TO_PARSE_JSON_COUNT, JSON_NOT_MODIFIED_COUNT, PARSE_JSON_INVOCATION_COUNT,
+            SUCCESSFULLY_PARSED_JSON_COUNT,
         },
     },
 };
@@ -14,20 +15,63 @@ use anyhow::Context;
 use backoff::{future::retry, ExponentialBackoff};
 use futures::FutureExt;
 use image::ImageFormat;
-use reqwest::Client;
+use reqwest::{header, Client, StatusCode};
 use serde_json::Value;
 use std::time::Duration;
 use tracing::info;
 
+/// Cache validators returned with a JSON, used to revalidate it with a conditional request.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CacheValidators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl CacheValidators {
+    fn from_headers(headers: &header::HeaderMap) -> Self {
+        let get = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|s| s.to_string())
+        };
+        Self {
+            etag: get(header::ETAG),
+            last_modified: get(header::LAST_MODIFIED),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+/// Result of parsing JSON from a URI.
+#[derive(Debug, PartialEq)]
+pub enum JSONParseResult {
+    /// Server responded with 304 Not Modified, the previously stored result can be reused.
+    NotModified,
+    /// JSON was fetched and parsed.
+    Parsed {
+        raw_image_uri: Option<String>,
+        raw_animation_uri: Option<String>,
+        json: Value,
+        validators: CacheValidators,
+    },
+}
+
 pub struct JSONParser;
 
 impl JSONParser {
     /// Parses JSON from input URI.
-    /// Returns the underlying raw image URI, raw animation URI, and JSON.
+    /// If validators from a previous fetch are provided, sends a conditional request and returns
+    /// [JSONParseResult::NotModified] if the JSON is unchanged.
+    /// Otherwise, returns the underlying raw image URI, raw animation URI, JSON, and validators.
     pub async fn parse(
         uri: String,
         max_file_size_bytes: u32,
-    ) -> anyhow::Result<(Option<String>, Option<String>, Value)> {
+        validators: Option<&CacheValidators>,
+    ) -> anyhow::Result<JSONParseResult> {
         PARSE_JSON_INVOCATION_COUNT.inc();
         let (mime, size) = get_uri_metadata(&uri).await?;
         if ImageFormat::from_mime_type(&mime).is_some() {
@@ -57,12 +101,22 @@ impl JSONParser {
                     .build()
                     .context("Failed to build reqwest client")?;
 
-                let response = client
-                    .get(uri.trim())
-                    .send()
-                    .await
-                    .context("Failed to get JSON")?;
+                let mut request = client.get(uri.trim());
+                if let Some(validators) = validators.filter(|v| !v.is_empty()) {
+                    if let Some(etag) = &validators.etag {
+                        request = request.header(header::IF_NONE_MATCH, etag);
+                    }
+                    if let Some(last_modified) = &validators.last_modified {
+                        request = request.header(header::IF_MODIFIED_SINCE, last_modified);
+                    }
+                }
+
+                let response = request.send().await.context("Failed to get JSON")?;
+                if response.status() == StatusCode::NOT_MODIFIED {
+                    return Ok(JSONParseResult::NotModified);
+                }
 
+                let new_validators = CacheValidators::from_headers(response.headers());
                 let parsed_json = response
                     .json::<Value>()
                     .await
@@ -72,7 +126,12 @@ impl JSONParser {
                 let raw_animation_uri =
                     parsed_json["animation_url"].as_str().map(|s| s.to_string());
 
-                Ok((raw_image_uri, raw_animation_uri, parsed_json))
+                Ok(JSONParseResult::Parsed {
+                    raw_image_uri,
+                    raw_animation_uri,
+                    json: parsed_json,
+                    validators: new_validators,
+                })
             }
             .boxed()
         };
@@ -83,6 +142,10 @@ impl JSONParser {
         };
 
         match retry(backoff, op).await {
+            Ok(JSONParseResult::NotModified) => {
+                JSON_NOT_MODIFIED_COUNT.inc();
+                Ok(JSONParseResult::NotModified)
+            },
             Ok(result) => {
                 SUCCESSFULLY_PARSED_JSON_COUNT.inc();
                 Ok(result)
@@ -96,3 +159,81 @@ impl JSONParser {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        http::{header, HeaderMap, StatusCode},
+        response::IntoResponse,
+        routing::get,
+        Json, Router,
+    };
+    use serde_json::json;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    use tokio::net::TcpListener;
+
+    const ETAG: &str = "\"v1\"";
+
+    /// Serves a JSON with an ETag, responding with 304 to matching conditional requests.
+    /// Returns the URI of the JSON and the number of full responses served.
+    async fn serve_json() -> (String, Arc<AtomicUsize>) {
+        let num_full_responses = Arc::new(AtomicUsize::new(0));
+        let counter = num_full_responses.clone();
+        let router = Router::new().route(
+            "/metadata.json",
+            get(move |headers: HeaderMap| async move {
+                if headers
+                    .get(header::IF_NONE_MATCH)
+                    .is_some_and(|value| value == ETAG)
+                {
+                    return StatusCode::NOT_MODIFIED.into_response();
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+                (
+                    [(header::ETAG, ETAG)],
+                    Json(json!({ "image": "https://example.com/image.png" })),
+                )
+                    .into_response()
+            }),
+        );
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await });
+        (format!("http://{}/metadata.json", addr), num_full_responses)
+    }
+
+    #[tokio::test]
+    async fn test_not_modified_short_circuits_parsing() {
+        let (uri, num_full_responses) = serve_json().await;
+
+        let validators = match JSONParser::parse(uri.clone(), u32::MAX, None)
+            .await
+            .unwrap()
+        {
+            JSONParseResult::Parsed {
+                raw_image_uri,
+                validators,
+                ..
+            } => {
+                assert_eq!(
+                    raw_image_uri.as_deref(),
+                    Some("https://example.com/image.png")
+                );
+                validators
+            },
+            JSONParseResult::NotModified => panic!("First fetch must not be conditional"),
+        };
+        assert_eq!(validators.etag.as_deref(), Some(ETAG));
+
+        let result = JSONParser::parse(uri, u32::MAX, Some(&validators))
+            .await
+            .unwrap();
+        assert_eq!(result, JSONParseResult::NotModified);
+        assert_eq!(num_full_responses.load(Ordering::SeqCst), 1);
+    }
+}
@@ -4,7 +4,9 @@
 pub mod constants;
 pub mod counters;
 pub mod database;
+pub mod failure;
 pub mod gcs;
 pub mod image_optimizer;
 pub mod json_parser;
 pub mod uri_parser;
+pub mod webhook;
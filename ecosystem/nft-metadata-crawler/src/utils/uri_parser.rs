@@ -55,6 +55,20 @@ impl URIParser {
         Err(anyhow::anyhow!("Invalid IPFS URI"))
     }
 
+    /// Returns the host of the URI if it is denied. An entry denies the host if it is equal to
+    /// it, and an entry starting with `.`, e.g., `.example.com`, also denies all its subdomains.
+    pub fn denied_host(uri: &str, denied_hosts: &[String]) -> Option<String> {
+        let host = Url::parse(uri).ok()?.host_str()?.to_ascii_lowercase();
+        denied_hosts
+            .iter()
+            .map(|denied| denied.to_ascii_lowercase())
+            .any(|denied| match denied.strip_prefix('.') {
+                Some(domain) => host == domain || host.ends_with(&denied),
+                None => host == denied,
+            })
+            .then_some(host)
+    }
+
     /// Formats a capture group into a URI.
     fn format_capture(
         captures: Captures<'_>,
@@ -84,6 +98,34 @@ mod tests {
     const CID: &str = "testcid";
     const PATH: &str = "testpath";
 
+    #[test]
+    fn test_denied_host() {
+        let denied_hosts = vec!["bad.com".to_string(), ".example.com".to_string()];
+        assert_eq!(
+            URIParser::denied_host("https://bad.com/1.json", &denied_hosts),
+            Some("bad.com".to_string())
+        );
+        assert_eq!(
+            URIParser::denied_host("https://cdn.Example.com/1.png", &denied_hosts),
+            Some("cdn.example.com".to_string())
+        );
+        assert_eq!(
+            URIParser::denied_host("https://example.com/1.png", &denied_hosts),
+            Some("example.com".to_string())
+        );
+
+        // Entries without a leading `.` only match exactly
+        assert_eq!(
+            URIParser::denied_host("https://sub.bad.com/1.json", &denied_hosts),
+            None
+        );
+        assert_eq!(
+            URIParser::denied_host("https://notexample.com/1.json", &denied_hosts),
+            None
+        );
+        assert_eq!(URIParser::denied_host("not a uri", &denied_hosts), None);
+    }
+
     #[test]
     fn test_parse_ipfs_uri() {
         let test_ipfs_uri = format!("ipfs://{}/{}", CID, PATH);
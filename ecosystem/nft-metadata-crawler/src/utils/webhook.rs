@@ -0,0 +1,199 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::utils::{
+    constants::{MAX_RETRY_TIME_SECONDS, MAX_WEBHOOK_REQUEST_SECONDS},
+    counters::WEBHOOK_NOTIFICATION_COUNT,
+    failure::FailureKind,
+};
+use anyhow::Context;
+use backoff::{future::retry, ExponentialBackoff};
+use futures::FutureExt;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::warn;
+
+/// Outcome of processing an asset
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetStatus {
+    Processed,
+    Failed,
+}
+
+/// JSON payload posted to the webhook once an asset finishes processing
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct AssetNotification {
+    pub uri: String,
+    pub status: AssetStatus,
+    /// URI the processed asset is stored at, if any
+    pub stored_uri: Option<String>,
+    pub error_kind: Option<FailureKind>,
+}
+
+impl AssetNotification {
+    pub fn processed(uri: &str, stored_uri: Option<String>) -> Self {
+        Self {
+            uri: uri.to_string(),
+            status: AssetStatus::Processed,
+            stored_uri,
+            error_kind: None,
+        }
+    }
+
+    pub fn failed(uri: &str, error_kind: FailureKind) -> Self {
+        Self {
+            uri: uri.to_string(),
+            status: AssetStatus::Failed,
+            stored_uri: None,
+            error_kind: Some(error_kind),
+        }
+    }
+}
+
+/// Posts asset notifications to a webhook
+#[derive(Clone)]
+pub struct WebhookNotifier {
+    client: Client,
+    url: String,
+    notify_on_failure: bool,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: String, notify_on_failure: bool) -> Self {
+        Self {
+            client: Client::new(),
+            url,
+            notify_on_failure,
+        }
+    }
+
+    /// Delivers the notification in the background, so that a slow or unavailable webhook never
+    /// blocks processing. Failures are only notified if configured.
+    pub fn notify(&self, notification: AssetNotification) {
+        if notification.status == AssetStatus::Failed && !self.notify_on_failure {
+            return;
+        }
+        let self_clone = self.clone();
+        tokio::spawn(async move {
+            let _ = self_clone.deliver(&notification).await;
+        });
+    }
+
+    /// Posts the notification, retrying with exponential backoff. Failures to deliver are logged
+    /// and counted.
+    pub async fn deliver(&self, notification: &AssetNotification) -> anyhow::Result<()> {
+        let op = || {
+            async {
+                self.client
+                    .post(&self.url)
+                    .timeout(Duration::from_secs(MAX_WEBHOOK_REQUEST_SECONDS))
+                    .json(notification)
+                    .send()
+                    .await
+                    .context("Failed to send webhook notification")?
+                    .error_for_status()
+                    .context("Webhook rejected notification")?;
+                Ok(())
+            }
+            .boxed()
+        };
+
+        let backoff = ExponentialBackoff {
+            max_elapsed_time: Some(Duration::from_secs(MAX_RETRY_TIME_SECONDS)),
+            ..Default::default()
+        };
+
+        let res = retry(backoff, op).await;
+        match &res {
+            Ok(()) => WEBHOOK_NOTIFICATION_COUNT
+                .with_label_values(&["delivered"])
+                .inc(),
+            Err(e) => {
+                warn!(
+                    asset_uri = notification.uri,
+                    error = ?e,
+                    "[NFT Metadata Crawler] Failed to deliver webhook notification"
+                );
+                WEBHOOK_NOTIFICATION_COUNT
+                    .with_label_values(&["failed"])
+                    .inc();
+            },
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::StatusCode, routing::post, Json, Router};
+    use serde_json::{json, Value};
+    use tokio::{net::TcpListener, sync::mpsc};
+
+    /// Serves a webhook forwarding every received notification to the returned channel
+    async fn serve_webhook(status: StatusCode) -> (String, mpsc::UnboundedReceiver<Value>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let router = Router::new().route(
+            "/webhook",
+            post(move |Json(body): Json<Value>| async move {
+                sender.send(body).unwrap();
+                status
+            }),
+        );
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await });
+        (format!("http://{}/webhook", addr), receiver)
+    }
+
+    #[tokio::test]
+    async fn test_webhook_receives_notification() {
+        let (url, mut receiver) = serve_webhook(StatusCode::OK).await;
+        let notifier = WebhookNotifier::new(url, false);
+
+        notifier.notify(AssetNotification::processed(
+            "https://example.com/1.json",
+            Some("https://cdn.example.com/1.png".to_string()),
+        ));
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            json!({
+                "uri": "https://example.com/1.json",
+                "status": "processed",
+                "stored_uri": "https://cdn.example.com/1.png",
+                "error_kind": null,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failures_are_only_notified_if_configured() {
+        let (url, mut receiver) = serve_webhook(StatusCode::OK).await;
+        let failure = AssetNotification::failed("https://example.com/1.json", FailureKind::Timeout);
+
+        WebhookNotifier::new(url.clone(), false).notify(failure.clone());
+        WebhookNotifier::new(url, true).notify(failure);
+        let body = receiver.recv().await.unwrap();
+        assert_eq!(body["status"], "failed");
+        assert_eq!(body["error_kind"], "timeout");
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_undelivered_notification_is_reported() {
+        let (url, mut receiver) = serve_webhook(StatusCode::INTERNAL_SERVER_ERROR).await;
+        let notifier = WebhookNotifier::new(url, false);
+
+        let res = notifier
+            .deliver(&AssetNotification::processed(
+                "https://example.com/1.json",
+                None,
+            ))
+            .await;
+        assert!(res.is_err());
+        assert!(receiver.recv().await.is_some());
+    }
+}
@@ -12,20 +12,74 @@ use aptos_db_indexer_schemas::schema::{
     ordered_transaction_by_account::OrderedTransactionByAccountSchema, state_keys::StateKeysSchema,
 };
 use aptos_schemadb::{ReadOptions, DB};
-use aptos_storage_interface::{DbReader, Result};
+use aptos_storage_interface::{AptosDbError, DbReader, Result};
 use aptos_types::{
     contract_event::ContractEvent,
     event::EventKey,
-    transaction::{Transaction::UserTransaction, TransactionListWithProof},
+    keyless::{Configuration, Groth16VerificationKey, KEYLESS_ACCOUNT_MODULE_NAME},
+    state_store::state_key::StateKey,
+    transaction::{
+        ReplayProtector, Transaction, Transaction::UserTransaction, TransactionListWithProof,
+        Version,
+    },
+};
+use either::Either;
+use move_core_types::{
+    ident_str,
+    language_storage::{StructTag, CORE_CODE_ADDRESS},
+    move_resource::MoveStructType,
 };
 use rayon::{
     iter::{IntoParallelIterator, ParallelIterator},
     ThreadPoolBuilder,
 };
-use std::{cmp, collections::HashSet, path::Path};
+use std::{
+    cmp,
+    collections::{BTreeMap, HashSet, VecDeque},
+    fmt,
+    path::Path,
+    time::{Duration, Instant},
+};
+use thiserror::Error;
 const SAMPLE_RATE: usize = 500_000;
+const BATCH_SIZE: u64 = 20_000;
 use clap::Parser;
 
+/// Errors returned when validating the internal indexer DB against the main DB.
+#[derive(Debug, Error)]
+pub enum ValidationError {
+    /// One of the DBs could not be opened.
+    #[error("Failed to open DB: {0}")]
+    DbOpenFailed(String),
+    /// Reading from one of the DBs failed.
+    #[error("Failed to read DB: {0}")]
+    DbReadFailed(#[from] AptosDbError),
+    /// There are no transactions in the requested version range.
+    #[error(
+        "No transactions to validate, first version: {start_version:?}, target version: {target_version}"
+    )]
+    VersionOutOfRange {
+        start_version: Option<Version>,
+        target_version: Version,
+    },
+    /// The data in the main DB does not match the data in the internal indexer DB.
+    #[error("Data mismatch at version {version}: {detail}")]
+    DataMismatch { version: Version, detail: String },
+    /// The internal indexer DB is missing data that exists in the main DB.
+    #[error("Internal indexer DB is inconsistent: {detail}")]
+    IndexerInconsistency { detail: String },
+}
+
+type ValidationResult<T> = std::result::Result<T, ValidationError>;
+
+/// Outcome of successfully validating a single version.
+#[derive(Debug, PartialEq, Eq)]
+pub struct VersionValidation {
+    pub version: Version,
+    /// Number of V1 events of the transaction checked against the internal indexer DB.
+    pub num_events: usize,
+}
+
 #[derive(Parser, Debug)]
 pub struct ValidationArgs {
     #[clap(short, long)]
@@ -36,7 +90,109 @@ pub struct ValidationArgs {
 
     #[clap(short, long)]
     pub target_version: u64,
+
+    /// Instead of validating, recompute the internal indexer entries from the main DB and report
+    /// how many entries of each index would change if the internal indexer was rebuilt. Nothing
+    /// is written to either DB.
+    #[clap(long)]
+    pub verify_rebuildable: bool,
+
+    /// Instead of validating the internal indexer DB, run the given check against the main DB
+    /// only. The internal indexer DB path is ignored.
+    #[clap(long, value_enum)]
+    pub check: Option<ValidationCheck>,
+
+    /// Number of threads validating transactions and events in parallel. The reported mismatch
+    /// does not depend on it.
+    #[clap(long, default_value_t = 30)]
+    pub threads: usize,
+}
+
+/// Checks which only read the main DB.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationCheck {
+    /// Checks that every write to the keyless account configuration resources decodes and matches
+    /// the state stored at that version.
+    Keyless,
+}
+
+/// Number of internal indexer entries that are missing or differ from what rebuilding the internal
+/// indexer from the main DB would write, per index.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RebuildCounts {
+    pub state_keys: usize,
+    pub transactions_by_account: usize,
+    pub events_by_key: usize,
+    pub events_by_version: usize,
+}
+
+impl RebuildCounts {
+    fn merge(mut self, other: Self) -> Self {
+        self.state_keys += other.state_keys;
+        self.transactions_by_account += other.transactions_by_account;
+        self.events_by_key += other.events_by_key;
+        self.events_by_version += other.events_by_version;
+        self
+    }
+
+    pub fn total(&self) -> usize {
+        self.state_keys + self.transactions_by_account + self.events_by_key + self.events_by_version
+    }
+}
+
+/// Time spent in each named check while validating. Batches are validated in parallel, so the
+/// time of a check is summed over all batches and may exceed the wall time of the whole run.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CheckTimings {
+    timings: BTreeMap<&'static str, Duration>,
+}
+
+impl CheckTimings {
+    /// Runs `f`, adding its wall time to `check` even if it fails.
+    fn time<T>(&mut self, check: &'static str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        self.record(check, start.elapsed());
+        result
+    }
+
+    fn record(&mut self, check: &'static str, elapsed: Duration) {
+        *self.timings.entry(check).or_default() += elapsed;
+    }
+
+    fn merge(mut self, other: Self) -> Self {
+        for (check, elapsed) in other.timings {
+            self.record(check, elapsed);
+        }
+        self
+    }
+
+    pub fn get(&self, check: &str) -> Option<Duration> {
+        self.timings.get(check).copied()
+    }
+
+    /// Returns the checks ordered from the slowest to the fastest.
+    pub fn slowest_first(&self) -> Vec<(&'static str, Duration)> {
+        let mut timings: Vec<_> = self
+            .timings
+            .iter()
+            .map(|(check, elapsed)| (*check, *elapsed))
+            .collect();
+        timings.sort_by(|(_, a), (_, b)| b.cmp(a));
+        timings
+    }
+}
+
+impl fmt::Display for CheckTimings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Time spent per check:")?;
+        for (check, elapsed) in self.slowest_first() {
+            writeln!(f, "  {}: {:.3}s", check, elapsed.as_secs_f64())?;
+        }
+        Ok(())
+    }
 }
+
 #[derive(clap::Subcommand)]
 pub enum Cmd {
     ValidateIndexerDB(ValidationArgs),
@@ -45,10 +201,22 @@ pub enum Cmd {
 impl Cmd {
     pub fn run(&self) -> Result<()> {
         match self {
+            Cmd::ValidateIndexerDB(ValidationArgs {
+                check: Some(ValidationCheck::Keyless),
+                db_root_path,
+                target_version,
+                ..
+            }) => validate_keyless(Path::new(db_root_path.as_str()), *target_version),
+            Cmd::ValidateIndexerDB(args) if args.verify_rebuildable => verify_rebuildable(
+                Path::new(args.db_root_path.as_str()),
+                Path::new(&args.internal_indexer_db_path.as_str()),
+                args.target_version,
+            ),
             Cmd::ValidateIndexerDB(args) => validate_db_data(
                 Path::new(args.db_root_path.as_str()),
                 Path::new(&args.internal_indexer_db_path.as_str()),
                 args.target_version,
+                args.threads,
             ),
         }
     }
@@ -57,31 +225,61 @@ impl Cmd {
 pub fn validate_db_data(
     db_root_path: &Path,
     internal_indexer_db_path: &Path,
-    mut target_ledger_version: u64,
+    target_ledger_version: u64,
+    num_threads: usize,
 ) -> Result<()> {
-    let num_threads = 30;
-    ThreadPoolBuilder::new()
-        .num_threads(num_threads)
-        .build_global()
-        .unwrap();
-    let internal_db =
-        open_internal_indexer_db(internal_indexer_db_path, &RocksdbConfig::default())?;
-
-    verify_state_kvs(db_root_path, &internal_db, target_ledger_version)?;
-
-    let aptos_db = AptosDB::new_for_test_with_sharding(db_root_path, 1000000);
-    let batch_size = 20_000;
-    let start_version = aptos_db.get_first_txn_version()?.unwrap();
-    target_ledger_version = std::cmp::min(
-        aptos_db.get_synced_version()?.unwrap(),
+    let mut timings = CheckTimings::default();
+    let result = validate_db_data_with_timings(
+        db_root_path,
+        internal_indexer_db_path,
         target_ledger_version,
+        num_threads,
+        &mut timings,
     );
-    assert!(
-        start_version < target_ledger_version,
-        "{}, {}",
-        start_version,
-        target_ledger_version
-    );
+    // Timings are printed on failure too, to show which checks ran and how long they took.
+    print!("{}", timings);
+    result.map_err(|err| AptosDbError::Other(err.to_string()))?;
+    Ok(())
+}
+
+/// Same as [validate_db_data], but returns a [ValidationError] so that callers can tell apart
+/// failures to open the DBs from inconsistencies in the data. On success, returns the time spent
+/// in each check. If there are several mismatches, the one at the lowest version is returned.
+pub fn try_validate_db_data(
+    db_root_path: &Path,
+    internal_indexer_db_path: &Path,
+    target_ledger_version: u64,
+    num_threads: usize,
+) -> ValidationResult<CheckTimings> {
+    let mut timings = CheckTimings::default();
+    validate_db_data_with_timings(
+        db_root_path,
+        internal_indexer_db_path,
+        target_ledger_version,
+        num_threads,
+        &mut timings,
+    )?;
+    Ok(timings)
+}
+
+/// Same as [try_validate_db_data], but adds the time spent in each check to `timings`, including
+/// the checks that ran before a failure.
+fn validate_db_data_with_timings(
+    db_root_path: &Path,
+    internal_indexer_db_path: &Path,
+    target_ledger_version: u64,
+    num_threads: usize,
+    timings: &mut CheckTimings,
+) -> ValidationResult<()> {
+    let internal_db = timings.time("open_db", || {
+        open_internal_indexer_db_for_validation(internal_indexer_db_path)
+    })?;
+    timings.time("state_keys", || {
+        verify_state_kvs(db_root_path, &internal_db, target_ledger_version)
+    })?;
+    let (aptos_db, start_version, target_ledger_version) = timings.time("open_db", || {
+        open_main_db(db_root_path, target_ledger_version)
+    })?;
     println!(
         "Validating events and transactions {}, {}",
         start_version, target_ledger_version
@@ -89,36 +287,473 @@ pub fn validate_db_data(
 
     // Calculate ranges and split into chunks
     let ranges: Vec<(u64, u64)> = (start_version..target_ledger_version)
-        .step_by(batch_size as usize)
+        .step_by(BATCH_SIZE as usize)
         .map(|start| {
-            let end = cmp::min(start + batch_size, target_ledger_version);
+            let end = cmp::min(start + BATCH_SIZE, target_ledger_version);
             (start, end)
         })
         .collect();
 
     // Process each chunk in parallel
-    ranges.into_par_iter().for_each(|(start, end)| {
+    validate_batches(ranges, num_threads, timings, |start, end, timings| {
         let num_of_txns = end - start;
         println!("Validating transactions from {} to {}", start, end);
-        let txns = aptos_db
-            .get_transactions(start, num_of_txns, target_ledger_version, true)
-            .unwrap();
-        verify_batch_txn_events(&txns, &internal_db, start)
-            .unwrap_or_else(|_| panic!("{}, {} failed to verify", start, end));
-        assert_eq!(txns.transactions.len() as u64, num_of_txns);
+        let txns = timings.time("fetch_transactions", || {
+            aptos_db.get_transactions(start, num_of_txns, target_ledger_version, true)
+        })?;
+        timings.time("transactions", || {
+            verify_transactions(&txns, &internal_db, start)
+        })?;
+        timings.time("events", || verify_events(&txns, &internal_db, start))?;
+        check_num_txns(&txns, start, num_of_txns)
+    })
+}
+
+/// Validates the version ranges in parallel on `num_threads` threads. Versions within a range are
+/// validated in order, and ranges are ordered too, so the returned error is the one of the lowest
+/// failing range, regardless of which thread found it first. This keeps reports of the same DB
+/// identical across runs and thread counts. The time spent in each check by all ranges, including
+/// failed ones, is added to `timings`.
+fn validate_batches(
+    ranges: Vec<(Version, Version)>,
+    num_threads: usize,
+    timings: &mut CheckTimings,
+    validate_batch: impl Fn(Version, Version, &mut CheckTimings) -> ValidationResult<()> + Send + Sync,
+) -> ValidationResult<()> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .unwrap();
+    let results: Vec<_> = pool.install(|| {
+        ranges
+            .into_par_iter()
+            .map(|(start, end)| {
+                let mut batch_timings = CheckTimings::default();
+                let result = validate_batch(start, end, &mut batch_timings);
+                (batch_timings, result)
+            })
+            .collect()
     });
+    let mut first_error = None;
+    for (batch_timings, result) in results {
+        *timings = std::mem::take(timings).merge(batch_timings);
+        if let Err(err) = result {
+            first_error.get_or_insert(err);
+        }
+    }
+    first_error.map_or(Ok(()), Err)
+}
+
+/// Validates the same data as [try_validate_db_data], but sequentially, yielding one result per
+/// version as soon as it is validated. This allows callers to process versions as a stream, or to
+/// stop early on custom conditions. Failures to open the DBs or to validate state keys are yielded
+/// as the only item.
+pub fn validate_db_data_iter(
+    db_root_path: &Path,
+    internal_indexer_db_path: &Path,
+    target_ledger_version: u64,
+) -> impl Iterator<Item = ValidationResult<VersionValidation>> {
+    match prepare_validation(
+        db_root_path,
+        internal_indexer_db_path,
+        target_ledger_version,
+    ) {
+        Ok((aptos_db, internal_db, start_version, target_ledger_version)) => {
+            Either::Left(VersionValidationIter::new(
+                move |start, num_of_txns| {
+                    Ok(aptos_db.get_transactions(
+                        start,
+                        num_of_txns,
+                        target_ledger_version,
+                        true,
+                    )?)
+                },
+                internal_db,
+                start_version,
+                target_ledger_version,
+                BATCH_SIZE,
+            ))
+        },
+        Err(err) => Either::Right(std::iter::once(Err(err))),
+    }
+}
+
+/// Reports how many internal indexer entries would change if the internal indexer was rebuilt from
+/// the main DB, without writing anything.
+pub fn verify_rebuildable(
+    db_root_path: &Path,
+    internal_indexer_db_path: &Path,
+    target_ledger_version: u64,
+) -> Result<()> {
+    let counts = try_verify_rebuildable(
+        db_root_path,
+        internal_indexer_db_path,
+        target_ledger_version,
+    )
+    .map_err(|err| AptosDbError::Other(err.to_string()))?;
+
+    println!("Entries that would change if the internal indexer was rebuilt:");
+    println!("  state keys: {}", counts.state_keys);
+    println!(
+        "  transactions by account: {}",
+        counts.transactions_by_account
+    );
+    println!("  events by key: {}", counts.events_by_key);
+    println!("  events by version: {}", counts.events_by_version);
+    if counts.total() == 0 {
+        println!("Internal indexer DB is consistent with the main DB, rebuilding is not needed.");
+    }
+    Ok(())
+}
+
+/// Same as [verify_rebuildable], but returns the counts per index instead of printing them. Only
+/// indices derived from the main DB are checked; translated V2 events are not recomputed.
+pub fn try_verify_rebuildable(
+    db_root_path: &Path,
+    internal_indexer_db_path: &Path,
+    target_ledger_version: u64,
+) -> ValidationResult<RebuildCounts> {
+    let internal_db = open_internal_indexer_db_for_validation(internal_indexer_db_path)?;
+    let state_keys =
+        find_missing_state_keys(db_root_path, &internal_db, target_ledger_version)?.len();
+    let (aptos_db, start_version, target_ledger_version) =
+        open_main_db(db_root_path, target_ledger_version)?;
+
+    let ranges: Vec<(u64, u64)> = (start_version..target_ledger_version)
+        .step_by(BATCH_SIZE as usize)
+        .map(|start| {
+            let end = cmp::min(start + BATCH_SIZE, target_ledger_version);
+            (start, end)
+        })
+        .collect();
+
+    let counts = ranges
+        .into_par_iter()
+        .map(|(start, end)| {
+            let num_of_txns = end - start;
+            println!("Recomputing index entries from {} to {}", start, end);
+            let txns =
+                aptos_db.get_transactions(start, num_of_txns, target_ledger_version, true)?;
+            check_num_txns(&txns, start, num_of_txns)?;
+            count_rebuild_changes(&txns, &internal_db, start)
+        })
+        .try_reduce(RebuildCounts::default, |a, b| Ok(a.merge(b)))?;
+
+    Ok(RebuildCounts {
+        state_keys,
+        ..counts
+    })
+}
+
+/// Checks the keyless account configuration resources and prints every inconsistent version.
+pub fn validate_keyless(db_root_path: &Path, target_ledger_version: u64) -> Result<()> {
+    let mismatches = try_validate_keyless(db_root_path, target_ledger_version)
+        .map_err(|err| AptosDbError::Other(err.to_string()))?;
+    for mismatch in &mismatches {
+        println!("{}", mismatch);
+    }
+    if !mismatches.is_empty() {
+        return Err(AptosDbError::Other(format!(
+            "Keyless account configuration is inconsistent at {} version(s)",
+            mismatches.len()
+        )));
+    }
+    println!("Keyless account configuration is consistent.");
+    Ok(())
+}
+
+/// Same as [validate_keyless], but returns one [ValidationError::DataMismatch] per inconsistent
+/// version instead of printing them. A version is inconsistent if its write to the keyless
+/// resource group fails to decode, or if the group stored in the main DB at that version diverges
+/// from what the transaction wrote.
+pub fn try_validate_keyless(
+    db_root_path: &Path,
+    target_ledger_version: u64,
+) -> ValidationResult<Vec<ValidationError>> {
+    let (aptos_db, start_version, target_ledger_version) =
+        open_main_db(db_root_path, target_ledger_version)?;
+    println!(
+        "Validating keyless account configuration {}, {}",
+        start_version, target_ledger_version
+    );
+
+    let state_key = keyless_group_state_key();
+    let mut mismatches = vec![];
+    let write_sets =
+        aptos_db.get_write_set_iterator(start_version, target_ledger_version - start_version)?;
+    for (offset, write_set) in write_sets.enumerate() {
+        let version = start_version + offset as u64;
+        let Some(write_op) = write_set?.get_write_op(&state_key).cloned() else {
+            continue;
+        };
+        if let Some(bytes) = write_op.bytes() {
+            if let Err(err) = verify_keyless_group(version, bytes) {
+                mismatches.push(err);
+                continue;
+            }
+        }
+        let stored = aptos_db.get_state_value_by_version(&state_key, version)?;
+        if stored.as_ref().map(|value| value.bytes()) != write_op.bytes() {
+            mismatches.push(ValidationError::DataMismatch {
+                version,
+                detail: "keyless resource group in the state KV DB diverges from the write set"
+                    .to_string(),
+            });
+        }
+    }
+    Ok(mismatches)
+}
+
+/// State key of `0x1::keyless_account::Group`, which holds both [Configuration] and
+/// [Groth16VerificationKey].
+fn keyless_group_state_key() -> StateKey {
+    let group_tag = StructTag {
+        address: CORE_CODE_ADDRESS,
+        module: ident_str!(KEYLESS_ACCOUNT_MODULE_NAME).to_owned(),
+        name: ident_str!("Group").to_owned(),
+        type_args: vec![],
+    };
+    StateKey::resource_group(&CORE_CODE_ADDRESS, &group_tag)
+}
+
+/// Checks that the keyless resource group and the keyless members it contains decode.
+fn verify_keyless_group(version: Version, bytes: &[u8]) -> ValidationResult<()> {
+    let decode_failed = |detail: String| ValidationError::DataMismatch { version, detail };
+    let group: BTreeMap<StructTag, Vec<u8>> = bcs::from_bytes(bytes).map_err(|err| {
+        decode_failed(format!("failed to decode keyless resource group: {}", err))
+    })?;
+    if let Some(bytes) = group.get(&Configuration::struct_tag()) {
+        bcs::from_bytes::<Configuration>(bytes).map_err(|err| {
+            decode_failed(format!("failed to decode keyless Configuration: {}", err))
+        })?;
+    }
+    if let Some(bytes) = group.get(&Groth16VerificationKey::struct_tag()) {
+        bcs::from_bytes::<Groth16VerificationKey>(bytes).map_err(|err| {
+            decode_failed(format!(
+                "failed to decode keyless Groth16VerificationKey: {}",
+                err
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+/// Recomputes the index entries the internal indexer writes for the transactions, and counts the
+/// ones that are missing from the internal indexer DB or have a different value.
+fn count_rebuild_changes(
+    txns: &TransactionListWithProof,
+    internal_db: &DB,
+    start_version: u64,
+) -> ValidationResult<RebuildCounts> {
+    let mut counts = RebuildCounts::default();
+    for (idx, txn) in txns.transactions.iter().enumerate() {
+        let version = start_version + idx as u64;
+        if let UserTransaction(signed_txn) = txn {
+            if let ReplayProtector::SequenceNumber(seq_num) = signed_txn.replay_protector() {
+                let key = (signed_txn.sender(), seq_num);
+                if internal_db.get::<OrderedTransactionByAccountSchema>(&key)? != Some(version) {
+                    counts.transactions_by_account += 1;
+                }
+            }
+        }
+    }
+
+    if let Some(event_vec) = &txns.events {
+        for (offset, events) in event_vec.iter().enumerate() {
+            let version = start_version + offset as u64;
+            for (idx, event) in events.iter().enumerate() {
+                if let ContractEvent::V1(event) = event {
+                    let (event_key, seq_num) = (*event.key(), event.sequence_number());
+                    if internal_db.get::<EventByKeySchema>(&(event_key, seq_num))?
+                        != Some((version, idx as u64))
+                    {
+                        counts.events_by_key += 1;
+                    }
+                    if internal_db.get::<EventByVersionSchema>(&(event_key, version, seq_num))?
+                        != Some(idx as u64)
+                    {
+                        counts.events_by_version += 1;
+                    }
+                }
+            }
+        }
+    }
+    Ok(counts)
+}
+
+/// Opens both DBs, validates state keys and returns the DBs together with the range of versions
+/// to validate.
+fn prepare_validation(
+    db_root_path: &Path,
+    internal_indexer_db_path: &Path,
+    target_ledger_version: u64,
+) -> ValidationResult<(AptosDB, DB, Version, Version)> {
+    let internal_db = open_internal_indexer_db_for_validation(internal_indexer_db_path)?;
 
+    verify_state_kvs(db_root_path, &internal_db, target_ledger_version)?;
+
+    let (aptos_db, start_version, target_ledger_version) =
+        open_main_db(db_root_path, target_ledger_version)?;
+    println!(
+        "Validating events and transactions {}, {}",
+        start_version, target_ledger_version
+    );
+    Ok((aptos_db, internal_db, start_version, target_ledger_version))
+}
+
+fn open_internal_indexer_db_for_validation(
+    internal_indexer_db_path: &Path,
+) -> ValidationResult<DB> {
+    open_internal_indexer_db(internal_indexer_db_path, &RocksdbConfig::default())
+        .map_err(|err| ValidationError::DbOpenFailed(err.to_string()))
+}
+
+/// Opens the main DB and returns it together with the range of versions to check.
+fn open_main_db(
+    db_root_path: &Path,
+    mut target_ledger_version: u64,
+) -> ValidationResult<(AptosDB, Version, Version)> {
+    let aptos_db = AptosDB::new_for_test_with_sharding(db_root_path, 1000000);
+    let start_version = aptos_db.get_first_txn_version()?;
+    if let Some(synced_version) = aptos_db.get_synced_version()? {
+        target_ledger_version = std::cmp::min(synced_version, target_ledger_version);
+    }
+    let start_version = match start_version {
+        Some(start_version) if start_version < target_ledger_version => start_version,
+        _ => {
+            return Err(ValidationError::VersionOutOfRange {
+                start_version,
+                target_version: target_ledger_version,
+            })
+        },
+    };
+    Ok((aptos_db, start_version, target_ledger_version))
+}
+
+fn check_num_txns(
+    txns: &TransactionListWithProof,
+    start_version: u64,
+    num_of_txns: u64,
+) -> ValidationResult<()> {
+    if txns.transactions.len() as u64 != num_of_txns {
+        return Err(ValidationError::DataMismatch {
+            version: start_version,
+            detail: format!(
+                "Expected {} transactions, got {}",
+                num_of_txns,
+                txns.transactions.len()
+            ),
+        });
+    }
     Ok(())
 }
 
+/// Lazily fetches batches of transactions and validates them version by version.
+struct VersionValidationIter<F> {
+    fetch_batch: F,
+    internal_db: DB,
+    next_version: Version,
+    target_version: Version,
+    batch_size: u64,
+    pending: VecDeque<ValidationResult<VersionValidation>>,
+}
+
+impl<F> VersionValidationIter<F>
+where
+    F: FnMut(Version, u64) -> ValidationResult<TransactionListWithProof>,
+{
+    fn new(
+        fetch_batch: F,
+        internal_db: DB,
+        start_version: Version,
+        target_version: Version,
+        batch_size: u64,
+    ) -> Self {
+        Self {
+            fetch_batch,
+            internal_db,
+            next_version: start_version,
+            target_version,
+            batch_size,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn validate_next_batch(&mut self) {
+        let start = self.next_version;
+        let num_of_txns = cmp::min(self.batch_size, self.target_version - start);
+        self.next_version += num_of_txns;
+
+        let txns = match (self.fetch_batch)(start, num_of_txns) {
+            Ok(txns) => txns,
+            Err(err) => {
+                self.pending.push_back(Err(err));
+                return;
+            },
+        };
+        for (idx, txn) in txns.transactions.iter().enumerate() {
+            let events = txns
+                .events
+                .as_ref()
+                .and_then(|events| events.get(idx))
+                .map(Vec::as_slice);
+            self.pending.push_back(verify_version(
+                txn,
+                events,
+                &self.internal_db,
+                start + idx as u64,
+            ));
+        }
+        if let Err(err) = check_num_txns(&txns, start, num_of_txns) {
+            self.pending.push_back(Err(err));
+        }
+    }
+}
+
+impl<F> Iterator for VersionValidationIter<F>
+where
+    F: FnMut(Version, u64) -> ValidationResult<TransactionListWithProof>,
+{
+    type Item = ValidationResult<VersionValidation>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pending.is_empty() && self.next_version < self.target_version {
+            self.validate_next_batch();
+        }
+        self.pending.pop_front()
+    }
+}
+
 pub fn verify_state_kvs(
     db_root_path: &Path,
     internal_db: &DB,
     target_ledger_version: u64,
-) -> Result<()> {
+) -> ValidationResult<()> {
+    let missing_keys = find_missing_state_keys(db_root_path, internal_db, target_ledger_version)?;
+    if let Some((state_key_hash, version)) = missing_keys.first() {
+        return Err(ValidationError::IndexerInconsistency {
+            detail: format!(
+                "{} state keys not found in internal db, first: {} at version {}",
+                missing_keys.len(),
+                state_key_hash,
+                version
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Returns the hashes and versions of state values in the main DB, up to the target version,
+/// whose state keys are missing from the internal indexer DB.
+fn find_missing_state_keys(
+    db_root_path: &Path,
+    internal_db: &DB,
+    target_ledger_version: u64,
+) -> ValidationResult<Vec<(HashValue, Version)>> {
     println!("Validating db statekeys");
     let storage_dir = StorageDirPaths::from_path(db_root_path);
-    let state_kv_db = StateKvDb::open_sharded(&storage_dir, RocksdbConfig::default(), false)?;
+    let state_kv_db = StateKvDb::open_sharded(&storage_dir, RocksdbConfig::default(), false)
+        .map_err(|err| ValidationError::DbOpenFailed(err.to_string()))?;
 
     //read all statekeys from internal db and store them in mem
     let mut all_internal_keys = HashSet::new();
@@ -136,34 +771,39 @@ pub fn verify_state_kvs(
         "Number of state keys in internal db: {}",
         all_internal_keys.len()
     );
+    let mut missing_keys = vec![];
     for shard_id in 0..16 {
         let shard = state_kv_db.db_shard(shard_id);
         println!("Validating state_kv for shard {}", shard_id);
-        verify_state_kv(shard, &all_internal_keys, target_ledger_version)?;
+        missing_keys.extend(find_missing_state_kv_keys(
+            shard,
+            &all_internal_keys,
+            target_ledger_version,
+        )?);
     }
-    Ok(())
+    Ok(missing_keys)
 }
 
 pub fn verify_batch_txn_events(
     txns: &TransactionListWithProof,
     internal_db: &DB,
     start_version: u64,
-) -> Result<()> {
+) -> ValidationResult<()> {
     verify_transactions(txns, internal_db, start_version)?;
     verify_events(txns, internal_db, start_version)
 }
 
-fn verify_state_kv(
+fn find_missing_state_kv_keys(
     shard: &DB,
     all_internal_keys: &HashSet<HashValue>,
     target_ledger_version: u64,
-) -> Result<()> {
+) -> ValidationResult<Vec<(HashValue, Version)>> {
     let read_opts = ReadOptions::default();
     let mut iter = shard.iter_with_opts::<StateValueByKeyHashSchema>(read_opts)?;
     // print a message every 10k keys
     let mut counter = 0;
     iter.seek_to_first();
-    let mut missing_keys = 0;
+    let mut missing_keys = vec![];
     for value in iter {
         let (state_key_hash, version) = value?.0;
         if version > target_ledger_version {
@@ -171,7 +811,7 @@ fn verify_state_kv(
         }
         // check if the state key hash is present in the internal db
         if !all_internal_keys.contains(&state_key_hash) {
-            missing_keys += 1;
+            missing_keys.push((state_key_hash, version));
             println!(
                 "State key hash not found in internal db: {:?}, version: {}",
                 state_key_hash, version
@@ -185,35 +825,71 @@ fn verify_state_kv(
             );
         }
     }
-    println!("Number of missing keys: {}", missing_keys);
-    Ok(())
+    println!("Number of missing keys: {}", missing_keys.len());
+    Ok(missing_keys)
 }
 
 fn verify_transactions(
     transaction_list: &TransactionListWithProof,
     internal_indexer_db: &DB,
     start_version: u64,
-) -> Result<()> {
+) -> ValidationResult<()> {
     for (idx, txn) in transaction_list.transactions.iter().enumerate() {
-        match txn {
-            UserTransaction(signed_transaction) => {
-                let key = (
-                    signed_transaction.sender(),
-                    signed_transaction.sequence_number(),
-                );
-                match internal_indexer_db.get::<OrderedTransactionByAccountSchema>(&key)? {
-                    Some(version) => {
-                        assert_eq!(version, start_version + idx as u64);
-                        if idx + start_version as usize % SAMPLE_RATE == 0 {
-                            println!("Processed {} at {:?}", idx + start_version as usize, key);
-                        }
-                    },
-                    None => {
-                        panic!("Transaction not found in internal indexer db: {:?}", key);
-                    },
+        verify_transaction(txn, internal_indexer_db, start_version + idx as u64)?;
+    }
+    Ok(())
+}
+
+fn verify_version(
+    txn: &Transaction,
+    events: Option<&[ContractEvent]>,
+    internal_indexer_db: &DB,
+    version: u64,
+) -> ValidationResult<VersionValidation> {
+    verify_transaction(txn, internal_indexer_db, version)?;
+    let num_events = match events {
+        Some(events) => verify_version_events(events, internal_indexer_db, version)?,
+        None => 0,
+    };
+    Ok(VersionValidation {
+        version,
+        num_events,
+    })
+}
+
+fn verify_transaction(
+    txn: &Transaction,
+    internal_indexer_db: &DB,
+    expected_version: u64,
+) -> ValidationResult<()> {
+    if let UserTransaction(signed_transaction) = txn {
+        let key = (
+            signed_transaction.sender(),
+            signed_transaction.sequence_number(),
+        );
+        match internal_indexer_db.get::<OrderedTransactionByAccountSchema>(&key)? {
+            Some(version) => {
+                if version != expected_version {
+                    return Err(ValidationError::DataMismatch {
+                        version: expected_version,
+                        detail: format!(
+                            "Transaction {:?} found at version {} in internal indexer db",
+                            key, version
+                        ),
+                    });
                 }
+                if expected_version as usize % SAMPLE_RATE == 0 {
+                    println!("Processed {} at {:?}", expected_version, key);
+                }
+            },
+            None => {
+                return Err(ValidationError::IndexerInconsistency {
+                    detail: format!(
+                        "Transaction not found in internal indexer db: {:?}, version: {}",
+                        key, expected_version
+                    ),
+                });
             },
-            _ => continue,
         }
     }
     Ok(())
@@ -225,16 +901,26 @@ fn verify_event_by_key(
     internal_indexer_db: &DB,
     expected_idx: usize,
     expected_version: u64,
-) -> Result<()> {
-    match internal_indexer_db.get::<EventByKeySchema>(&(*event_key, seq_num)) {
-        Ok(None) => {
-            panic!("Event not found in internal indexer db: {:?}", event_key);
-        },
-        Err(e) => {
-            panic!("Error while fetching event: {:?}", e);
+) -> ValidationResult<()> {
+    match internal_indexer_db.get::<EventByKeySchema>(&(*event_key, seq_num))? {
+        None => {
+            return Err(ValidationError::IndexerInconsistency {
+                detail: format!(
+                    "Event not found in internal indexer db: {:?}, version: {}",
+                    event_key, expected_version
+                ),
+            });
         },
-        Ok(Some((version, idx))) => {
-            assert!(idx as usize == expected_idx && version == expected_version);
+        Some((version, idx)) => {
+            if idx as usize != expected_idx || version != expected_version {
+                return Err(ValidationError::DataMismatch {
+                    version: expected_version,
+                    detail: format!(
+                        "Event {:?} found at version {} with index {} in internal indexer db, expected index {}",
+                        event_key, version, idx, expected_idx
+                    ),
+                });
+            }
             if version as usize % SAMPLE_RATE == 0 {
                 println!(
                     "Processed {} at {:?}, {:?}",
@@ -252,16 +938,26 @@ fn verify_event_by_version(
     internal_indexer_db: &DB,
     version: u64,
     expected_idx: usize,
-) -> Result<()> {
-    match internal_indexer_db.get::<EventByVersionSchema>(&(*event_key, version, seq_num)) {
-        Ok(None) => {
-            panic!("Event not found in internal indexer db: {:?}", event_key);
-        },
-        Err(e) => {
-            panic!("Error while fetching event: {:?}", e);
+) -> ValidationResult<()> {
+    match internal_indexer_db.get::<EventByVersionSchema>(&(*event_key, version, seq_num))? {
+        None => {
+            return Err(ValidationError::IndexerInconsistency {
+                detail: format!(
+                    "Event not found in internal indexer db: {:?}, version: {}",
+                    event_key, version
+                ),
+            });
         },
-        Ok(Some(idx)) => {
-            assert!(idx as usize == expected_idx);
+        Some(idx) => {
+            if idx as usize != expected_idx {
+                return Err(ValidationError::DataMismatch {
+                    version,
+                    detail: format!(
+                        "Event {:?} found with index {} in internal indexer db, expected index {}",
+                        event_key, idx, expected_idx
+                    ),
+                });
+            }
         },
     }
     Ok(())
@@ -271,40 +967,306 @@ fn verify_events(
     transaction_list: &TransactionListWithProof,
     internal_indexer_db: &DB,
     start_version: u64,
-) -> Result<()> {
-    let mut version = start_version;
-    match &transaction_list.events {
-        None => {
-            return Ok(());
-        },
-        Some(event_vec) => {
-            for events in event_vec {
-                for (idx, event) in events.iter().enumerate() {
-                    match event {
-                        ContractEvent::V1(event) => {
-                            let seq_num = event.sequence_number();
-                            let event_key = event.key();
-                            verify_event_by_version(
-                                event_key,
-                                seq_num,
-                                internal_indexer_db,
-                                version,
-                                idx,
-                            )?;
-                            verify_event_by_key(
-                                event_key,
-                                seq_num,
-                                internal_indexer_db,
-                                idx,
-                                version,
-                            )?;
-                        },
-                        _ => continue,
+) -> ValidationResult<()> {
+    if let Some(event_vec) = &transaction_list.events {
+        for (offset, events) in event_vec.iter().enumerate() {
+            verify_version_events(events, internal_indexer_db, start_version + offset as u64)?;
+        }
+    }
+    Ok(())
+}
+
+/// Verifies the V1 events of a single version, returning the number of events verified.
+fn verify_version_events(
+    events: &[ContractEvent],
+    internal_indexer_db: &DB,
+    version: u64,
+) -> ValidationResult<usize> {
+    let mut num_events = 0;
+    for (idx, event) in events.iter().enumerate() {
+        if let ContractEvent::V1(event) = event {
+            let seq_num = event.sequence_number();
+            let event_key = event.key();
+            verify_event_by_version(event_key, seq_num, internal_indexer_db, version, idx)?;
+            verify_event_by_key(event_key, seq_num, internal_indexer_db, idx, version)?;
+            num_events += 1;
+        }
+    }
+    Ok(num_events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_temppath::TempPath;
+    use aptos_types::{
+        account_address::AccountAddress, state_store::state_value::StateValue,
+        transaction::TransactionInfoListWithProof,
+    };
+    use claims::assert_ok;
+    use move_core_types::language_storage::TypeTag;
+
+    #[test]
+    fn test_verify_events_missing_from_indexer() {
+        let tmp_dir = TempPath::new();
+        let internal_db = open_internal_indexer_db(tmp_dir.path(), &RocksdbConfig::default())
+            .expect("Internal indexer db should open");
+
+        let event = ContractEvent::new_v1(
+            EventKey::new(0, AccountAddress::ONE),
+            0,
+            TypeTag::Bool,
+            bcs::to_bytes(&true).unwrap(),
+        )
+        .unwrap();
+        let txns = TransactionListWithProof::new(
+            vec![],
+            Some(vec![vec![event]]),
+            Some(0),
+            TransactionInfoListWithProof::new_empty(),
+        );
+
+        let err = verify_batch_txn_events(&txns, &internal_db, 0).unwrap_err();
+        assert!(matches!(err, ValidationError::IndexerInconsistency { .. }));
+    }
+
+    #[test]
+    fn test_version_validation_iter_stops_early() {
+        let tmp_dir = TempPath::new();
+        let internal_db = open_internal_indexer_db(tmp_dir.path(), &RocksdbConfig::default())
+            .expect("Internal indexer db should open");
+
+        // Version 5 emits an event which is missing from the internal indexer db.
+        let event = ContractEvent::new_v1(
+            EventKey::new(0, AccountAddress::ONE),
+            0,
+            TypeTag::Bool,
+            bcs::to_bytes(&true).unwrap(),
+        )
+        .unwrap();
+        let mut num_fetched_batches = 0;
+        let fetch_batch = |start: Version, num_of_txns: u64| {
+            num_fetched_batches += 1;
+            let versions = start..start + num_of_txns;
+            let events = versions
+                .clone()
+                .map(|version| {
+                    if version == 5 {
+                        vec![event.clone()]
+                    } else {
+                        vec![]
                     }
-                }
-                version += 1;
+                })
+                .collect();
+            Ok(TransactionListWithProof::new(
+                versions
+                    .map(|_| Transaction::StateCheckpoint(HashValue::zero()))
+                    .collect(),
+                Some(events),
+                Some(start),
+                TransactionInfoListWithProof::new_empty(),
+            ))
+        };
+
+        let mut validated_versions = vec![];
+        for result in VersionValidationIter::new(fetch_batch, internal_db, 0, 100, 4) {
+            match result {
+                Ok(validation) => validated_versions.push(validation.version),
+                Err(err) => {
+                    assert!(matches!(err, ValidationError::IndexerInconsistency { .. }));
+                    break;
+                },
             }
-        },
+        }
+        assert_eq!(validated_versions, vec![0, 1, 2, 3, 4]);
+        // Only the batches up to the failing version have been fetched.
+        assert_eq!(num_fetched_batches, 2);
+    }
+
+    #[test]
+    fn test_count_rebuild_changes() {
+        let tmp_dir = TempPath::new();
+        let internal_db = open_internal_indexer_db(tmp_dir.path(), &RocksdbConfig::default())
+            .expect("Internal indexer db should open");
+
+        let new_event = |seq_num| {
+            ContractEvent::new_v1(
+                EventKey::new(0, AccountAddress::ONE),
+                seq_num,
+                TypeTag::Bool,
+                bcs::to_bytes(&true).unwrap(),
+            )
+            .unwrap()
+        };
+        let event_key = EventKey::new(0, AccountAddress::ONE);
+
+        // The first event is indexed by key correctly, but indexed by version with a wrong index.
+        // The second event is missing from the internal indexer db.
+        internal_db
+            .put::<EventByKeySchema>(&(event_key, 0), &(7, 0))
+            .unwrap();
+        internal_db
+            .put::<EventByVersionSchema>(&(event_key, 7, 0), &1)
+            .unwrap();
+        let txns = TransactionListWithProof::new(
+            vec![Transaction::StateCheckpoint(HashValue::zero())],
+            Some(vec![vec![new_event(0), new_event(1)]]),
+            Some(7),
+            TransactionInfoListWithProof::new_empty(),
+        );
+
+        let counts = count_rebuild_changes(&txns, &internal_db, 7).unwrap();
+        assert_eq!(counts, RebuildCounts {
+            state_keys: 0,
+            transactions_by_account: 0,
+            events_by_key: 1,
+            events_by_version: 2,
+        });
+        assert_eq!(counts.total(), 3);
+    }
+
+    /// The internal indexer DB only records state keys, not values, so validation reports state
+    /// values in the main DB whose key hash does not belong to any indexed state key.
+    #[test]
+    fn test_state_value_with_unindexed_key_hash_is_reported() {
+        let db_dir = TempPath::new();
+        let internal_db_dir = TempPath::new();
+        let internal_db =
+            open_internal_indexer_db(internal_db_dir.path(), &RocksdbConfig::default())
+                .expect("Internal indexer db should open");
+
+        let state_keys: Vec<_> = (0..4)
+            .map(|i| StateKey::raw(format!("key_{}", i).as_bytes()))
+            .collect();
+        let unindexed_hash = HashValue::sha3_256_of(b"unindexed");
+        {
+            let state_kv_db = StateKvDb::open_sharded(
+                &StorageDirPaths::from_path(db_dir.path()),
+                RocksdbConfig::default(),
+                false,
+            )
+            .unwrap();
+            let write = |hash: HashValue, version: Version| {
+                state_kv_db
+                    .db_shard(hash.nibble(0))
+                    .put::<StateValueByKeyHashSchema>(
+                        &(hash, version),
+                        &Some(StateValue::new_legacy(vec![version as u8].into())),
+                    )
+                    .unwrap();
+            };
+            for (version, state_key) in state_keys.iter().enumerate() {
+                internal_db.put::<StateKeysSchema>(state_key, &()).unwrap();
+                write(state_key.hash(), version as Version);
+            }
+            // Write a value at version 2 under a key hash which is not indexed, and a value past
+            // the validated range which must not be reported.
+            write(unindexed_hash, 2);
+            write(HashValue::sha3_256_of(b"too new"), 10);
+        }
+
+        assert_eq!(
+            assert_ok!(find_missing_state_keys(db_dir.path(), &internal_db, 3)),
+            vec![(unindexed_hash, 2)]
+        );
+        let err = verify_state_kvs(db_dir.path(), &internal_db, 3).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                ValidationError::IndexerInconsistency { detail }
+                    if detail.contains(&format!("{} at version 2", unindexed_hash))
+            ),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_validate_batches_reports_lowest_mismatch() {
+        let ranges: Vec<_> = (0..100)
+            .step_by(10)
+            .map(|start| (start, start + 10))
+            .collect();
+        // Later ranges fail faster, so that with several threads they are likely found first.
+        let validate_batch = |start: Version, end: Version, timings: &mut CheckTimings| {
+            timings.record("events", Duration::from_millis(1));
+            std::thread::sleep(Duration::from_millis(100 - end));
+            match (start..end).find(|version| version % 30 == 25) {
+                Some(version) => Err(ValidationError::DataMismatch {
+                    version,
+                    detail: "corrupted".to_string(),
+                }),
+                None => Ok(()),
+            }
+        };
+
+        let reports: Vec<_> = [1, 4, 16]
+            .into_iter()
+            .map(|num_threads| {
+                let mut timings = CheckTimings::default();
+                let report =
+                    validate_batches(ranges.clone(), num_threads, &mut timings, validate_batch)
+                        .unwrap_err()
+                        .to_string();
+                // The timings of all ranges are kept, including those of failed ones.
+                assert_eq!(timings.get("events"), Some(Duration::from_millis(10)));
+                report
+            })
+            .collect();
+        assert!(reports.iter().all(|report| report == &reports[0]));
+        assert_eq!(reports[0], "Data mismatch at version 25: corrupted");
+    }
+
+    #[test]
+    fn test_check_timings() {
+        let mut timings = CheckTimings::default();
+        timings.record("events", Duration::from_secs(1));
+        let result: ValidationResult<()> = timings.time("state_keys", || {
+            Err(ValidationError::IndexerInconsistency {
+                detail: "missing".to_string(),
+            })
+        });
+        assert!(result.is_err());
+        assert!(timings.get("state_keys").is_some());
+
+        let mut other = CheckTimings::default();
+        other.record("events", Duration::from_secs(2));
+        other.record("transactions", Duration::from_secs(1));
+        let timings = timings.merge(other);
+
+        assert_eq!(timings.get("events"), Some(Duration::from_secs(3)));
+        assert_eq!(timings.get("open_db"), None);
+        let order: Vec<_> = timings
+            .slowest_first()
+            .into_iter()
+            .map(|(check, _)| check)
+            .collect();
+        assert_eq!(order, vec!["events", "transactions", "state_keys"]);
+        assert!(timings
+            .to_string()
+            .starts_with("Time spent per check:\n  events: 3.000s"));
+    }
+
+    #[test]
+    fn test_verify_keyless_group() {
+        let config = Configuration::new_for_testing();
+        let group: BTreeMap<StructTag, Vec<u8>> =
+            BTreeMap::from([(Configuration::struct_tag(), bcs::to_bytes(&config).unwrap())]);
+        verify_keyless_group(3, &bcs::to_bytes(&group).unwrap()).unwrap();
+
+        // The group decodes, but its Configuration member is truncated.
+        let truncated: BTreeMap<StructTag, Vec<u8>> =
+            BTreeMap::from([(Configuration::struct_tag(), vec![1, 2])]);
+        let err = verify_keyless_group(4, &bcs::to_bytes(&truncated).unwrap()).unwrap_err();
+        assert!(matches!(err, ValidationError::DataMismatch {
+            version: 4,
+            ..
+        }));
+
+        let err = verify_keyless_group(5, &[0xFF]).unwrap_err();
+        assert!(matches!(err, ValidationError::DataMismatch {
+            version: 5,
+            ..
+        }));
     }
-    Ok(())
 }
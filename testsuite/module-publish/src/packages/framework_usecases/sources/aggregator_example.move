@@ -65,6 +65,16 @@ module 0xABCD::aggregator_example {
         assert!(aggregator_v2::try_add(&mut counter.count, 1), ECOUNTER_INCREMENT_FAIL);
     }
 
+    public entry fun increment_agg_v2_repeated(count: u64) acquires CounterAggV2 {
+        assert!(exists<CounterAggV2>(@publisher_address), error::invalid_argument(ECOUNTER_AGG_RESOURCE_NOT_PRESENT));
+        let counter = borrow_global_mut<CounterAggV2>(@publisher_address);
+        let i = 0;
+        while (i < count) {
+            assert!(aggregator_v2::try_add(&mut counter.count, 1), ECOUNTER_INCREMENT_FAIL);
+            i = i + 1;
+        };
+    }
+
     public entry fun modify_bounded_agg_v2(increment: bool, delta: u64) acquires BoundedAggV2 {
         assert!(exists<BoundedAggV2>(@publisher_address), error::invalid_argument(EBOUNDED_AGG_RESOURCE_NOT_PRESENT));
         let bounded = borrow_global_mut<BoundedAggV2>(@publisher_address);
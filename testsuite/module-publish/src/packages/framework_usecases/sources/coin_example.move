@@ -1,6 +1,10 @@
 module 0xABCD::coin_example {
+    use aptos_framework::coin;
     use std::signer;
 
+    /// Amount minted to the admin at initialization, to be transferred to users.
+    const ADMIN_BALANCE: u64 = 1000000000000000000;
+
     struct ExampleCoin {}
 
     fun init_module(sender: &signer) {
@@ -11,10 +15,20 @@ module 0xABCD::coin_example {
             8,
             false,
         );
+        aptos_framework::managed_coin::register<ExampleCoin>(sender);
+        aptos_framework::managed_coin::mint<ExampleCoin>(sender, signer::address_of(sender), ADMIN_BALANCE);
     }
 
     public entry fun mint_p(user: &signer, admin: &signer, amount: u64) {
         aptos_framework::managed_coin::register<ExampleCoin>(user);
         aptos_framework::managed_coin::mint<ExampleCoin>(admin, signer::address_of(user), amount);
     }
+
+    /// Transfers coins from the admin to the user, registering the user on first use.
+    public entry fun transfer_p(user: &signer, admin: &signer, amount: u64) {
+        if (!coin::is_account_registered<ExampleCoin>(signer::address_of(user))) {
+            aptos_framework::managed_coin::register<ExampleCoin>(user);
+        };
+        coin::transfer<ExampleCoin>(admin, signer::address_of(user), amount);
+    }
 }
@@ -12,6 +12,9 @@ module 0xABCD::fungible_asset_example {
 
     const ASSET_SYMBOL: vector<u8> = b"FA";
 
+    /// Amount minted to the admin at initialization, to be transferred to users.
+    const ADMIN_BALANCE: u64 = 1000000000000000000;
+
     #[resource_group_member(group = aptos_framework::object::ObjectGroup)]
     /// Hold refs to control the minting, transfer and burning of fungible assets.
     struct ManagedFungibleAsset has key {
@@ -39,6 +42,7 @@ module 0xABCD::fungible_asset_example {
         let burn_ref = fungible_asset::generate_burn_ref(constructor_ref);
         let transfer_ref = fungible_asset::generate_transfer_ref(constructor_ref);
         let metadata_object_signer = object::generate_signer(constructor_ref);
+        primary_fungible_store::mint(&mint_ref, signer::address_of(admin), ADMIN_BALANCE);
         move_to(
             &metadata_object_signer,
             ManagedFungibleAsset { mint_ref, transfer_ref, burn_ref }
@@ -73,6 +77,12 @@ module 0xABCD::fungible_asset_example {
         fungible_asset::transfer(from, from_wallet, to_wallet, amount);
     }
 
+    /// Transfers fungible assets from the admin's primary store to the user's.
+    public entry fun transfer_p(user: &signer, admin: &signer, amount: u64) {
+        let asset = get_metadata(signer::address_of(admin));
+        primary_fungible_store::transfer(admin, asset, signer::address_of(user), amount);
+    }
+
     /// Borrow the immutable reference of the refs of `metadata`.
     /// This validates that the signer is the metadata object's owner.
     inline fun authorized_borrow_refs(
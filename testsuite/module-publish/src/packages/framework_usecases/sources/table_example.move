@@ -0,0 +1,32 @@
+module 0xABCD::table_example {
+    use std::signer;
+    use aptos_std::smart_table::{Self, SmartTable};
+
+    struct SmartTableResource has key {
+        value: SmartTable<u64, u64>,
+    }
+
+    /// Upserts all `inserts` into the sender's table, and then looks up all `lookups` in it. The
+    /// table is kept across transactions, so it grows (and splits its buckets) until it holds all
+    /// of the keys being used.
+    public entry fun test_insert_and_lookup_smart_table(
+        sender: &signer,
+        inserts: vector<u64>,
+        lookups: vector<u64>,
+    ) acquires SmartTableResource {
+        let sender_address = signer::address_of(sender);
+        if (!exists<SmartTableResource>(sender_address)) {
+            move_to(sender, SmartTableResource { value: smart_table::new() });
+        };
+
+        let table = &mut borrow_global_mut<SmartTableResource>(sender_address).value;
+        inserts.for_each(|key| { table.upsert(key, key); });
+
+        let sum = 0;
+        lookups.for_each(|key| {
+            if (table.contains(key)) {
+                sum = sum + *table.borrow(key);
+            };
+        });
+    }
+}
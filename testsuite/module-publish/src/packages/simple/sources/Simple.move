@@ -26,6 +26,8 @@ module 0xABCD::simple {
 
     // Resource being modified doesn't exist
     const ECOUNTER_RESOURCE_NOT_PRESENT: u64 = 1;
+    // Depth of the nested struct is not supported
+    const EINVALID_NESTED_STRUCT_DEPTH: u64 = 2;
 
     // Load and return a value from the constant `RANDOM`.
     // No data read or write.
@@ -53,6 +55,9 @@ module 0xABCD::simple {
     public entry fun nop_2_signers(_s1: &signer, _s2: &signer) {
     }
 
+    public entry fun nop_3_signers(_s1: &signer, _s2: &signer, _s3: &signer) {
+    }
+
     public entry fun nop_5_signers(_s1: &signer, _s2: &signer, _s3: &signer, _s4: &signer, _s5: &signer) {
     }
 
@@ -153,6 +158,24 @@ module 0xABCD::simple {
         counter.count;
     }
 
+    // Counter stored under each signer's own address.
+    struct SignerCounter has key {
+        count: u64,
+    }
+
+    // Resolves the signer's address and mutably borrows the signer's `SignerCounter` `count`
+    // times, to measure the cost of the common signer-keyed access pattern.
+    public entry fun signer_keyed_borrows(s: &signer, count: u64) acquires SignerCounter {
+        if (!exists<SignerCounter>(signer::address_of(s))) {
+            move_to<SignerCounter>(s, SignerCounter { count: 0 });
+        };
+        while (count > 0) {
+            count = count - 1;
+            let counter = borrow_global_mut<SignerCounter>(signer::address_of(s));
+            counter.count = counter.count + 1;
+        }
+    }
+
     //
     // Resource
     //
@@ -474,7 +497,7 @@ module 0xABCD::simple {
         simple_events: EventHandle<SimpleEvent>,
     }
 
-    fun emit_events(owner: &signer, count: u64) acquires EventStore
+    public entry fun emit_events(owner: &signer, count: u64) acquires EventStore
     {
         let owner_address = signer::address_of(owner);
         if (!exists<EventStore>(owner_address)) {
@@ -492,6 +515,78 @@ module 0xABCD::simple {
         }
     }
 
+    struct SizedEvent has drop, store {
+        event_id: u64,
+        payload: vector<u8>,
+    }
+
+    struct SizedEventStore has key {
+        sized_events: EventHandle<SizedEvent>,
+    }
+
+    public entry fun emit_events_sized(owner: &signer, count: u64, payload_bytes: u64) acquires SizedEventStore
+    {
+        let owner_address = signer::address_of(owner);
+        if (!exists<SizedEventStore>(owner_address)) {
+            move_to<SizedEventStore>(owner, SizedEventStore {
+                sized_events: account::new_event_handle<SizedEvent>(owner)
+            });
+        };
+        let payload = vector::empty<u8>();
+        while (payload_bytes > 0) {
+            payload_bytes = payload_bytes - 1;
+            vector::push_back(&mut payload, ((payload_bytes % 256) as u8));
+        };
+        let event_store = borrow_global_mut<SizedEventStore>(owner_address);
+        while (count > 0) {
+            count = count - 1;
+            event::emit_event<SizedEvent>(
+                &mut event_store.sized_events,
+                SizedEvent { event_id: count, payload },
+            );
+        }
+    }
+
+    // Node of a nested struct, where every node has `width` copies of the same child.
+    struct NestedNode<T> has copy, drop, store {
+        value: u64,
+        children: vector<T>,
+    }
+
+    fun nested_node<T: copy + drop + store>(child: T, width: u64): NestedNode<T> {
+        let children = vector::empty<T>();
+        let i = 0;
+        while (i < width) {
+            vector::push_back(&mut children, child);
+            i = i + 1;
+        };
+        NestedNode { value: width, children }
+    }
+
+    // Builds a struct nested `depth` (between 1 and 4) levels deep, where each level has `width`
+    // children, serializes it with BCS, and drops it, to measure the cost of (de)constructing and
+    // serializing deeply nested resources.
+    public entry fun nested_struct_serialize(_s: &signer, depth: u64, width: u64) {
+        assert!(depth >= 1 && depth <= 4, error::invalid_argument(EINVALID_NESTED_STRUCT_DEPTH));
+        let level_1 = nested_node(width, width);
+        if (depth == 1) {
+            bcs::to_bytes(&level_1);
+            return
+        };
+        let level_2 = nested_node(level_1, width);
+        if (depth == 2) {
+            bcs::to_bytes(&level_2);
+            return
+        };
+        let level_3 = nested_node(level_2, width);
+        if (depth == 3) {
+            bcs::to_bytes(&level_3);
+            return
+        };
+        let level_4 = nested_node(level_3, width);
+        bcs::to_bytes(&level_4);
+    }
+
     public entry fun publish_p(_s: &signer, owner: &signer, metadata_serialized: vector<u8>, code: vector<vector<u8>>) {
         code::publish_package_txn(owner, metadata_serialized, code)
     }
@@ -12,7 +12,9 @@ use move_core_types::{
     account_address::AccountAddress, ident_str, identifier::Identifier, language_storage::ModuleId,
     vm_status::StatusCode,
 };
-use move_vm_runtime::{AsUnsyncCodeStorage, AsUnsyncModuleStorage, CodeStorage, ModuleStorage};
+use move_vm_runtime::{
+    AsUnsyncCodeStorage, AsUnsyncModuleStorage, CodeStorage, ModuleStorage, RuntimeEnvironment,
+};
 use move_vm_test_utils::InMemoryStorage;
 use move_vm_types::sha3_256;
 
@@ -171,6 +173,46 @@ fn test_dependency_dag_traversal() {
     ]);
 }
 
+#[test]
+fn test_dependency_traversal_bypassing_cache() {
+    let mut module_bytes_storage = InMemoryStorage::new();
+
+    let a_id = ModuleId::new(AccountAddress::ZERO, Identifier::new("a").unwrap());
+    let b_id = ModuleId::new(AccountAddress::ZERO, Identifier::new("b").unwrap());
+    let c_id = ModuleId::new(AccountAddress::ZERO, Identifier::new("c").unwrap());
+    let d_id = ModuleId::new(AccountAddress::ZERO, Identifier::new("d").unwrap());
+
+    add_module_bytes(&mut module_bytes_storage, "a", vec!["b", "c"], vec![]);
+    add_module_bytes(&mut module_bytes_storage, "b", vec!["d"], vec![]);
+    add_module_bytes(&mut module_bytes_storage, "c", vec!["d"], vec![]);
+    add_module_bytes(&mut module_bytes_storage, "d", vec![], vec![]);
+
+    let module_storage = module_bytes_storage.into_unsync_module_storage();
+    let num_verified = RuntimeEnvironment::num_locally_verified_modules_for_test;
+
+    let before = num_verified();
+    assert_ok!(module_storage.fetch_verified_module(a_id.address(), a_id.name()));
+    module_storage.assert_cached_state(vec![], vec![&a_id, &b_id, &c_id, &d_id]);
+    assert_eq!(num_verified() - before, 4);
+
+    // Cached verified modules are not verified again.
+    let before = num_verified();
+    assert_ok!(module_storage.fetch_verified_module(a_id.address(), a_id.name()));
+    assert_eq!(num_verified() - before, 0);
+
+    // Bypassing the cache verifies the module and all its dependencies again, but the shared
+    // dependency only once per traversal.
+    let before = num_verified();
+    let result = module_storage.fetch_verified_module_bypassing_cache(a_id.address(), a_id.name());
+    assert_some!(assert_ok!(result));
+    module_storage.assert_cached_state(vec![], vec![&a_id, &b_id, &c_id, &d_id]);
+    assert_eq!(num_verified() - before, 4);
+
+    let result = module_storage
+        .fetch_verified_module_bypassing_cache(&AccountAddress::ZERO, ident_str!("e"));
+    assert_none!(assert_ok!(result));
+}
+
 #[test]
 fn test_cyclic_dependencies_traversal_fails() {
     let mut module_bytes_storage = InMemoryStorage::new();
@@ -190,6 +232,37 @@ fn test_cyclic_dependencies_traversal_fails() {
     );
 }
 
+#[test]
+fn test_missing_dependency_traversal_fails() {
+    let mut module_bytes_storage = InMemoryStorage::new();
+
+    let a_id = ModuleId::new(AccountAddress::ZERO, Identifier::new("a").unwrap());
+    let b_id = ModuleId::new(AccountAddress::ZERO, Identifier::new("b").unwrap());
+
+    // Module c is a dependency of b, but does not exist.
+    add_module_bytes(&mut module_bytes_storage, "a", vec!["b"], vec![]);
+    add_module_bytes(&mut module_bytes_storage, "b", vec!["c"], vec![]);
+
+    let module_storage = module_bytes_storage.into_unsync_module_storage();
+
+    let result = module_storage.fetch_verified_module(a_id.address(), a_id.name());
+    let err = assert_err!(result);
+    assert_eq!(err.major_status(), StatusCode::LINKER_ERROR);
+    let expected_message = format!(
+        "Linker Error: Module {}::c doesn't exist",
+        AccountAddress::ZERO.to_hex()
+    );
+    assert_eq!(err.message(), Some(&expected_message));
+
+    // Partially traversed modules must not be cached as verified.
+    module_storage.assert_cached_state(vec![&a_id, &b_id], vec![]);
+
+    // Traversal fails consistently.
+    let result = module_storage.fetch_verified_module(b_id.address(), b_id.name());
+    assert_eq!(assert_err!(result).major_status(), StatusCode::LINKER_ERROR);
+    module_storage.assert_cached_state(vec![&a_id, &b_id], vec![]);
+}
+
 #[test]
 fn test_cyclic_friends_are_allowed() {
     let mut module_bytes_storage = InMemoryStorage::new();
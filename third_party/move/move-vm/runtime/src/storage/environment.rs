@@ -33,6 +33,13 @@ use move_vm_types::loaded_data::{
 use move_vm_types::loaded_data::{runtime_types::Type, struct_name_indexing::StructNameIndexMap};
 use std::sync::Arc;
 
+#[cfg(any(test, feature = "testing"))]
+thread_local! {
+    /// Number of modules locally verified by the current thread. Used by tests to check if the
+    /// verification runs.
+    static NUM_LOCALLY_VERIFIED_MODULES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
 /// [MoveVM] runtime environment encapsulating different configurations. Shared between the VM and
 /// the code cache, possibly across multiple threads.
 pub struct RuntimeEnvironment {
@@ -140,7 +147,28 @@ impl RuntimeEnvironment {
         module_size: usize,
         module_hash: &[u8; 32],
     ) -> VMResult<LocallyVerifiedModule> {
-        if !VERIFIED_MODULES_V2.contains(module_hash) {
+        self.build_locally_verified_module_in_traversal(
+            compiled_module,
+            module_size,
+            module_hash,
+            VERIFIED_MODULES_V2.generation(),
+            false,
+        )
+    }
+
+    /// Same as [RuntimeEnvironment::build_locally_verified_module], but the verification result
+    /// is only cached if the verified module cache has not been flushed since the specified
+    /// generation (i.e., since the module traversal started). If the cache is bypassed, the
+    /// module is verified even if it has already been verified before.
+    pub(crate) fn build_locally_verified_module_in_traversal(
+        &self,
+        compiled_module: Arc<CompiledModule>,
+        module_size: usize,
+        module_hash: &[u8; 32],
+        verified_cache_generation: u64,
+        bypass_cache: bool,
+    ) -> VMResult<LocallyVerifiedModule> {
+        if bypass_cache || !VERIFIED_MODULES_V2.contains(module_hash) {
             let _timer = VM_TIMER.timer_with_label(
                 "LoaderV2::build_locally_verified_module [verification cache miss]",
             );
@@ -154,7 +182,10 @@ impl RuntimeEnvironment {
                 compiled_module.as_ref(),
             )?;
             check_natives(compiled_module.as_ref())?;
-            VERIFIED_MODULES_V2.put(*module_hash);
+            VERIFIED_MODULES_V2.put(*module_hash, verified_cache_generation);
+
+            #[cfg(any(test, feature = "testing"))]
+            NUM_LOCALLY_VERIFIED_MODULES.with(|num| num.set(num.get() + 1));
         }
 
         Ok(LocallyVerifiedModule(compiled_module, module_size))
@@ -318,6 +349,24 @@ impl RuntimeEnvironment {
     ) -> PartialVMResult<StructIdentifier> {
         self.struct_name_index_map.idx_to_struct_name(idx)
     }
+
+    /// Test-only function to be able to check how many modules were locally verified by the
+    /// current thread.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn num_locally_verified_modules_for_test() -> usize {
+        NUM_LOCALLY_VERIFIED_MODULES.with(|num| num.get())
+    }
+
+    /// Test-only function to be able to check which natives are registered in this runtime.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn has_native_for_test(
+        &self,
+        addr: &AccountAddress,
+        module_name: &str,
+        func_name: &str,
+    ) -> bool {
+        self.natives.resolve(addr, module_name, func_name).is_some()
+    }
 }
 
 impl Clone for RuntimeEnvironment {
@@ -3,7 +3,7 @@
 
 pub(crate) mod depth_formula_calculator;
 pub(crate) mod ty_tag_converter;
-mod verified_module_cache;
+pub(crate) mod verified_module_cache;
 
 pub mod code_storage;
 pub mod dependencies_gas_charging;
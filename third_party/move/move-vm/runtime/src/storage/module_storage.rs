@@ -4,7 +4,10 @@
 use crate::{
     loader::{Function, LazyLoadedFunction, LazyLoadedFunctionState, LoadedFunctionOwner, Module},
     logging::expect_no_verification_errors,
-    storage::ty_layout_converter::{LayoutConverter, StorageLayoutConverter},
+    storage::{
+        ty_layout_converter::{LayoutConverter, StorageLayoutConverter},
+        verified_module_cache::VERIFIED_MODULES_V2,
+    },
     LoadedFunction, WithRuntimeEnvironment,
 };
 use ambassador::delegatable_trait;
@@ -115,6 +118,16 @@ pub trait ModuleStorage: WithRuntimeEnvironment {
         module_name: &IdentStr,
     ) -> VMResult<Option<Arc<Module>>>;
 
+    /// Same as [ModuleStorage::fetch_verified_module], but the module and all its transitive
+    /// dependencies are verified again, even if they have already been verified and cached. The
+    /// verification results are still cached. Should only be used to benchmark the cold
+    /// verification path.
+    fn fetch_verified_module_bypassing_cache(
+        &self,
+        address: &AccountAddress,
+        module_name: &IdentStr,
+    ) -> VMResult<Option<Arc<Module>>>;
+
     /// Returns the verified module. If it does not exist, a linker error is returned. All other
     /// errors are mapped using [expect_no_verification_errors] - since on-chain code should not
     /// fail bytecode verification.
@@ -327,6 +340,37 @@ where
 
         let _timer = VM_TIMER.timer_with_label("ModuleStorage::fetch_verified_module [cache miss]");
 
+        // If the verified module cache is flushed while traversing, verification results are
+        // still returned but are not cached.
+        let verified_cache_generation = VERIFIED_MODULES_V2.generation();
+        let mut visited = HashSet::new();
+        visited.insert(id.clone());
+        Ok(Some(visit_dependencies_and_verify(
+            id,
+            module,
+            version,
+            &mut visited,
+            verified_cache_generation,
+            false,
+            self,
+        )?))
+    }
+
+    fn fetch_verified_module_bypassing_cache(
+        &self,
+        address: &AccountAddress,
+        module_name: &IdentStr,
+    ) -> VMResult<Option<Arc<Module>>> {
+        let id = ModuleId::new(*address, module_name.to_owned());
+
+        // Even if the module is already verified, traverse and verify it again together with all
+        // its transitive dependencies.
+        let (module, version) = match self.get_module_or_build_with(&id, self)? {
+            Some(module_and_version) => module_and_version,
+            None => return Ok(None),
+        };
+
+        let verified_cache_generation = VERIFIED_MODULES_V2.generation();
         let mut visited = HashSet::new();
         visited.insert(id.clone());
         Ok(Some(visit_dependencies_and_verify(
@@ -334,6 +378,8 @@ where
             module,
             version,
             &mut visited,
+            verified_cache_generation,
+            true,
             self,
         )?))
     }
@@ -383,11 +429,16 @@ where
 ///   not see any dependencies or friends. Hence, A cannot discover C and vice-versa, making
 ///   detection of such corner cases only possible if **all existing modules are checked**, which
 ///   is clearly infeasible.
+///
+/// If the cache is bypassed, all visited modules are verified again even if they have already been
+/// verified before, and only modules verified as part of this traversal are reused.
 fn visit_dependencies_and_verify<T, E, V>(
     module_id: ModuleId,
     module: Arc<ModuleCode<CompiledModule, Module, E>>,
     version: V,
     visited: &mut HashSet<ModuleId>,
+    verified_cache_generation: u64,
+    bypass_cache: bool,
     module_cache_with_context: &T,
 ) -> VMResult<Arc<Module>>
 where
@@ -415,10 +466,12 @@ where
         module_id.address(),
         module_id.name(),
     )?;
-    let locally_verified_code = runtime_environment.build_locally_verified_module(
+    let locally_verified_code = runtime_environment.build_locally_verified_module_in_traversal(
         module.code().deserialized().clone(),
         module.extension().size_in_bytes(),
         module.extension().hash(),
+        verified_cache_generation,
+        bypass_cache,
     )?;
 
     // Step 2: Traverse and collect all verified immediate dependencies so that we can verify
@@ -431,8 +484,9 @@ where
             .get_module_or_build_with(&dependency_id, module_cache_with_context)?
             .ok_or_else(|| module_linker_error!(addr, name))?;
 
-        // Dependency is already verified!
-        if dependency.code().is_verified() {
+        // Dependency is already verified! When bypassing the cache, it must have been verified
+        // again as part of this traversal.
+        if dependency.code().is_verified() && (!bypass_cache || visited.contains(&dependency_id)) {
             verified_dependencies.push(dependency.code().verified().clone());
             continue;
         }
@@ -444,6 +498,8 @@ where
                 dependency,
                 dependency_version,
                 visited,
+                verified_cache_generation,
+                bypass_cache,
                 module_cache_with_context,
             )?;
             verified_dependencies.push(verified_dependency);
@@ -3,12 +3,20 @@
 
 use lazy_static::lazy_static;
 use parking_lot::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 /// Cache for already verified modules. Since loader V1 uses such a cache to not perform repeated
 /// verifications, possibly even across blocks, for comparative performance we need to have it as
 /// well. For now, we keep it as a separate cache to make sure there is no interference between V1
 /// and V2 implementations.
-pub(crate) struct VerifiedModuleCache(Mutex<lru::LruCache<[u8; 32], ()>>);
+///
+/// The cache also tracks a generation, which is incremented every time the cache is flushed. A
+/// module traversal records the generation when it starts and only commits its verification
+/// results if the cache has not been flushed in the meantime.
+pub(crate) struct VerifiedModuleCache {
+    cache: Mutex<lru::LruCache<[u8; 32], ()>>,
+    generation: AtomicU64,
+}
 
 impl VerifiedModuleCache {
     /// Maximum size of the cache. When modules are cached, they can skip re-verification.
@@ -16,24 +24,131 @@ impl VerifiedModuleCache {
 
     /// Returns new empty verified module cache.
     pub(crate) fn empty() -> Self {
-        Self(Mutex::new(lru::LruCache::new(Self::VERIFIED_CACHE_SIZE)))
+        Self {
+            cache: Mutex::new(lru::LruCache::new(Self::VERIFIED_CACHE_SIZE)),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the current generation of the cache.
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
     }
 
     /// Returns true if the module hash is contained in the cache. For tests, the cache is treated
     /// as empty at all times.
     pub(crate) fn contains(&self, module_hash: &[u8; 32]) -> bool {
-        !cfg!(test) && !cfg!(feature = "testing") && self.0.lock().contains(module_hash)
+        !cfg!(test) && !cfg!(feature = "testing") && self.is_cached(module_hash)
     }
 
-    /// Inserts the hash into the cache, marking the corresponding as locally verified. For tests,
-    /// entries are not added to the cache.
-    pub(crate) fn put(&self, module_hash: [u8; 32]) {
+    /// Returns true if the module hash is in the cache, regardless of whether caching is enabled.
+    fn is_cached(&self, module_hash: &[u8; 32]) -> bool {
+        self.cache.lock().contains(module_hash)
+    }
+
+    /// Inserts the hash into the cache, marking the corresponding as locally verified, unless the
+    /// cache has been flushed since the specified generation. For tests, entries are not added to
+    /// the cache.
+    pub(crate) fn put(&self, module_hash: [u8; 32], generation: u64) {
         if !cfg!(test) && !cfg!(feature = "testing") {
-            self.0.lock().put(module_hash, ());
+            self.put_if_not_flushed(module_hash, generation);
         }
     }
+
+    /// Inserts the hash into the cache if the generation has not changed, returning true if the
+    /// entry was inserted.
+    fn put_if_not_flushed(&self, module_hash: [u8; 32], generation: u64) -> bool {
+        // The generation is only changed while holding the lock, so it cannot change between the
+        // check and the insertion.
+        let mut cache = self.cache.lock();
+        if self.generation() != generation {
+            return false;
+        }
+        cache.put(module_hash, ());
+        true
+    }
+
+    /// Removes all entries from the cache, and invalidates all traversals that are in progress.
+    pub(crate) fn flush(&self) {
+        let mut cache = self.cache.lock();
+        cache.clear();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+    }
 }
 
 lazy_static! {
     pub(crate) static ref VERIFIED_MODULES_V2: VerifiedModuleCache = VerifiedModuleCache::empty();
 }
+
+/// Resets all process-wide VM caches, so that no state leaks between unrelated workloads run by
+/// the same long-lived process (e.g., a replay tool executing different networks). Traversals in
+/// progress do not commit their verification results afterwards. Caches owned by a
+/// [crate::RuntimeEnvironment] or by a block executor are not global and are dropped together
+/// with their owners.
+///
+/// This is a production-only reset: when built for tests (or with the "testing" feature), the
+/// verified module cache is bypassed, so there is no cached state to isolate tests from.
+pub fn reset_all_global_vm_caches() {
+    VERIFIED_MODULES_V2.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reset_all_global_vm_caches_flushes_verified_modules() {
+        // In tests, put is a no-op, so populate the cache the way put does in production.
+        let generation = VERIFIED_MODULES_V2.generation();
+        assert!(VERIFIED_MODULES_V2.put_if_not_flushed([0; 32], generation));
+        assert!(VERIFIED_MODULES_V2.is_cached(&[0; 32]));
+
+        reset_all_global_vm_caches();
+        assert!(!VERIFIED_MODULES_V2.is_cached(&[0; 32]));
+
+        // A traversal started before the reset cannot repopulate the cache.
+        assert_ne!(VERIFIED_MODULES_V2.generation(), generation);
+        assert!(!VERIFIED_MODULES_V2.put_if_not_flushed([1; 32], generation));
+        assert!(!VERIFIED_MODULES_V2.is_cached(&[1; 32]));
+    }
+
+    #[test]
+    fn test_flush_during_traversal_discards_results() {
+        use std::{
+            sync::{Arc, Barrier},
+            thread,
+        };
+
+        // Use a local cache so that concurrent tests using the global one are not affected.
+        let cache = Arc::new(VerifiedModuleCache::empty());
+        let traversal_started = Arc::new(Barrier::new(2));
+        let flushed = Arc::new(Barrier::new(2));
+
+        let invalidator = {
+            let cache = cache.clone();
+            let traversal_started = traversal_started.clone();
+            let flushed = flushed.clone();
+            thread::spawn(move || {
+                traversal_started.wait();
+                cache.flush();
+                flushed.wait();
+            })
+        };
+
+        // Traversal verifies the first module before the cache is flushed.
+        let generation = cache.generation();
+        assert!(cache.put_if_not_flushed([0; 32], generation));
+
+        // The cache is flushed in the middle of the traversal, so the remaining results of the
+        // traversal must not be committed.
+        traversal_started.wait();
+        flushed.wait();
+        invalidator.join().unwrap();
+        assert!(!cache.put_if_not_flushed([1; 32], generation));
+        assert!(!cache.is_cached(&[0; 32]));
+
+        // A new traversal can populate the cache again.
+        assert!(cache.put_if_not_flushed([1; 32], cache.generation()));
+        assert!(cache.is_cached(&[1; 32]));
+    }
+}
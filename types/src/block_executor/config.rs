@@ -13,9 +13,16 @@ pub struct BlockExecutorModuleCacheLocalConfig {
     /// The maximum size of module cache (the sum of serialized sizes of all cached modules in
     /// bytes).
     pub max_module_cache_size_in_bytes: usize,
+    /// Soft limit on the size of module cache (in bytes). When exceeded, a warning is logged once,
+    /// giving operators a heads-up before the cache reaches its maximum size and gets flushed.
+    pub module_cache_size_warning_threshold_in_bytes: usize,
     /// The maximum size (in terms of entries) of struct name re-indexing map stored in the runtime
     /// environment.
     pub max_struct_name_index_map_num_entries: usize,
+    /// If true, modules cached by previous blocks are never reused, and Aptos framework is not
+    /// prefetched, so that every block resolves modules from storage. Outputs are the same, but
+    /// execution is slower. Used to debug suspected module cache issues.
+    pub disable_cross_block_module_cache: bool,
 }
 
 impl Default for BlockExecutorModuleCacheLocalConfig {
@@ -25,7 +32,9 @@ impl Default for BlockExecutorModuleCacheLocalConfig {
             // Use 1Gb for now, should be large enough to cache all mainnet modules (at the time
             // of writing this comment, 13.11.24).
             max_module_cache_size_in_bytes: 1024 * 1024 * 1024,
+            module_cache_size_warning_threshold_in_bytes: 768 * 1024 * 1024,
             max_struct_name_index_map_num_entries: 1_000_000,
+            disable_cross_block_module_cache: false,
         }
     }
 }
@@ -75,6 +75,19 @@ pub trait TStateView {
     fn contains_state_value(&self, state_key: &Self::Key) -> StateViewResult<bool> {
         self.get_state_value(state_key).map(|opt| opt.is_some())
     }
+
+    /// Gets the state values for the given state keys, in the same order as the keys. Views
+    /// backed by remote or persistent storage can override this to fetch all values in a single
+    /// batch. By default, values are fetched one key at a time.
+    fn multi_get_state_values(
+        &self,
+        state_keys: &[Self::Key],
+    ) -> StateViewResult<Vec<Option<StateValue>>> {
+        state_keys
+            .iter()
+            .map(|state_key| self.get_state_value(state_key))
+            .collect()
+    }
 }
 
 pub trait StateView: TStateView<Key = StateKey> {}
@@ -126,6 +139,10 @@ where
     fn get_state_value(&self, state_key: &K) -> StateViewResult<Option<StateValue>> {
         self.deref().get_state_value(state_key)
     }
+
+    fn multi_get_state_values(&self, state_keys: &[K]) -> StateViewResult<Vec<Option<StateValue>>> {
+        self.deref().multi_get_state_values(state_keys)
+    }
 }
 
 /// Test-only basic [StateView] implementation with generic keys.